@@ -4,20 +4,26 @@ use std::{
 };
 
 use ethabi::{AbiError, Param, ParamType};
+use futures::{Stream, TryStreamExt};
 use log::warn;
 use log_derive::{logfn, logfn_inputs};
 
 use crate::{
     client::{
+        event_pagination::paginate_events,
         implementation::web3::{client::Web3Client, contract::Web3Contract},
-        Client, Contract, QuorumHandler,
+        verification::verify_contract,
+        Client, ConfirmationPolicy, ConnectionConfig, Contract, EventPagination, FeeStrategy,
+        NonceManager, PendingTransaction, QuorumHandler,
     },
     error::{VdrError, VdrResult},
+    signer::Signer,
     types::{
-        Block, ContractConfig, ContractSpec, EventLog, EventQuery, PingStatus, Transaction,
-        TransactionType,
+        Block, ContractConfig, ContractSpec, EventLog, EventQuery, EventStream, PingStatus,
+        SimulationResult, Transaction, TransactionEnvelope, TransactionFees, TransactionReceipt,
+        TransactionStatus, TransactionType, VerificationMismatch, VerificationReport,
     },
-    Address, BlockDetails, QuorumConfig,
+    Address, BlockDetails, QuorumConfig, TransactionEndorsingData,
 };
 
 /// Client object for interaction with the network
@@ -28,6 +34,10 @@ pub struct LedgerClient {
     errors: HashMap<[u8; 4], AbiError>,
     network: Option<String>,
     quorum_handler: Option<QuorumHandler>,
+    confirmation_policy: ConfirmationPolicy,
+    transaction_envelope: TransactionEnvelope,
+    fee_strategy: FeeStrategy,
+    nonce_manager: Option<NonceManager>,
 }
 
 impl LedgerClient {
@@ -51,25 +61,20 @@ impl LedgerClient {
         network: Option<&str>,
         quorum_config: Option<&QuorumConfig>,
     ) -> VdrResult<LedgerClient> {
-        let client = Box::new(Web3Client::new(rpc_node)?);
+        let mut builder = LedgerClientBuilder::new()
+            .set_chain_id(chain_id)
+            .set_rpc_node(rpc_node)
+            .set_contract_configs(contract_configs);
 
-        let contracts = Self::init_contracts(&client, contract_configs)?;
-        let errors = Self::build_error_map(&contracts)?;
+        if let Some(network) = network {
+            builder = builder.set_network(network);
+        }
 
-        let quorum_handler = match quorum_config {
-            Some(quorum_config) => Some(QuorumHandler::new(quorum_config.clone())?),
-            None => None,
-        };
+        if let Some(quorum_config) = quorum_config {
+            builder = builder.set_quorum_config(quorum_config);
+        }
 
-        let ledger_client = LedgerClient {
-            chain_id,
-            client,
-            contracts,
-            errors,
-            network: network.map(String::from),
-            quorum_handler,
-        };
-        Ok(ledger_client)
+        builder.build()
     }
 
     /// Ping Ledger.
@@ -85,45 +90,286 @@ impl LedgerClient {
         }
     }
 
+    /// Verify the client configuration against the network
+    ///     Checks that the node belongs to the network with the configured chain id, and that every configured
+    ///     contract is deployed and implements the functions of its ABI
+    ///
+    /// # Returns
+    ///  report: [VerificationReport] - found mismatches. Empty if the configuration matches the network
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn verify(&self) -> VdrResult<VerificationReport> {
+        let chain_id = self.client.get_chain_id().await?;
+        let mut mismatches = Vec::new();
+        if chain_id != self.chain_id {
+            mismatches.push(VerificationMismatch::ChainId {
+                expected: self.chain_id,
+                actual: chain_id,
+            });
+        }
+
+        let mut names: Vec<&String> = self.contracts.keys().collect();
+        names.sort();
+        for name in names {
+            let contract = self.contracts[name].as_ref();
+            if let Some(mismatch) = verify_contract(self.client.as_ref(), name, contract).await? {
+                mismatches.push(mismatch);
+            }
+        }
+
+        Ok(VerificationReport {
+            chain_id,
+            mismatches,
+        })
+    }
+
     /// Submit prepared transaction to the ledger
     ///     Depending on the transaction type Write/Read ethereum methods will be used
+    ///     Write transactions are confirmed according to the client confirmation policy
     ///
     /// #Params
     ///  `transaction`: [Transaction] - transaction to submit
     ///
     /// #Returns
     ///  response: [Vec] - transaction execution result:
-    ///    depending on the type it will be either result bytes or block hash
+    ///    depending on the type it will be either result bytes or transaction hash
     #[logfn(Info)]
     #[logfn_inputs(Debug)]
     pub async fn submit_transaction(&self, transaction: &Transaction) -> VdrResult<Vec<u8>> {
-        let result = match transaction.type_ {
-            TransactionType::Read => {
-                self.client
-                    .call_transaction(transaction.to.as_ref(), &transaction.data)
-                    .await
+        self.submit_transaction_with_policy(transaction, &self.confirmation_policy)
+            .await
+    }
+
+    /// Submit prepared transaction to the ledger using custom confirmation policy for write transactions
+    ///
+    /// #Params
+    ///  `transaction`: [Transaction] - transaction to submit
+    ///  `policy`: [ConfirmationPolicy] - policy defining how long to wait for write transaction confirmation
+    ///
+    /// #Returns
+    ///  response: [Vec] - transaction execution result:
+    ///    depending on the type it will be either result bytes or transaction hash
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn submit_transaction_with_policy(
+        &self,
+        transaction: &Transaction,
+        policy: &ConfirmationPolicy,
+    ) -> VdrResult<Vec<u8>> {
+        match transaction.type_ {
+            TransactionType::Read => self.call_transaction(transaction).await,
+            TransactionType::Write => {
+                let pending_transaction = self.send_transaction(transaction).await?;
+                pending_transaction.confirm(policy).await?;
+                Ok(pending_transaction.hash().to_vec())
             }
-            TransactionType::Write => self.client.submit_transaction(&transaction.encode()?).await,
-        };
+        }
+    }
 
-        let data = match result {
-            Ok(data) => data,
-            Err(VdrError::ClientTransactionReverted(revert_reason)) => {
-                let decoded_reason = self.decode_revert_reason(&revert_reason)?;
+    /// Sign prepared transaction with the signer and submit it to the ledger
+    ///     Read transactions are submitted without signing
+    ///
+    /// #Params
+    ///  `transaction`: [Transaction] - transaction to sign and submit
+    ///  `signer`: [Signer] - signer holding the key of the transaction sender
+    ///
+    /// #Returns
+    ///  response: [Vec] - transaction execution result:
+    ///    depending on the type it will be either result bytes or transaction hash
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn sign_and_submit(
+        &self,
+        transaction: &Transaction,
+        signer: &dyn Signer,
+    ) -> VdrResult<Vec<u8>> {
+        if transaction.type_ == TransactionType::Read {
+            return self.submit_transaction(transaction).await;
+        }
 
-                return Err(VdrError::ClientTransactionReverted(decoded_reason));
+        let mut transaction = transaction.clone();
+        let signature = match signer.sign_transaction(&transaction).await {
+            Ok(signature) => signature,
+            Err(error) => {
+                // transaction is not going to be sent so its nonce can be reused
+                if let Err(release_error) = self.release_nonce(&transaction) {
+                    warn!(
+                        "Error: {} during releasing nonce of not signed transaction",
+                        release_error
+                    );
+                }
+                return Err(error);
             }
-            Err(error) => return Err(error),
         };
+        transaction.set_signature(signature);
+        self.submit_transaction(&transaction).await
+    }
 
-        if let Some(quorum_handler) = &self.quorum_handler {
-            quorum_handler.check(transaction, &data).await?;
+    /// Sign endorsing data with the signer on behalf of its author and set the signature
+    ///
+    /// #Params
+    ///  `data`: [TransactionEndorsingData] - endorsing data to sign
+    ///  `signer`: [Signer] - signer holding the key of the author
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn sign_endorsing_data(
+        &self,
+        data: &mut TransactionEndorsingData,
+        signer: &dyn Signer,
+    ) -> VdrResult<()> {
+        let signature = signer.sign_endorsing_data(data).await?;
+        data.set_signature(signature);
+        Ok(())
+    }
+
+    /// Send prepared write transaction to the ledger without waiting for its inclusion into a block
+    ///
+    /// #Params
+    ///  `transaction`: [Transaction] - write transaction to send
+    ///
+    /// #Returns
+    ///  pending_transaction: [PendingTransaction] - handle to track status of the sent transaction
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn send_transaction(
+        &self,
+        transaction: &Transaction,
+    ) -> VdrResult<PendingTransaction<'_>> {
+        if transaction.type_ != TransactionType::Write {
+            let vdr_error = VdrError::ClientInvalidTransaction(
+                "Only write transactions can be sent without waiting for the result".to_string(),
+            );
+
+            warn!("Error: {} during sending transaction", vdr_error);
+
+            return Err(vdr_error);
+        }
+
+        let result = match transaction.encode() {
+            Ok(encoded_transaction) => self.client.send_transaction(&encoded_transaction).await,
+            Err(error) => Err(error),
         };
 
-        Ok(data)
+        let hash = match result {
+            Ok(hash) => hash,
+            Err(error) => {
+                let nonce_result = if is_transaction_rejected(&error) {
+                    // transaction is not accepted by the node so its nonce can be reused
+                    self.release_nonce(transaction)
+                } else {
+                    // the node may have got the transaction, so its nonce stays reserved until the account is synced
+                    self.resync_nonce(transaction)
+                };
+                if let Err(nonce_error) = nonce_result {
+                    warn!(
+                        "Error: {} during updating nonce of not sent transaction",
+                        nonce_error
+                    );
+                }
+
+                return match error {
+                    VdrError::ClientTransactionReverted(revert_reason) => {
+                        let decoded_reason = self
+                            .decode_revert_reason(&revert_reason)
+                            .unwrap_or(revert_reason);
+                        Err(VdrError::ClientTransactionReverted(decoded_reason))
+                    }
+                    error => Err(error),
+                };
+            }
+        };
+
+        Ok(PendingTransaction::new(self, transaction, hash))
+    }
+
+    /// Simulate execution of write transaction before signing it
+    ///     Transaction is executed with `eth_call` on behalf of its sender at the latest block.
+    ///     It allows to reject transactions which are going to be reverted (for example, when DID or Schema already
+    ///     exists or the sender is not authorized) without asking the sender to sign them.
+    ///
+    /// #Params
+    ///  `transaction`: [Transaction] - built write transaction. Signature is not required
+    ///
+    /// #Returns
+    ///  result: [SimulationResult] - output of the contract method or decoded revert reason
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn simulate_transaction(
+        &self,
+        transaction: &Transaction,
+    ) -> VdrResult<SimulationResult> {
+        if transaction.type_ != TransactionType::Write {
+            let vdr_error = VdrError::ClientInvalidTransaction(
+                "Only write transactions can be simulated".to_string(),
+            );
+
+            warn!("Error: {} during simulating transaction", vdr_error);
+
+            return Err(vdr_error);
+        }
+        if transaction.from.is_none() {
+            let vdr_error =
+                VdrError::ClientInvalidTransaction("Transaction `sender` is not set".to_string());
+
+            warn!("Error: {} during simulating transaction", vdr_error);
+
+            return Err(vdr_error);
+        }
+
+        match self.client.simulate_transaction(transaction).await {
+            Ok(output) => Ok(SimulationResult::Success { output }),
+            Err(VdrError::ClientTransactionReverted(revert_reason)) => {
+                Ok(SimulationResult::Reverted {
+                    revert_reason: self
+                        .decode_revert_reason(&revert_reason)
+                        .unwrap_or(revert_reason),
+                })
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Return nonce reserved for the write transaction which is not going to be sent to the ledger
+    ///     Required only if the client is created with [NonceManager]
+    ///
+    /// # Params
+    ///  `transaction`: [Transaction] - built write transaction
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn release_nonce(&self, transaction: &Transaction) -> VdrResult<()> {
+        match (&self.nonce_manager, &transaction.from, transaction.nonce) {
+            (Some(nonce_manager), Some(from), Some(nonce)) => nonce_manager.release(from, nonce),
+            _ => Ok(()),
+        }
+    }
+
+    /// Get status of the transaction sent to the ledger
+    ///
+    /// # Params
+    ///  `hash`: [Vec] - hash of the transaction
+    ///
+    /// # Returns
+    ///  status: [TransactionStatus] - transaction status
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn get_transaction_status(&self, hash: &[u8]) -> VdrResult<TransactionStatus> {
+        match self.client.get_transaction_status(hash).await? {
+            TransactionStatus::Reverted {
+                block_number,
+                revert_reason,
+            } => Ok(TransactionStatus::Reverted {
+                block_number,
+                revert_reason: self
+                    .decode_revert_reason(&revert_reason)
+                    .unwrap_or(revert_reason),
+            }),
+            status => Ok(status),
+        }
     }
 
     /// Submit prepared events query to the ledger
+    ///     If quorum check is enabled, the events are cross-checked with the quorum nodes.
+    ///     Query without `to_block` is executed up to the latest block of the primary node
     ///
     /// #Params
     ///  `query`: [EventQuery] - events query to submit
@@ -133,22 +379,95 @@ impl LedgerClient {
     #[logfn(Info)]
     #[logfn_inputs(Debug)]
     pub async fn query_events(&self, query: &EventQuery) -> VdrResult<Vec<EventLog>> {
-        let events = self.client.query_events(query).await?;
-        // TODO: Check quorum for events
-        Ok(events)
+        match &self.quorum_handler {
+            Some(quorum_handler) => {
+                // fix the block range, so all nodes return the logs of the same blocks
+                let query = match query.to_block {
+                    Some(_) => query.clone(),
+                    None => EventQuery {
+                        to_block: Some(Block::from(self.get_block(None).await?.number)),
+                        ..query.clone()
+                    },
+                };
+                let events = self.client.query_events(&query).await?;
+                quorum_handler
+                    .sync_validator_nodes(self, self.client.as_ref())
+                    .await?;
+                quorum_handler.check_events(&query, &events).await?;
+                Ok(events)
+            }
+            None => self.client.query_events(query).await,
+        }
+    }
+
+    /// Submit prepared events query to the ledger splitting its block range into chunks
+    ///     Use for queries over large block ranges which the node may reject when sent with one request
+    ///
+    /// #Params
+    ///  `query`: [EventQuery] - events query to submit. If `to_block` is not set, events are queried up to the latest block
+    ///  `pagination`: [EventPagination] - chunking settings
+    ///
+    /// #Returns
+    ///  events: [Vec] - list of log events received from the ledger
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn query_events_with_pagination(
+        &self,
+        query: &EventQuery,
+        pagination: &EventPagination,
+    ) -> VdrResult<Vec<EventLog>> {
+        self.stream_events(query, pagination).try_collect().await
     }
 
-    /// Get receipt for the given block hash
+    /// Stream log events matching the query, requesting the ledger block range chunk by chunk
+    ///     Allows to process events of the whole registry history without loading them into memory at once
+    ///
+    /// #Params
+    ///  `query`: [EventQuery] - events query to submit. If `to_block` is not set, events are queried up to the latest block
+    ///  `pagination`: [EventPagination] - chunking settings
+    ///
+    /// #Returns
+    ///  events: [Stream] - stream of log events in the order of blocks
+    #[logfn_inputs(Debug)]
+    pub fn stream_events<'a>(
+        &'a self,
+        query: &EventQuery,
+        pagination: &EventPagination,
+    ) -> impl Stream<Item = VdrResult<EventLog>> + 'a {
+        paginate_events(self, query, pagination)
+    }
+
+    /// Subscribe to log events matching the given query
+    ///     Requires the client to be connected to the node over WebSocket (see [Web3Client::new_ws])
+    ///
+    /// #Params
+    ///  `query`: [EventQuery] - events query to subscribe to. Block range is ignored
+    ///
+    /// #Returns
+    ///  events: [EventStream] - stream of log events pushed by the ledger
+    #[logfn_inputs(Debug)]
+    pub async fn subscribe_events(&self, query: &EventQuery) -> VdrResult<EventStream> {
+        self.client.subscribe_events(query).await
+    }
+
+    /// Get receipt for the given transaction hash
+    ///     Revert reason of the failed transaction is decoded using errors of the known contracts
+    ///     Use [crate::parse_receipt_events] to decode receipt logs into contract events
     ///
     /// # Params
-    ///  `transaction`: [Transaction] - transaction to submit
+    ///  `hash`: [Vec] - hash of the transaction
     ///
     /// # Returns
-    ///  receipt: [String] - receipt for the given block
+    ///  receipt: [TransactionReceipt] - receipt of the transaction
     #[logfn(Info)]
     #[logfn_inputs(Debug)]
-    pub async fn get_receipt(&self, hash: &[u8]) -> VdrResult<String> {
-        self.client.get_receipt(hash).await
+    pub async fn get_receipt(&self, hash: &[u8]) -> VdrResult<TransactionReceipt> {
+        let mut receipt = self.client.get_receipt(hash).await?;
+        receipt.revert_reason = receipt.revert_reason.map(|revert_reason| {
+            self.decode_revert_reason(&revert_reason)
+                .unwrap_or(revert_reason)
+        });
+        Ok(receipt)
     }
 
     /// Get a number of transactions sent by the given account address
@@ -164,6 +483,34 @@ impl LedgerClient {
         self.client.get_transaction_count(address).await
     }
 
+    pub(crate) async fn reserve_nonce(&self, address: &Address) -> VdrResult<u64> {
+        match &self.nonce_manager {
+            Some(nonce_manager) => nonce_manager.reserve(self.client.as_ref(), address).await,
+            None => self.get_transaction_count(address).await,
+        }
+    }
+
+    pub(crate) async fn get_raw_transaction_status(
+        &self,
+        hash: &[u8],
+    ) -> VdrResult<TransactionStatus> {
+        self.client.get_transaction_status(hash).await
+    }
+
+    pub(crate) fn confirm_nonce(&self, transaction: &Transaction) -> VdrResult<()> {
+        match (&self.nonce_manager, &transaction.from, transaction.nonce) {
+            (Some(nonce_manager), Some(from), Some(nonce)) => nonce_manager.confirm(from, nonce),
+            _ => Ok(()),
+        }
+    }
+
+    fn resync_nonce(&self, transaction: &Transaction) -> VdrResult<()> {
+        match (&self.nonce_manager, &transaction.from) {
+            (Some(nonce_manager), Some(from)) => nonce_manager.resync(from),
+            _ => Ok(()),
+        }
+    }
+
     pub(crate) fn contract(&self, name: &str) -> VdrResult<&dyn Contract> {
         self.contracts
             .get(name)
@@ -177,6 +524,64 @@ impl LedgerClient {
             })
     }
 
+    pub(crate) fn contract_name(&self, address: &Address) -> Option<&str> {
+        self.contracts
+            .iter()
+            .find(|(_, contract)| {
+                contract
+                    .address()
+                    .as_ref()
+                    .eq_ignore_ascii_case(address.as_ref())
+            })
+            .map(|(name, _)| name.as_str())
+    }
+
+    async fn call_transaction(&self, transaction: &Transaction) -> VdrResult<Vec<u8>> {
+        let data = match self
+            .client
+            .call_transaction(
+                transaction.to.as_ref(),
+                &transaction.data,
+                transaction.block_tag.clone(),
+            )
+            .await
+        {
+            Ok(data) => data,
+            Err(VdrError::ClientTransactionReverted(revert_reason)) => {
+                let decoded_reason = self
+                    .decode_revert_reason(&revert_reason)
+                    .unwrap_or(revert_reason);
+
+                return Err(VdrError::ClientTransactionReverted(decoded_reason));
+            }
+            Err(error) => return Err(error),
+        };
+
+        self.check_quorum(transaction, &data).await?;
+
+        Ok(data)
+    }
+
+    pub(crate) async fn check_quorum(
+        &self,
+        transaction: &Transaction,
+        result: &[u8],
+    ) -> VdrResult<()> {
+        if let Some(quorum_handler) = &self.quorum_handler {
+            quorum_handler
+                .sync_validator_nodes(self, self.client.as_ref())
+                .await?;
+            match transaction.type_ {
+                TransactionType::Read => quorum_handler.check(transaction, result).await?,
+                TransactionType::Write => {
+                    let receipt = self.client.get_receipt(result).await?;
+                    quorum_handler.check_receipt(&receipt).await?
+                }
+            };
+        };
+        Ok(())
+    }
+
     pub(crate) fn chain_id(&self) -> u64 {
         self.chain_id
     }
@@ -185,10 +590,33 @@ impl LedgerClient {
         self.network.as_ref()
     }
 
+    pub(crate) fn transaction_envelope(&self) -> &TransactionEnvelope {
+        &self.transaction_envelope
+    }
+
+    pub(crate) async fn transaction_fees(
+        &self,
+        transaction: &Transaction,
+    ) -> VdrResult<TransactionFees> {
+        match self
+            .fee_strategy
+            .fees(self.client.as_ref(), transaction)
+            .await
+        {
+            Err(VdrError::ClientTransactionReverted(revert_reason)) => {
+                let decoded_reason = self
+                    .decode_revert_reason(&revert_reason)
+                    .unwrap_or(revert_reason);
+
+                Err(VdrError::ClientTransactionReverted(decoded_reason))
+            }
+            result => result,
+        }
+    }
+
     #[logfn(Info)]
     #[logfn_inputs(Debug)]
     fn init_contracts(
-        client: &Web3Client,
         contract_configs: &[ContractConfig],
     ) -> VdrResult<HashMap<String, Box<dyn Contract>>> {
         let mut contracts: HashMap<String, Box<dyn Contract>> = HashMap::new();
@@ -211,7 +639,7 @@ impl LedgerClient {
                 }
             };
 
-            let contract = Web3Contract::new(client, &contract_config.address, &spec)?;
+            let contract = Web3Contract::new(&contract_config.address, &spec)?;
             contracts.insert(spec.name.clone(), Box::new(contract));
         }
 
@@ -257,7 +685,7 @@ impl LedgerClient {
             .collect()
     }
 
-    fn decode_revert_reason(&self, revert_reason: &str) -> VdrResult<String> {
+    pub(crate) fn decode_revert_reason(&self, revert_reason: &str) -> VdrResult<String> {
         let error_data = hex::decode(revert_reason.trim_start_matches("0x")).map_err(|_| {
             VdrError::ContractInvalidResponseData(
                 format!(
@@ -327,12 +755,220 @@ impl LedgerClient {
     }
 }
 
+/// Whether the node definitely rejected the transaction: reverted it or found it invalid.
+/// Other RPC errors (e.g. `already known`, `nonce too low`) do not prove the transaction is not in the pool
+fn is_transaction_rejected(error: &VdrError) -> bool {
+    matches!(
+        error,
+        VdrError::ClientTransactionReverted(_) | VdrError::ClientInvalidTransaction(_)
+    )
+}
+
 impl Debug for LedgerClient {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, r#"LedgerClient {{ chain_id: {} }}"#, self.chain_id)
     }
 }
 
+/// Builder for [LedgerClient] allowing to plug in custom [Client] implementations
+///
+/// Either `rpc_node` or `client` must be set. Quorum check is enabled if either `quorum_config` is set or
+/// at least one quorum client is added.
+#[derive(Default)]
+pub struct LedgerClientBuilder {
+    chain_id: u64,
+    rpc_node: Option<String>,
+    connection_config: Option<ConnectionConfig>,
+    client: Option<Box<dyn Client>>,
+    contract_configs: Vec<ContractConfig>,
+    network: Option<String>,
+    quorum_config: Option<QuorumConfig>,
+    quorum_clients: Vec<Box<dyn Client>>,
+    confirmation_policy: ConfirmationPolicy,
+    transaction_envelope: TransactionEnvelope,
+    fee_strategy: FeeStrategy,
+    nonce_manager: Option<NonceManager>,
+}
+
+impl LedgerClientBuilder {
+    pub fn new() -> LedgerClientBuilder {
+        LedgerClientBuilder::default()
+    }
+
+    /// Set chain id of network (chain ID is part of the transaction signing process to protect against transaction replay attack)
+    pub fn set_chain_id(mut self, chain_id: u64) -> LedgerClientBuilder {
+        self.chain_id = chain_id;
+        self
+    }
+
+    /// Set RPC node endpoint. Web3 HTTP client will be used to interact with the node
+    pub fn set_rpc_node(mut self, rpc_node: &str) -> LedgerClientBuilder {
+        self.rpc_node = Some(rpc_node.to_string());
+        self
+    }
+
+    /// Set headers, authentication and TLS settings of the connection to `rpc_node`
+    pub fn set_connection_config(
+        mut self,
+        connection_config: &ConnectionConfig,
+    ) -> LedgerClientBuilder {
+        self.connection_config = Some(connection_config.clone());
+        self
+    }
+
+    /// Set custom client implementation to use for interaction with the primary node
+    pub fn set_client(mut self, client: Box<dyn Client>) -> LedgerClientBuilder {
+        self.client = Some(client);
+        self
+    }
+
+    /// Set specifications for contracts deployed on the network
+    pub fn set_contract_configs(
+        mut self,
+        contract_configs: &[ContractConfig],
+    ) -> LedgerClientBuilder {
+        self.contract_configs = contract_configs.to_vec();
+        self
+    }
+
+    /// Set name of the network
+    pub fn set_network(mut self, network: &str) -> LedgerClientBuilder {
+        self.network = Some(network.to_string());
+        self
+    }
+
+    /// Set quorum configuration. Web3 HTTP client will be created for each node listed in the config
+    pub fn set_quorum_config(mut self, quorum_config: &QuorumConfig) -> LedgerClientBuilder {
+        self.quorum_config = Some(quorum_config.clone());
+        self
+    }
+
+    /// Add custom client implementation to use for quorum checks
+    pub fn add_quorum_client(mut self, client: Box<dyn Client>) -> LedgerClientBuilder {
+        self.quorum_clients.push(client);
+        self
+    }
+
+    /// Set policy defining how long to wait for write transactions to be confirmed
+    pub fn set_confirmation_policy(
+        mut self,
+        confirmation_policy: &ConfirmationPolicy,
+    ) -> LedgerClientBuilder {
+        self.confirmation_policy = confirmation_policy.clone();
+        self
+    }
+
+    /// Set envelope format of write transactions built by the client (`Legacy` by default).
+    /// Use `DynamicFee` (EIP-1559) for networks with London fork and non-zero base fee
+    pub fn set_transaction_envelope(
+        mut self,
+        transaction_envelope: TransactionEnvelope,
+    ) -> LedgerClientBuilder {
+        self.transaction_envelope = transaction_envelope;
+        self
+    }
+
+    /// Set strategy defining gas limit and fees of write transactions built by the client.
+    /// Default `Free` strategy is suitable for gas free networks only
+    pub fn set_fee_strategy(mut self, fee_strategy: &FeeStrategy) -> LedgerClientBuilder {
+        self.fee_strategy = fee_strategy.clone();
+        self
+    }
+
+    /// Set manager reserving nonces of write transactions locally instead of requesting the node for every
+    /// transaction. Allows to build and send many transactions from one account concurrently
+    pub fn set_nonce_manager(mut self, nonce_manager: NonceManager) -> LedgerClientBuilder {
+        self.nonce_manager = Some(nonce_manager);
+        self
+    }
+
+    /// Build [LedgerClient] using the specified parameters
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn build(self) -> VdrResult<LedgerClient> {
+        let client: Box<dyn Client> = match (self.rpc_node, self.client) {
+            (Some(rpc_node), None) => match self.connection_config.as_ref() {
+                Some(connection_config) => {
+                    Box::new(Web3Client::new_with_config(&rpc_node, connection_config)?)
+                }
+                None => Box::new(Web3Client::new(&rpc_node)?),
+            },
+            (None, Some(client)) => client,
+            (Some(_), Some(_)) | (None, None) => {
+                return Err(VdrError::ClientInvalidState(
+                    "Either `rpc_node` or `client` must be provided".to_string(),
+                ));
+            }
+        };
+
+        let contracts = LedgerClient::init_contracts(&self.contract_configs)?;
+        let errors = LedgerClient::build_error_map(&contracts)?;
+
+        let quorum_handler = if self.quorum_config.is_some() || !self.quorum_clients.is_empty() {
+            let quorum_config = self.quorum_config.unwrap_or(QuorumConfig {
+                nodes: vec![],
+                request_retries: None,
+                request_timeout: None,
+                retry_interval: None,
+                read_policy: None,
+                write_policy: None,
+                weights: None,
+                validator_nodes: None,
+                connection: None,
+            });
+            Some(QuorumHandler::with_clients(
+                quorum_config,
+                self.quorum_clients,
+            )?)
+        } else {
+            None
+        };
+
+        Ok(LedgerClient {
+            chain_id: self.chain_id,
+            client,
+            contracts,
+            errors,
+            network: self.network,
+            quorum_handler,
+            confirmation_policy: self.confirmation_policy,
+            transaction_envelope: self.transaction_envelope,
+            fee_strategy: self.fee_strategy,
+            nonce_manager: self.nonce_manager,
+        })
+    }
+
+    /// Build the client and verify its configuration against the network (see [LedgerClient::verify])
+    ///
+    /// # Returns
+    ///  client: [LedgerClient] - client which configuration matches the network
+    pub async fn connect(self) -> VdrResult<LedgerClient> {
+        let client = self.build()?;
+        let report = client.verify().await?;
+        if !report.is_valid() {
+            let vdr_error = VdrError::ClientInvalidState(format!(
+                "Client configuration does not match the network: {:?}",
+                report.mismatches
+            ));
+
+            warn!("Error: {} during connecting to the network", vdr_error);
+
+            return Err(vdr_error);
+        }
+        Ok(client)
+    }
+}
+
+impl Debug for LedgerClientBuilder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            r#"LedgerClientBuilder {{ chain_id: {}, rpc_node: {:?}, network: {:?} }}"#,
+            self.chain_id, self.rpc_node, self.network
+        )
+    }
+}
+
 #[cfg(test)]
 pub mod test {
     use super::*;
@@ -407,7 +1043,7 @@ pub mod test {
         serde_json::from_reader(file).expect("Unable to parse besu config file")
     }
 
-    fn contracts() -> Vec<ContractConfig> {
+    pub fn contracts() -> Vec<ContractConfig> {
         vec![
             ContractConfig {
                 address: CONFIG.contracts.ethereum_did_registry.address.to_string(),
@@ -490,31 +1126,43 @@ pub mod test {
         ledger_client
     }
 
-    pub fn mock_custom_client(client: Box<dyn Client>) -> LedgerClient {
-        let mut ledger_client = LedgerClient::new(
-            CONFIG.chain_id,
-            &CONFIG.node_address,
-            &contracts(),
-            Some(TEST_NETWORK),
-            Some(&QuorumConfig::default()),
-        )
-        .unwrap();
+    pub fn mock_custom_client_without_quorum(client: Box<dyn Client>) -> LedgerClient {
+        LedgerClientBuilder::new()
+            .set_chain_id(CONFIG.chain_id)
+            .set_client(client)
+            .set_contract_configs(&contracts())
+            .set_network(TEST_NETWORK)
+            .build()
+            .unwrap()
+    }
 
-        ledger_client.client = client;
-        ledger_client
+    pub fn mock_custom_client(client: Box<dyn Client>) -> LedgerClient {
+        LedgerClientBuilder::new()
+            .set_chain_id(CONFIG.chain_id)
+            .set_client(client)
+            .set_contract_configs(&contracts())
+            .set_network(TEST_NETWORK)
+            .set_quorum_config(&QuorumConfig::default())
+            .build()
+            .unwrap()
     }
 
     mod create {
         use crate::{
             transaction::test::write_transaction, validator_control::test::VALIDATOR_CONTROL_NAME,
-            SignatureData,
+            BlockTag, SignatureData,
         };
+        use futures::{stream, StreamExt};
         use mockall::predicate::eq;
         use rstest::rstest;
         use serde_json::Value;
 
         use super::*;
 
+        fn event_query() -> EventQuery {
+            EventQuery::new(&CONFIG.contracts.ethereum_did_registry.address)
+        }
+
         #[test]
         fn create_client_test() {
             client();
@@ -583,6 +1231,64 @@ pub mod test {
             assert_eq!(client_err, expected_error);
         }
 
+        #[async_std::test]
+        async fn create_client_with_custom_client() {
+            let mut client_mock = MockClient::new();
+            client_mock.expect_get_block().returning(|_| {
+                Ok(BlockDetails {
+                    number: 1,
+                    timestamp: 2,
+                })
+            });
+
+            let client = LedgerClientBuilder::new()
+                .set_chain_id(CONFIG.chain_id)
+                .set_client(Box::new(client_mock))
+                .set_contract_configs(&contracts())
+                .build()
+                .unwrap();
+
+            let status = client.ping().await.unwrap();
+
+            assert_eq!(PingStatus::ok(1, 2), status);
+        }
+
+        #[test]
+        fn create_client_with_custom_quorum_clients() {
+            let client = LedgerClientBuilder::new()
+                .set_chain_id(CONFIG.chain_id)
+                .set_client(Box::new(MockClient::new()))
+                .set_contract_configs(&contracts())
+                .add_quorum_client(Box::new(MockClient::new()))
+                .add_quorum_client(Box::new(MockClient::new()))
+                .build()
+                .unwrap();
+
+            assert!(client.quorum_handler.is_some());
+        }
+
+        #[rstest]
+        #[case::client_not_set(LedgerClientBuilder::new())]
+        #[case::both_rpc_node_and_client_set(
+            LedgerClientBuilder::new()
+                .set_rpc_node(&CONFIG.node_address)
+                .set_client(Box::new(MockClient::new()))
+        )]
+        fn create_client_builder_errors(#[case] builder: LedgerClientBuilder) {
+            let client_err = builder
+                .set_chain_id(CONFIG.chain_id)
+                .set_contract_configs(&contracts())
+                .build()
+                .unwrap_err();
+
+            assert_eq!(
+                client_err,
+                VdrError::ClientInvalidState(
+                    "Either `rpc_node` or `client` must be provided".to_string()
+                )
+            );
+        }
+
         #[rstest]
         #[case::empty_recipient_address("", VdrError::ClientInvalidTransaction("Invalid transaction target address \"0x\"".to_string()))]
         #[case::invalid_recipient_address(INVALID_ADDRESS, VdrError::ClientInvalidTransaction("Invalid transaction target address \"0x123\"".to_string()))]
@@ -616,7 +1322,7 @@ pub mod test {
         )]
         #[case::error_without_required_argument(
             "0x863b93fe",
-            VdrError::ContractInvalidResponseData("Unable to parse the revert reason '0x863b93fe': Failed to decode the arguments".to_string()),
+            VdrError::ClientTransactionReverted("0x863b93fe".to_string()),
         )]
         #[case::error_with_extra_argument(
             "0x4e487b71000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000011",
@@ -624,19 +1330,23 @@ pub mod test {
         )]
         #[case::incorrect_error_selector(
             "0x9999999e000000000000000000000000f0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5", 
-            VdrError::ContractInvalidResponseData("Unable to parse the revert reason '0x9999999e000000000000000000000000f0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5': Cannot match the error selector with registered errors".to_string())
+            VdrError::ClientTransactionReverted("0x9999999e000000000000000000000000f0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5".to_string())
+        )]
+        #[case::unknown_error(
+            "0x9999999e",
+            VdrError::ClientTransactionReverted("0x9999999e".to_string())
         )]
         #[case::incorrect_hex(
             "0xQQ123456e00000000000000000000000f0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5", 
-            VdrError::ContractInvalidResponseData("Unable to parse the revert reason '0xQQ123456e00000000000000000000000f0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5': Incorrect hex string".to_string())
+            VdrError::ClientTransactionReverted("0xQQ123456e00000000000000000000000f0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5".to_string())
         )]
         #[case::empty_data(
             "", 
-            VdrError::ContractInvalidResponseData("Unable to parse the revert reason '': Incorrect data".to_string())
+            VdrError::ClientTransactionReverted("".to_string())
         )]
         #[case::incorrect_data(
             "0x9999", 
-            VdrError::ContractInvalidResponseData("Unable to parse the revert reason '0x9999': Incorrect data".to_string())
+            VdrError::ClientTransactionReverted("0x9999".to_string())
         )]
         async fn handle_transaction_reverts(
             #[case] encoded_error_message: &'static str,
@@ -654,12 +1364,17 @@ pub mod test {
 
             let mut client_mock = MockClient::new();
             client_mock
-                .expect_submit_transaction()
+                .expect_send_transaction()
                 .with(eq(transaction.encode().unwrap()))
+                .returning(|_| Ok(vec![1; 32]));
+            client_mock
+                .expect_get_transaction_status()
+                .with(eq(vec![1; 32]))
                 .returning(|_| {
-                    Err(VdrError::ClientTransactionReverted(
-                        encoded_error_message.to_string(),
-                    ))
+                    Ok(TransactionStatus::Reverted {
+                        block_number: 1,
+                        revert_reason: encoded_error_message.to_string(),
+                    })
                 });
 
             let client = mock_custom_client(Box::new(client_mock));
@@ -669,6 +1384,92 @@ pub mod test {
             assert_eq!(actual_error, expected_error);
         }
 
+        #[rstest]
+        #[case::success(
+            Ok(vec![1; 32]),
+            SimulationResult::Success { output: vec![1; 32] }
+        )]
+        #[case::custom_error(
+            Err(VdrError::ClientTransactionReverted("0x863b93fe000000000000000000000000f0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5".to_string())),
+            SimulationResult::Reverted { revert_reason: "DidNotFound(identity: f0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5)".to_string() }
+        )]
+        #[case::unknown_error(
+            Err(VdrError::ClientTransactionReverted("0x9999999e".to_string())),
+            SimulationResult::Reverted { revert_reason: "0x9999999e".to_string() }
+        )]
+        async fn simulate_transaction(
+            #[case] response: VdrResult<Vec<u8>>,
+            #[case] expected_result: SimulationResult,
+        ) {
+            let transaction = Transaction {
+                to: CONFIG.contracts.ethereum_did_registry.address.clone(),
+                ..write_transaction()
+            };
+
+            let mut client_mock = MockClient::new();
+            client_mock
+                .expect_simulate_transaction()
+                .with(eq(transaction.clone()))
+                .returning(move |_| response.clone());
+
+            let client = mock_custom_client(Box::new(client_mock));
+
+            let result = client.simulate_transaction(&transaction).await.unwrap();
+
+            assert_eq!(expected_result, result);
+        }
+
+        #[async_std::test]
+        async fn simulate_transaction_node_error() {
+            let mut client_mock = MockClient::new();
+            client_mock
+                .expect_simulate_transaction()
+                .returning(|_| Err(VdrError::ClientNodeUnreachable));
+
+            let client = mock_custom_client(Box::new(client_mock));
+
+            let err = client
+                .simulate_transaction(&write_transaction())
+                .await
+                .unwrap_err();
+
+            assert_eq!(VdrError::ClientNodeUnreachable, err);
+        }
+
+        #[async_std::test]
+        async fn simulate_read_transaction_not_allowed() {
+            let client = mock_client();
+
+            let err = client
+                .simulate_transaction(&read_transaction())
+                .await
+                .unwrap_err();
+
+            assert!(matches!(err, VdrError::ClientInvalidTransaction { .. }));
+        }
+
+        #[async_std::test]
+        async fn call_transaction_at_block() {
+            let mut transaction = read_transaction();
+            transaction.set_block_tag(BlockTag::Number(5));
+
+            let mut client_mock = MockClient::new();
+            client_mock
+                .expect_call_transaction()
+                .with(
+                    eq(transaction.to.to_string()),
+                    eq(transaction.data.clone()),
+                    eq(Some(BlockTag::Number(5))),
+                )
+                .returning(|_, _, _| Ok(vec![1; 32]));
+
+            let client = mock_custom_client_without_quorum(Box::new(client_mock));
+
+            let response = client.submit_transaction(&transaction).await.unwrap();
+
+            assert_eq!(vec![1; 32], response);
+        }
+
         #[async_std::test]
         async fn get_receipt_invalid_transaction_hash() {
             let client = client();
@@ -710,11 +1511,19 @@ pub mod test {
             client_mock
                 .expect_get_receipt()
                 .with(eq(txn_hash.clone()))
-                .returning(|_| Ok("".to_string()));
+                .returning(|_| {
+                    Ok(TransactionReceipt {
+                        success: false,
+                        revert_reason: Some("0x4e487b710000000000000000000000000000000000000000000000000000000000000011".to_string()),
+                        ..TransactionReceipt::default()
+                    })
+                });
 
             let client = mock_custom_client(Box::new(client_mock));
 
-            client.get_receipt(&txn_hash).await.unwrap();
+            let receipt = client.get_receipt(&txn_hash).await.unwrap();
+
+            assert_eq!(Some("Panic(code: 11)".to_string()), receipt.revert_reason);
         }
 
         #[async_std::test]
@@ -731,6 +1540,41 @@ pub mod test {
             ));
         }
 
+        #[async_std::test]
+        async fn subscribe_events_positive() {
+            let event = EventLog::new(vec![vec![1; 32]], vec![1; 32], 1);
+            let expected_event = event.clone();
+
+            let mut client_mock = MockClient::new();
+            client_mock.expect_subscribe_events().returning(move |_| {
+                let events: EventStream = Box::pin(stream::iter(vec![Ok(event.clone())]));
+                Ok(events)
+            });
+
+            let client = mock_custom_client(Box::new(client_mock));
+
+            let events: Vec<EventLog> = client
+                .subscribe_events(&event_query())
+                .await
+                .unwrap()
+                .map(|event| event.unwrap())
+                .collect()
+                .await;
+
+            assert_eq!(vec![expected_event], events);
+        }
+
+        #[async_std::test]
+        async fn subscribe_events_over_http_not_supported() {
+            let client = client();
+
+            let err = client.subscribe_events(&event_query()).await.err().unwrap();
+
+            assert!(matches!(
+                err,  | VdrError::ClientInvalidState { .. }
+            ));
+        }
+
         #[async_std::test]
         async fn get_contract_does_not_exist() {
             let client = client();
@@ -743,6 +1587,133 @@ pub mod test {
         }
     }
 
+    mod verify {
+        use super::*;
+
+        const IMPLEMENTATION_ADDRESS: &str = "0x1111111111111111111111111111111111111111";
+
+        /// Bytecode dispatching all functions of the configured contracts
+        fn dispatcher_code() -> Vec<u8> {
+            mock_client()
+                .contracts
+                .values()
+                .flat_map(|contract| contract.functions())
+                .flat_map(|function| {
+                    let selector: Vec<u8> = function
+                        .short_signature()
+                        .into_iter()
+                        .skip_while(|byte| *byte == 0)
+                        .collect();
+                    [vec![0x5f + selector.len() as u8], selector].concat()
+                })
+                .collect()
+        }
+
+        fn client_mock(chain_id: u64) -> MockClient {
+            let mut client = MockClient::new();
+            client.expect_get_chain_id().returning(move || Ok(chain_id));
+            client
+                .expect_get_storage_at()
+                .returning(|_, _| Ok(vec![0; 32]));
+            client
+        }
+
+        #[async_std::test]
+        async fn verify_positive() {
+            let code = dispatcher_code();
+            let mut client = client_mock(CONFIG.chain_id);
+            client
+                .expect_get_code()
+                .returning(move |_| Ok(code.clone()));
+            let client = mock_custom_client_without_quorum(Box::new(client));
+
+            let report = client.verify().await.unwrap();
+
+            assert!(report.is_valid());
+            assert_eq!(CONFIG.chain_id, report.chain_id);
+        }
+
+        #[async_std::test]
+        async fn verify_chain_id_and_not_deployed_contract() {
+            let code = dispatcher_code();
+            let mut client = client_mock(CONFIG.chain_id + 1);
+            client.expect_get_code().returning(move |address| {
+                if *address == CONFIG.contracts.ethereum_did_registry.address {
+                    Ok(vec![])
+                } else {
+                    Ok(code.clone())
+                }
+            });
+            let client = mock_custom_client_without_quorum(Box::new(client));
+
+            let report = client.verify().await.unwrap();
+
+            assert_eq!(
+                vec![
+                    VerificationMismatch::ChainId {
+                        expected: CONFIG.chain_id,
+                        actual: CONFIG.chain_id + 1,
+                    },
+                    VerificationMismatch::ContractNotDeployed {
+                        contract: "EthereumExtDidRegistry".to_string(),
+                        address: CONFIG.contracts.ethereum_did_registry.address.clone(),
+                    },
+                ],
+                report.mismatches
+            );
+        }
+
+        #[async_std::test]
+        async fn verify_proxy_checks_implementation_code() {
+            let mut client = MockClient::new();
+            client
+                .expect_get_chain_id()
+                .returning(|| Ok(CONFIG.chain_id));
+            client.expect_get_storage_at().returning(|_, _| {
+                let mut value = vec![0; 12];
+                value.extend(hex::decode(&IMPLEMENTATION_ADDRESS[2..]).unwrap());
+                Ok(value)
+            });
+            let code = dispatcher_code();
+            client.expect_get_code().returning(move |address| {
+                if address.as_ref() == IMPLEMENTATION_ADDRESS {
+                    // implementation without dispatcher
+                    Ok(vec![0x60, 0x80, 0x60, 0x40, 0x52])
+                } else {
+                    Ok(code.clone())
+                }
+            });
+            let client = mock_custom_client_without_quorum(Box::new(client));
+
+            let report = client.verify().await.unwrap();
+
+            assert_eq!(client.contracts.len(), report.mismatches.len());
+            assert!(report.mismatches.iter().all(|mismatch| matches!(
+                mismatch,
+                VerificationMismatch::ContractFunctionsMissing { .. }
+            )));
+        }
+
+        #[async_std::test]
+        async fn connect_chain_id_mismatch() {
+            let code = dispatcher_code();
+            let mut client = client_mock(CONFIG.chain_id + 1);
+            client
+                .expect_get_code()
+                .returning(move |_| Ok(code.clone()));
+
+            let err = LedgerClientBuilder::new()
+                .set_chain_id(CONFIG.chain_id)
+                .set_client(Box::new(client))
+                .set_contract_configs(&contracts())
+                .connect()
+                .await
+                .unwrap_err();
+
+            assert!(matches!(err, VdrError::ClientInvalidState { .. }));
+        }
+    }
+
     #[cfg(feature = "ledger_test")]
     mod ping {
         use super::*;
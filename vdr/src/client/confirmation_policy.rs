@@ -0,0 +1,53 @@
+use serde_derive::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Policy defining how long to wait for a write transaction to be confirmed by the network
+///
+/// Default policy waits without time limit until the transaction is included into a block.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConfirmationPolicy {
+    /// Number of blocks (including the block with the transaction) to wait for. Ignored in instant finality mode
+    pub confirmations: Option<u64>,
+    /// Interval between transaction status checks in milliseconds
+    pub poll_interval: Option<u64>,
+    /// Max time to wait for the transaction in milliseconds. Wait without limit if not set
+    pub timeout: Option<u64>,
+    /// Treat inclusion into a block as final.
+    /// Should be used for networks with immediate finality consensus (QBFT, IBFT 2.0).
+    /// Waits for the same single block as the default `confirmations`, but overrides `confirmations` set in the
+    /// same policy, so a policy shared between deployments can be switched to instant finality without changing
+    /// the confirmation depth
+    pub instant_finality: Option<bool>,
+}
+
+const DEFAULT_CONFIRMATIONS: u64 = 1;
+const DEFAULT_POLL_INTERVAL: u64 = 200;
+
+impl ConfirmationPolicy {
+    /// Create policy treating inclusion into a block as final (QBFT, IBFT 2.0 consensus)
+    pub fn instant_finality() -> ConfirmationPolicy {
+        ConfirmationPolicy {
+            instant_finality: Some(true),
+            ..ConfirmationPolicy::default()
+        }
+    }
+
+    pub(crate) fn confirmations(&self) -> u64 {
+        if self.is_instant_finality() {
+            return DEFAULT_CONFIRMATIONS;
+        }
+        self.confirmations.unwrap_or(DEFAULT_CONFIRMATIONS)
+    }
+
+    pub(crate) fn poll_interval(&self) -> Duration {
+        Duration::from_millis(self.poll_interval.unwrap_or(DEFAULT_POLL_INTERVAL))
+    }
+
+    pub(crate) fn timeout(&self) -> Option<Duration> {
+        self.timeout.map(Duration::from_millis)
+    }
+
+    pub(crate) fn is_instant_finality(&self) -> bool {
+        self.instant_finality.unwrap_or(false)
+    }
+}
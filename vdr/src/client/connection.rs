@@ -0,0 +1,182 @@
+use async_trait::async_trait;
+use serde_derive::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+    sync::Arc,
+};
+
+use crate::error::{VdrError, VdrResult};
+
+/// Settings of the authenticated HTTP connection to the RPC node
+///
+/// Use for nodes behind gateways requiring custom headers, JWT (Besu `--rpc-http-authentication`), basic auth or
+/// client certificates. Only one of `bearer_token`, `basic_auth` and `token_provider` can be set.
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionConfig {
+    /// Headers added to every request
+    pub headers: Option<HashMap<String, String>>,
+    /// Token sent in `Authorization: Bearer` header of every request
+    pub bearer_token: Option<String>,
+    /// Credentials sent in `Authorization: Basic` header of every request
+    pub basic_auth: Option<BasicAuth>,
+    /// TLS settings: custom CA certificate and client certificate for mutual TLS
+    pub tls: Option<TlsConfig>,
+    /// Provider of expiring bearer tokens. The token is requested before every request and the connection is
+    /// re-created when the token changes. Not supported for wasm: tokens can not be refreshed there, so use
+    /// `bearer_token` and re-create the client when the token expires
+    #[serde(skip)]
+    pub token_provider: Option<Arc<dyn TokenProvider>>,
+}
+
+/// Credentials of HTTP basic authentication
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BasicAuth {
+    pub username: String,
+    pub password: Option<String>,
+}
+
+/// TLS settings of the connection. Certificates are PEM encoded
+///
+/// TLS settings are not supported for wasm: certificates are managed by the browser.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TlsConfig {
+    /// CA certificate trusted in addition to the system root certificates (for nodes with self-signed certificates)
+    pub ca_certificate: Option<String>,
+    /// Client certificate used for mutual TLS. Requires `client_key`
+    pub client_certificate: Option<String>,
+    /// PKCS#8 private key of the client certificate
+    pub client_key: Option<String>,
+    /// Accept invalid node certificates. Must be used for testing only
+    pub accept_invalid_certificates: Option<bool>,
+}
+
+/// Provider of bearer tokens used to authenticate requests to the RPC node
+///
+/// The provider is called before every request, so implementations should cache the token until it expires.
+#[cfg_attr(not(feature = "wasm"), async_trait)]
+#[cfg_attr(feature = "wasm", async_trait(?Send))]
+pub trait TokenProvider: Sync + Send {
+    /// Get the token valid for the next request
+    ///
+    /// # Returns
+    /// bearer token (without `Bearer` prefix)
+    async fn token(&self) -> VdrResult<String>;
+}
+
+impl ConnectionConfig {
+    pub(crate) fn validate(&self) -> VdrResult<()> {
+        let authentications = [
+            self.bearer_token.is_some(),
+            self.basic_auth.is_some(),
+            self.token_provider.is_some(),
+        ];
+        if authentications.into_iter().filter(|set| *set).count() > 1 {
+            return Err(VdrError::ClientInvalidState(
+                "Only one of `bearer_token`, `basic_auth` and `token_provider` can be set"
+                    .to_string(),
+            ));
+        }
+
+        if let Some(tls) = self.tls.as_ref() {
+            if tls.client_certificate.is_some() != tls.client_key.is_some() {
+                return Err(VdrError::ClientInvalidState(
+                    "Both `client_certificate` and `client_key` must be set for mutual TLS"
+                        .to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Debug for ConnectionConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // credentials are not printed
+        write!(
+            f,
+            r#"ConnectionConfig {{ headers: {:?}, bearer_token: {}, basic_auth: {:?}, tls: {:?}, token_provider: {} }}"#,
+            self.headers
+                .as_ref()
+                .map(|headers| headers.keys().collect::<Vec<_>>()),
+            self.bearer_token.is_some(),
+            self.basic_auth,
+            self.tls
+                .as_ref()
+                .map(|tls| tls.client_certificate.is_some()),
+            self.token_provider.is_some(),
+        )
+    }
+}
+
+impl Debug for BasicAuth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, r#"BasicAuth {{ username: {} }}"#, self.username)
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use rstest::rstest;
+
+    struct StaticTokenProvider;
+
+    #[async_trait]
+    impl TokenProvider for StaticTokenProvider {
+        async fn token(&self) -> VdrResult<String> {
+            Ok("token".to_string())
+        }
+    }
+
+    fn basic_auth() -> Option<BasicAuth> {
+        Some(BasicAuth {
+            username: "user".to_string(),
+            password: Some("password".to_string()),
+        })
+    }
+
+    #[rstest]
+    #[case::bearer_and_basic(ConnectionConfig {
+        bearer_token: Some("token".to_string()),
+        basic_auth: basic_auth(),
+        ..ConnectionConfig::default()
+    })]
+    #[case::basic_and_provider(ConnectionConfig {
+        basic_auth: basic_auth(),
+        token_provider: Some(Arc::new(StaticTokenProvider)),
+        ..ConnectionConfig::default()
+    })]
+    #[case::certificate_without_key(ConnectionConfig {
+        tls: Some(TlsConfig {
+            client_certificate: Some("certificate".to_string()),
+            ..TlsConfig::default()
+        }),
+        ..ConnectionConfig::default()
+    })]
+    fn validate_invalid_config(#[case] config: ConnectionConfig) {
+        let err = config.validate().unwrap_err();
+
+        assert!(matches!(err, VdrError::ClientInvalidState { .. }));
+    }
+
+    #[test]
+    fn debug_hides_credentials() {
+        let config = ConnectionConfig {
+            headers: Some(HashMap::from([(
+                "X-Api-Key".to_string(),
+                "secret".to_string(),
+            )])),
+            basic_auth: basic_auth(),
+            ..ConnectionConfig::default()
+        };
+
+        let debug = format!("{:?}", config);
+
+        assert!(debug.contains("X-Api-Key"));
+        assert!(!debug.contains("secret"));
+        assert!(!debug.contains("password"));
+    }
+}
@@ -0,0 +1,252 @@
+use futures::{stream, Stream, TryStreamExt};
+use log::{trace, warn};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    client::LedgerClient,
+    error::{VdrError, VdrResult},
+    types::{Block, EventLog, EventQuery},
+};
+
+/// Settings of splitting event queries over large block ranges into several `eth_getLogs` requests
+///
+/// Default settings query events by chunks of 1000 blocks. If the node rejects a request because the block range or
+/// the response size is over its limits, the chunk size is halved (down to `min_chunk_size`) and the request is repeated.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct EventPagination {
+    /// Number of blocks queried with one request
+    pub chunk_size: Option<u64>,
+    /// Min number of blocks queried with one request when the chunk size is reduced
+    pub min_chunk_size: Option<u64>,
+}
+
+const DEFAULT_CHUNK_SIZE: u64 = 1000;
+const DEFAULT_MIN_CHUNK_SIZE: u64 = 1;
+
+impl EventPagination {
+    pub(crate) fn chunk_size(&self) -> u64 {
+        self.chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE).max(1)
+    }
+
+    pub(crate) fn min_chunk_size(&self) -> u64 {
+        self.min_chunk_size
+            .unwrap_or(DEFAULT_MIN_CHUNK_SIZE)
+            .clamp(1, self.chunk_size())
+    }
+}
+
+struct PaginationState {
+    query: EventQuery,
+    next_block: u64,
+    last_block: Option<u64>,
+    chunk_size: u64,
+}
+
+/// Query events matching the query block by block range chunks
+///
+/// # Params
+///  - `client`: [LedgerClient] - client used to query events
+///  - `query`: [EventQuery] - events query. If `to_block` is not set, events are queried up to the latest block
+///  - `pagination`: [EventPagination] - chunking settings
+///
+/// # Returns
+///  events - stream of log events in the order of blocks
+pub(crate) fn paginate_events<'a>(
+    client: &'a LedgerClient,
+    query: &EventQuery,
+    pagination: &EventPagination,
+) -> impl Stream<Item = VdrResult<EventLog>> + 'a {
+    let min_chunk_size = pagination.min_chunk_size();
+    let state = PaginationState {
+        query: query.clone(),
+        next_block: query.from_block.as_ref().map(Block::value).unwrap_or(0),
+        last_block: query.to_block.as_ref().map(Block::value),
+        chunk_size: pagination.chunk_size(),
+    };
+
+    stream::try_unfold(state, move |state| {
+        query_next_chunk(client, state, min_chunk_size)
+    })
+    .map_ok(|events| stream::iter(events.into_iter().map(Ok)))
+    .try_flatten()
+}
+
+async fn query_next_chunk(
+    client: &LedgerClient,
+    mut state: PaginationState,
+    min_chunk_size: u64,
+) -> VdrResult<Option<(Vec<EventLog>, PaginationState)>> {
+    let last_block = match state.last_block {
+        Some(last_block) => last_block,
+        None => {
+            let latest_block = client.get_block(None).await?.number;
+            state.last_block = Some(latest_block);
+            latest_block
+        }
+    };
+
+    loop {
+        if state.next_block > last_block {
+            return Ok(None);
+        }
+
+        let to_block = state
+            .next_block
+            .saturating_add(state.chunk_size - 1)
+            .min(last_block);
+        let chunk_query = EventQuery {
+            from_block: Some(Block::from(state.next_block)),
+            to_block: Some(Block::from(to_block)),
+            ..state.query.clone()
+        };
+
+        match client.query_events(&chunk_query).await {
+            Ok(events) => {
+                trace!(
+                    "Received {} events for blocks {}..={}",
+                    events.len(),
+                    state.next_block,
+                    to_block
+                );
+                state.next_block = to_block + 1;
+                return Ok(Some((events, state)));
+            }
+            Err(VdrError::ClientQueryLimitExceeded(message))
+                if state.chunk_size > min_chunk_size =>
+            {
+                state.chunk_size = (state.chunk_size / 2).max(min_chunk_size);
+                warn!(
+                    "Event query limit exceeded: {}. Reducing chunk size to {} blocks",
+                    message, state.chunk_size
+                );
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::{
+        client::{
+            client::test::{mock_custom_client_without_quorum, CONFIG},
+            MockClient,
+        },
+        BlockDetails,
+    };
+    use mockall::{predicate::function, Sequence};
+
+    const LATEST_BLOCK: u64 = 25;
+
+    fn query(from_block: Option<u64>, to_block: Option<u64>) -> EventQuery {
+        EventQuery {
+            from_block: from_block.map(Block::from),
+            to_block: to_block.map(Block::from),
+            ..EventQuery::new(&CONFIG.contracts.ethereum_did_registry.address)
+        }
+    }
+
+    fn event(block: u64) -> EventLog {
+        EventLog::new(vec![vec![1; 32]], vec![1; 32], block)
+    }
+
+    fn expect_chunk(
+        client: &mut MockClient,
+        sequence: &mut Sequence,
+        from_block: u64,
+        to_block: u64,
+        response: VdrResult<Vec<EventLog>>,
+    ) {
+        client
+            .expect_query_events()
+            .with(function(move |query: &EventQuery| {
+                query.from_block == Some(Block::from(from_block))
+                    && query.to_block == Some(Block::from(to_block))
+            }))
+            .times(1)
+            .in_sequence(sequence)
+            .returning(move |_| response.clone());
+    }
+
+    async fn collect(
+        client: MockClient,
+        query: &EventQuery,
+        pagination: &EventPagination,
+    ) -> VdrResult<Vec<EventLog>> {
+        let client = mock_custom_client_without_quorum(Box::new(client));
+        paginate_events(&client, query, pagination)
+            .try_collect()
+            .await
+    }
+
+    #[async_std::test]
+    async fn paginate_events_up_to_latest_block() {
+        let mut client = MockClient::new();
+        let mut sequence = Sequence::new();
+        client.expect_get_block().returning(|_| {
+            Ok(BlockDetails {
+                number: LATEST_BLOCK,
+                timestamp: 0,
+            })
+        });
+        expect_chunk(&mut client, &mut sequence, 0, 9, Ok(vec![event(3)]));
+        expect_chunk(&mut client, &mut sequence, 10, 19, Ok(vec![]));
+        expect_chunk(
+            &mut client,
+            &mut sequence,
+            20,
+            25,
+            Ok(vec![event(20), event(25)]),
+        );
+        let pagination = EventPagination {
+            chunk_size: Some(10),
+            ..EventPagination::default()
+        };
+
+        let events = collect(client, &query(None, None), &pagination)
+            .await
+            .unwrap();
+
+        assert_eq!(vec![event(3), event(20), event(25)], events);
+    }
+
+    #[async_std::test]
+    async fn paginate_events_reduces_chunk_size() {
+        let mut client = MockClient::new();
+        let mut sequence = Sequence::new();
+        let limit_error = VdrError::ClientQueryLimitExceeded("Exceeds max range".to_string());
+        expect_chunk(&mut client, &mut sequence, 5, 14, Err(limit_error.clone()));
+        expect_chunk(&mut client, &mut sequence, 5, 9, Ok(vec![event(7)]));
+        expect_chunk(&mut client, &mut sequence, 10, 14, Ok(vec![event(14)]));
+        let pagination = EventPagination {
+            chunk_size: Some(10),
+            ..EventPagination::default()
+        };
+
+        let events = collect(client, &query(Some(5), Some(14)), &pagination)
+            .await
+            .unwrap();
+
+        assert_eq!(vec![event(7), event(14)], events);
+    }
+
+    #[async_std::test]
+    async fn paginate_events_min_chunk_size_reached() {
+        let mut client = MockClient::new();
+        let mut sequence = Sequence::new();
+        let limit_error = VdrError::ClientQueryLimitExceeded("Exceeds max range".to_string());
+        expect_chunk(&mut client, &mut sequence, 0, 3, Err(limit_error.clone()));
+        expect_chunk(&mut client, &mut sequence, 0, 1, Err(limit_error.clone()));
+        let pagination = EventPagination {
+            chunk_size: Some(4),
+            min_chunk_size: Some(2),
+        };
+
+        let err = collect(client, &query(Some(0), Some(10)), &pagination)
+            .await
+            .unwrap_err();
+
+        assert_eq!(limit_error, err);
+    }
+}
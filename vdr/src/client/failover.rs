@@ -0,0 +1,476 @@
+use async_trait::async_trait;
+use futures::future::join_all;
+use log::warn;
+use log_derive::{logfn, logfn_inputs};
+use std::{
+    fmt::{Debug, Formatter},
+    future::Future,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+use crate::{
+    client::{implementation::web3::client::Web3Client, Client},
+    error::{VdrError, VdrResult},
+    types::{
+        BlockTag, EventLog, EventQuery, EventStream, FeeHistory, PingStatus, TransactionReceipt,
+        TransactionStatus,
+    },
+    Address, BlockDetails, Transaction,
+};
+
+/// Client distributing requests across several RPC nodes of the same network
+///
+/// Read requests are rotated over healthy nodes in round-robin order.
+/// Write requests are sent to the first healthy node and fail over to the next one if the node is unreachable.
+/// A node failing with a transport error is marked as unhealthy and used again only after it answers a ping.
+///
+/// Note: this client does not verify responses. Use [crate::QuorumConfig] to check results against other nodes.
+pub struct FailoverClient {
+    clients: Vec<Box<dyn Client>>,
+    healthy: Vec<AtomicBool>,
+    next: AtomicUsize,
+}
+
+impl FailoverClient {
+    /// Create failover client connected to the given RPC nodes over HTTP
+    ///
+    /// # Params
+    ///  - `nodes`: [Vec] - RPC node endpoints. Writes prefer nodes in the given order
+    ///
+    /// # Returns
+    ///  client: [FailoverClient] - client to use as [LedgerClient](crate::LedgerClient) transport
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn new(nodes: &[String]) -> VdrResult<FailoverClient> {
+        let clients = nodes
+            .iter()
+            .map(|node_address| {
+                let client: Box<dyn Client> = Box::new(Web3Client::new(node_address)?);
+                Ok(client)
+            })
+            .collect::<VdrResult<Vec<_>>>()?;
+
+        FailoverClient::with_clients(clients)
+    }
+
+    /// Create failover client on top of custom client implementations
+    ///
+    /// # Params
+    ///  - `clients`: [Vec] - clients connected to the nodes. Writes prefer clients in the given order
+    ///
+    /// # Returns
+    ///  client: [FailoverClient] - client to use as [LedgerClient](crate::LedgerClient) transport
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn with_clients(clients: Vec<Box<dyn Client>>) -> VdrResult<FailoverClient> {
+        if clients.is_empty() {
+            return Err(VdrError::ClientInvalidState(
+                "At least one node must be provided".to_string(),
+            ));
+        }
+
+        let healthy = clients.iter().map(|_| AtomicBool::new(true)).collect();
+
+        Ok(FailoverClient {
+            clients,
+            healthy,
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    /// Ping all nodes and update their health state
+    ///
+    /// # Returns
+    ///  statuses: [Vec] - ping status of every node in the order they were provided
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn check_health(&self) -> Vec<PingStatus> {
+        let pings = (0..self.clients.len()).map(|index| async move {
+            match self.clients[index].get_block(None).await {
+                Ok(block) => {
+                    self.set_healthy(index, true);
+                    PingStatus::ok(block.number, block.timestamp)
+                }
+                Err(err) => {
+                    self.set_healthy(index, false);
+                    PingStatus::err(err.to_string().as_str())
+                }
+            }
+        });
+        join_all(pings).await
+    }
+
+    fn is_healthy(&self, index: usize) -> bool {
+        self.healthy[index].load(Ordering::Relaxed)
+    }
+
+    fn set_healthy(&self, index: usize, healthy: bool) {
+        self.healthy[index].store(healthy, Ordering::Relaxed)
+    }
+
+    /// Only transport failures are retried: errors returned by the node (reverts, rejected transactions, missing
+    /// receipts) would be the same on other nodes or must not be sent twice
+    fn is_node_failure(error: &VdrError) -> bool {
+        matches!(error, VdrError::ClientNodeUnreachable)
+    }
+
+    /// Get node indexes in the order they should be tried: healthy nodes first starting from `start`
+    fn nodes_order(&self, start: usize) -> Vec<usize> {
+        let count = self.clients.len();
+        let (healthy, unhealthy): (Vec<usize>, Vec<usize>) = (0..count)
+            .map(|offset| (start + offset) % count)
+            .partition(|index| self.is_healthy(*index));
+        healthy.into_iter().chain(unhealthy).collect()
+    }
+
+    fn next_read_node(&self) -> usize {
+        self.next.fetch_add(1, Ordering::Relaxed) % self.clients.len()
+    }
+
+    async fn ping(&self, index: usize) -> bool {
+        let alive = self.clients[index].get_block(None).await.is_ok();
+        self.set_healthy(index, alive);
+        alive
+    }
+
+    async fn execute<'a, T, F, Fut>(&'a self, start: usize, request: F) -> VdrResult<T>
+    where
+        F: Fn(&'a dyn Client) -> Fut,
+        Fut: Future<Output = VdrResult<T>>,
+    {
+        let mut last_error = VdrError::ClientNodeUnreachable;
+
+        for index in self.nodes_order(start) {
+            if !self.is_healthy(index) && !self.ping(index).await {
+                continue;
+            }
+
+            match request(self.clients[index].as_ref()).await {
+                Ok(result) => return Ok(result),
+                Err(err) if Self::is_node_failure(&err) => {
+                    warn!(
+                        "Error: {:?} during sending request to node {}. Trying next node",
+                        err, index
+                    );
+                    self.set_healthy(index, false);
+                    last_error = err;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        Err(last_error)
+    }
+}
+
+#[cfg_attr(not(feature = "wasm"), async_trait)]
+#[cfg_attr(feature = "wasm", async_trait(? Send))]
+impl Client for FailoverClient {
+    async fn get_transaction_count(&self, address: &Address) -> VdrResult<u64> {
+        self.execute(self.next_read_node(), |client| {
+            client.get_transaction_count(address)
+        })
+        .await
+    }
+
+    async fn send_transaction(&self, transaction: &[u8]) -> VdrResult<Vec<u8>> {
+        self.execute(0, |client| client.send_transaction(transaction))
+            .await
+    }
+
+    async fn get_transaction_status(&self, hash: &[u8]) -> VdrResult<TransactionStatus> {
+        self.execute(self.next_read_node(), |client| {
+            client.get_transaction_status(hash)
+        })
+        .await
+    }
+
+    async fn call_transaction(
+        &self,
+        to: &str,
+        transaction: &[u8],
+        block: Option<BlockTag>,
+    ) -> VdrResult<Vec<u8>> {
+        self.execute(self.next_read_node(), |client| {
+            client.call_transaction(to, transaction, block.clone())
+        })
+        .await
+    }
+
+    async fn query_events(&self, query: &EventQuery) -> VdrResult<Vec<EventLog>> {
+        self.execute(self.next_read_node(), |client| client.query_events(query))
+            .await
+    }
+
+    async fn subscribe_events(&self, query: &EventQuery) -> VdrResult<EventStream> {
+        self.execute(0, |client| client.subscribe_events(query))
+            .await
+    }
+
+    async fn simulate_transaction(&self, transaction: &Transaction) -> VdrResult<Vec<u8>> {
+        self.execute(self.next_read_node(), |client| {
+            client.simulate_transaction(transaction)
+        })
+        .await
+    }
+
+    async fn estimate_gas(&self, transaction: &Transaction) -> VdrResult<u64> {
+        self.execute(self.next_read_node(), |client| {
+            client.estimate_gas(transaction)
+        })
+        .await
+    }
+
+    async fn get_gas_price(&self) -> VdrResult<u64> {
+        self.execute(self.next_read_node(), |client| client.get_gas_price())
+            .await
+    }
+
+    async fn get_fee_history(
+        &self,
+        block_count: u64,
+        reward_percentile: f64,
+    ) -> VdrResult<FeeHistory> {
+        self.execute(self.next_read_node(), |client| {
+            client.get_fee_history(block_count, reward_percentile)
+        })
+        .await
+    }
+
+    async fn get_chain_id(&self) -> VdrResult<u64> {
+        self.execute(self.next_read_node(), |client| client.get_chain_id())
+            .await
+    }
+
+    async fn get_code(&self, address: &Address) -> VdrResult<Vec<u8>> {
+        self.execute(self.next_read_node(), |client| client.get_code(address))
+            .await
+    }
+
+    async fn get_storage_at(&self, address: &Address, slot: &[u8]) -> VdrResult<Vec<u8>> {
+        self.execute(self.next_read_node(), |client| {
+            client.get_storage_at(address, slot)
+        })
+        .await
+    }
+
+    async fn get_receipt(&self, hash: &[u8]) -> VdrResult<TransactionReceipt> {
+        self.execute(self.next_read_node(), |client| client.get_receipt(hash))
+            .await
+    }
+
+    async fn get_block(&self, block: Option<u64>) -> VdrResult<BlockDetails> {
+        self.execute(self.next_read_node(), |client| client.get_block(block))
+            .await
+    }
+
+    async fn get_transaction(&self, hash: &[u8]) -> VdrResult<Option<Transaction>> {
+        self.execute(self.next_read_node(), |client| client.get_transaction(hash))
+            .await
+    }
+}
+
+impl Debug for FailoverClient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, r#"FailoverClient {{ nodes: {} }}"#, self.clients.len())
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::client::MockClient;
+
+    const DEAD_NODE_ADDRESS: &str = "http://127.0.0.1:1";
+
+    const BLOCK: BlockDetails = BlockDetails {
+        number: 1,
+        timestamp: 1,
+    };
+
+    fn mock_node(alive: bool) -> MockClient {
+        let mut client = MockClient::new();
+        client.expect_get_block().returning(move |_| {
+            if alive {
+                Ok(BLOCK.clone())
+            } else {
+                Err(VdrError::ClientNodeUnreachable)
+            }
+        });
+        client
+    }
+
+    fn mock_read_node(response: VdrResult<Vec<u8>>, calls: usize) -> Box<dyn Client> {
+        let mut client = mock_node(response.is_ok());
+        client
+            .expect_call_transaction()
+            .times(calls)
+            .returning(move |_, _, _| response.clone());
+        Box::new(client)
+    }
+
+    /// Client connected to a port nobody listens on
+    fn dead_node() -> Box<dyn Client> {
+        Box::new(Web3Client::new(DEAD_NODE_ADDRESS).unwrap())
+    }
+
+    fn mock_write_node(response: VdrResult<Vec<u8>>, calls: usize) -> Box<dyn Client> {
+        let mut client = mock_node(response.is_ok());
+        client
+            .expect_send_transaction()
+            .times(calls)
+            .returning(move |_| response.clone());
+        Box::new(client)
+    }
+
+    #[test]
+    fn create_failover_client_without_nodes() {
+        let err = FailoverClient::with_clients(vec![]).unwrap_err();
+
+        assert!(matches!(err, VdrError::ClientInvalidState { .. }));
+    }
+
+    #[async_std::test]
+    async fn read_requests_rotated_across_nodes() {
+        let client = FailoverClient::with_clients(vec![
+            mock_read_node(Ok(vec![1]), 2),
+            mock_read_node(Ok(vec![2]), 2),
+        ])
+        .unwrap();
+
+        let mut responses = vec![];
+        for _ in 0..4 {
+            responses.push(client.call_transaction("", &[], None).await.unwrap());
+        }
+
+        assert_eq!(vec![vec![1], vec![2], vec![1], vec![2]], responses);
+    }
+
+    #[async_std::test]
+    async fn read_request_fails_over_unreachable_node() {
+        let client = FailoverClient::with_clients(vec![
+            mock_read_node(Err(VdrError::ClientNodeUnreachable), 1),
+            mock_read_node(Ok(vec![2]), 2),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            vec![2],
+            client.call_transaction("", &[], None).await.unwrap()
+        );
+        // first node is marked unhealthy and skipped until it answers a ping
+        assert_eq!(
+            vec![2],
+            client.call_transaction("", &[], None).await.unwrap()
+        );
+        assert!(!client.is_healthy(0));
+    }
+
+    #[async_std::test]
+    async fn query_events_fails_over_dead_node() {
+        let mut node = mock_node(true);
+        node.expect_query_events()
+            .times(1)
+            .returning(|_| Ok(vec![]));
+        let client = FailoverClient::with_clients(vec![dead_node(), Box::new(node)]).unwrap();
+
+        let events = client
+            .query_events(&EventQuery::new(&Address::from(
+                "0x0000000000000000000000000000000000003333",
+            )))
+            .await
+            .unwrap();
+
+        assert!(events.is_empty());
+        assert!(!client.is_healthy(0));
+    }
+
+    #[async_std::test]
+    async fn get_block_fails_over_dead_node() {
+        let client =
+            FailoverClient::with_clients(vec![dead_node(), Box::new(mock_node(true))]).unwrap();
+
+        assert_eq!(BLOCK, client.get_block(None).await.unwrap());
+        assert!(!client.is_healthy(0));
+    }
+
+    #[async_std::test]
+    async fn get_transaction_fails_over_dead_node() {
+        let mut node = mock_node(true);
+        node.expect_get_transaction()
+            .times(1)
+            .returning(|_| Ok(None));
+        let client = FailoverClient::with_clients(vec![dead_node(), Box::new(node)]).unwrap();
+
+        assert_eq!(None, client.get_transaction(&[0; 32]).await.unwrap());
+        assert!(!client.is_healthy(0));
+    }
+
+    #[async_std::test]
+    async fn read_request_does_not_fail_over_on_revert() {
+        let revert = VdrError::ClientTransactionReverted("0x".to_string());
+        let client = FailoverClient::with_clients(vec![
+            mock_read_node(Err(revert.clone()), 1),
+            mock_read_node(Ok(vec![2]), 0),
+        ])
+        .unwrap();
+
+        let err = client.call_transaction("", &[], None).await.unwrap_err();
+
+        assert_eq!(revert, err);
+    }
+
+    #[async_std::test]
+    async fn write_request_does_not_fail_over_on_rpc_error() {
+        let rejected = VdrError::ClientUnexpectedError(
+            r#"{"code":-32001,"message":"Nonce too low"}"#.to_string(),
+        );
+        let client = FailoverClient::with_clients(vec![
+            mock_write_node(Err(rejected.clone()), 1),
+            mock_write_node(Ok(vec![2]), 0),
+        ])
+        .unwrap();
+
+        let err = client.send_transaction(&[]).await.unwrap_err();
+
+        assert_eq!(rejected, err);
+        assert!(client.is_healthy(0));
+    }
+
+    #[async_std::test]
+    async fn write_request_fails_over_to_next_node() {
+        let client = FailoverClient::with_clients(vec![
+            mock_write_node(Err(VdrError::ClientNodeUnreachable), 1),
+            mock_write_node(Ok(vec![2]), 1),
+        ])
+        .unwrap();
+
+        assert_eq!(vec![2], client.send_transaction(&[]).await.unwrap());
+    }
+
+    #[async_std::test]
+    async fn request_fails_when_all_nodes_unreachable() {
+        let client = FailoverClient::with_clients(vec![
+            mock_write_node(Err(VdrError::ClientNodeUnreachable), 1),
+            mock_write_node(Err(VdrError::ClientNodeUnreachable), 1),
+        ])
+        .unwrap();
+
+        let err = client.send_transaction(&[]).await.unwrap_err();
+
+        assert_eq!(VdrError::ClientNodeUnreachable, err);
+    }
+
+    #[async_std::test]
+    async fn check_health_updates_node_state() {
+        let nodes: Vec<Box<dyn Client>> =
+            vec![Box::new(mock_node(true)), Box::new(mock_node(false))];
+        let client = FailoverClient::with_clients(nodes).unwrap();
+
+        let statuses = client.check_health().await;
+
+        assert_eq!(PingStatus::ok(BLOCK.number, BLOCK.timestamp), statuses[0]);
+        assert!(client.is_healthy(0));
+        assert!(!client.is_healthy(1));
+    }
+}
@@ -0,0 +1,195 @@
+use log_derive::{logfn, logfn_inputs};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    client::Client,
+    error::VdrResult,
+    types::{Transaction, TransactionEnvelope, TransactionFees},
+};
+
+/// Strategy defining gas limit and fees of write transactions built by the client
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum FeeStrategy {
+    /// Gas free network: max available gas limit and zero fees
+    #[default]
+    Free,
+    /// Use the given gas limit and fees for every transaction
+    Fixed(TransactionFees),
+    /// Estimate gas limit (`eth_estimateGas`) and fees (`eth_gasPrice` for `Legacy` and `AccessList` transactions,
+    /// `eth_feeHistory` for `DynamicFee` transactions) and multiply them by `multiplier` (1.25 if not set)
+    Estimated { multiplier: Option<f64> },
+}
+
+const DEFAULT_MULTIPLIER: f64 = 1.25;
+/// Number of latest blocks used to estimate priority fee
+const FEE_HISTORY_BLOCKS: u64 = 5;
+/// Percentile of priority fees paid in the latest blocks
+const FEE_HISTORY_PERCENTILE: f64 = 50.0;
+
+impl FeeStrategy {
+    /// Create strategy estimating gas limit and fees with the default multiplier
+    pub fn estimated() -> FeeStrategy {
+        FeeStrategy::Estimated { multiplier: None }
+    }
+
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub(crate) async fn fees(
+        &self,
+        client: &dyn Client,
+        transaction: &Transaction,
+    ) -> VdrResult<TransactionFees> {
+        match self {
+            // unset values fall back to the max available gas limit and zero fees
+            FeeStrategy::Free => Ok(TransactionFees::default()),
+            FeeStrategy::Fixed(fees) => Ok(fees.clone()),
+            FeeStrategy::Estimated { multiplier } => {
+                let multiplier = multiplier.unwrap_or(DEFAULT_MULTIPLIER);
+                let apply = |value: u64| (value as f64 * multiplier).ceil() as u64;
+
+                let gas_limit = apply(client.estimate_gas(transaction).await?);
+
+                match transaction.envelope {
+                    TransactionEnvelope::Legacy | TransactionEnvelope::AccessList => {
+                        Ok(TransactionFees {
+                            gas_limit: Some(gas_limit),
+                            gas_price: Some(apply(client.get_gas_price().await?)),
+                            ..TransactionFees::default()
+                        })
+                    }
+                    TransactionEnvelope::DynamicFee => {
+                        let fee_history = client
+                            .get_fee_history(FEE_HISTORY_BLOCKS, FEE_HISTORY_PERCENTILE)
+                            .await?;
+                        let max_priority_fee_per_gas = apply(fee_history.max_priority_fee());
+                        let max_fee_per_gas =
+                            apply(fee_history.next_base_fee()) + max_priority_fee_per_gas;
+                        Ok(TransactionFees {
+                            gas_limit: Some(gas_limit),
+                            max_fee_per_gas: Some(max_fee_per_gas),
+                            max_priority_fee_per_gas: Some(max_priority_fee_per_gas),
+                            ..TransactionFees::default()
+                        })
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::{
+        client::MockClient,
+        error::VdrError,
+        types::{transaction::test::write_transaction, FeeHistory},
+    };
+
+    fn transaction(envelope: TransactionEnvelope) -> Transaction {
+        Transaction {
+            envelope,
+            ..write_transaction()
+        }
+    }
+
+    fn mock_client() -> MockClient {
+        let mut client = MockClient::new();
+        client.expect_estimate_gas().returning(|_| Ok(100_000));
+        client.expect_get_gas_price().returning(|| Ok(1_000));
+        client.expect_get_fee_history().returning(|_, _| {
+            Ok(FeeHistory {
+                base_fee_per_gas: vec![800, 1_000],
+                priority_fee_per_gas: vec![100, 200],
+            })
+        });
+        client
+    }
+
+    #[async_std::test]
+    async fn free_strategy_fees() {
+        let fees = FeeStrategy::Free
+            .fees(
+                &MockClient::new(),
+                &transaction(TransactionEnvelope::Legacy),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(TransactionFees::default(), fees);
+    }
+
+    #[async_std::test]
+    async fn fixed_strategy_fees() {
+        let expected_fees = TransactionFees {
+            gas_limit: Some(50_000),
+            gas_price: Some(10),
+            ..TransactionFees::default()
+        };
+
+        let fees = FeeStrategy::Fixed(expected_fees.clone())
+            .fees(
+                &MockClient::new(),
+                &transaction(TransactionEnvelope::Legacy),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(expected_fees, fees);
+    }
+
+    #[async_std::test]
+    async fn estimated_strategy_legacy_fees() {
+        let fees = FeeStrategy::Estimated {
+            multiplier: Some(2.0),
+        }
+        .fees(&mock_client(), &transaction(TransactionEnvelope::Legacy))
+        .await
+        .unwrap();
+
+        assert_eq!(
+            TransactionFees {
+                gas_limit: Some(200_000),
+                gas_price: Some(2_000),
+                ..TransactionFees::default()
+            },
+            fees
+        );
+    }
+
+    #[async_std::test]
+    async fn estimated_strategy_dynamic_fees() {
+        let fees = FeeStrategy::estimated()
+            .fees(
+                &mock_client(),
+                &transaction(TransactionEnvelope::DynamicFee),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            TransactionFees {
+                gas_limit: Some(125_000),
+                max_fee_per_gas: Some(1_500),
+                max_priority_fee_per_gas: Some(250),
+                ..TransactionFees::default()
+            },
+            fees
+        );
+    }
+
+    #[async_std::test]
+    async fn estimated_strategy_reverted_transaction() {
+        let mut client = MockClient::new();
+        client
+            .expect_estimate_gas()
+            .returning(|_| Err(VdrError::ClientTransactionReverted("0x".to_string())));
+
+        let err = FeeStrategy::estimated()
+            .fees(&client, &transaction(TransactionEnvelope::Legacy))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, VdrError::ClientTransactionReverted { .. }));
+    }
+}
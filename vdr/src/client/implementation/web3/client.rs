@@ -1,63 +1,369 @@
 use crate::{
-    client::Client,
+    client::{implementation::web3::transport::build_http, Client, ConnectionConfig},
     error::{VdrError, VdrResult},
-    types::EventQuery,
+    types::{
+        AccessListItem, BlockTag, EventQuery, FeeHistory, ReceiptLog, TransactionEnvelope,
+        TransactionFees, TransactionReceipt, TransactionStatus,
+    },
     Address, Block, BlockDetails, Transaction,
 };
 
 use async_trait::async_trait;
 use ethereum_types::{H160, U64};
+use jsonrpc_core::types::error::ErrorCode;
 use log::{trace, warn};
 use log_derive::{logfn, logfn_inputs};
-use serde_json::json;
+use serde_json::{json, Value};
 use std::{
     fmt::{Debug, Formatter},
     str::FromStr,
-    time::Duration,
 };
 
+#[cfg(not(feature = "wasm"))]
+use futures::StreamExt;
 #[cfg(not(feature = "wasm"))]
 use web3::{
-    api::Eth,
-    transports::Http,
+    transports::{Either, Http, WebSocket},
     types::{
-        Address as EthAddress, BlockId, BlockNumber, Bytes, CallRequest, FilterBuilder,
-        TransactionId, H256,
+        Address as EthAddress, BlockId, BlockNumber, Bytes, CallRequest, FilterBuilder, Log,
+        Transaction as EthTransaction, H256, U256,
     },
-    Web3,
+    Error as Web3Error, Transport, Web3,
 };
 
 use crate::types::EventLog;
+#[cfg(not(feature = "wasm"))]
+use crate::{client::implementation::web3::transport::TokenHttp, types::EventStream};
 #[cfg(feature = "wasm")]
 use web3_wasm::{
-    api::Eth,
     transports::Http,
     types::{
-        Address as EthAddress, BlockId, BlockNumber, Bytes, CallRequest, FilterBuilder,
-        TransactionId, H256,
+        Address as EthAddress, BlockId, BlockNumber, Bytes, CallRequest, FilterBuilder, Log,
+        Transaction as EthTransaction, H256, U256,
     },
-    Web3,
+    Error as Web3Error, Transport, Web3,
 };
 
+/// Transport used to connect to the node: HTTP or WebSocket (WebSocket is not available for wasm).
+/// HTTP requests are authenticated with refreshed tokens if the token provider is set
+#[cfg(not(feature = "wasm"))]
+type Web3Transport = Either<Either<Http, TokenHttp>, WebSocket>;
+#[cfg(feature = "wasm")]
+type Web3Transport = Http;
+
+/// JSON-RPC error code returned by nodes when a request exceeds their limits (EIP-1474)
+const LIMIT_EXCEEDED_ERROR_CODE: i64 = -32005;
+/// Node error messages returned when `eth_getLogs` block range or response size exceeds the node limits
+const QUERY_LIMIT_ERRORS: [&str; 5] = [
+    // Besu
+    "exceeds max range",
+    "exceeds maximum rpc range limit",
+    // Geth
+    "exceed maximum block range",
+    "query returned more than",
+    // Erigon
+    "max results",
+];
+
 pub struct Web3Client {
-    client: Web3<Http>,
+    client: Web3<Web3Transport>,
+    /// Duplex connection used for event subscriptions. Set only when connected over WebSocket
+    #[cfg(not(feature = "wasm"))]
+    subscriptions: Option<Web3<WebSocket>>,
 }
 
-const POLL_INTERVAL: u64 = 200;
-const NUMBER_TX_CONFIRMATIONS: usize = 1; // FIXME: what number of confirmation events should we wait? 2n+1?
-
 impl Web3Client {
     #[logfn(Info)]
     #[logfn_inputs(Debug)]
     pub fn new(node_address: &str) -> VdrResult<Web3Client> {
         let transport = Http::new(node_address).map_err(|_| VdrError::ClientNodeUnreachable)?;
-        let web3 = Web3::new(transport);
-        let web3_client = Web3Client { client: web3 };
+        #[cfg(not(feature = "wasm"))]
+        let transport = Either::Left(Either::Left(transport));
+        Ok(Web3Client::with_transport(transport))
+    }
+
+    /// Create client connected to the node over authenticated HTTP connection
+    ///
+    /// # Params
+    ///  - `node_address`: [String] - HTTP RPC node endpoint
+    ///  - `config`: [ConnectionConfig] - headers, authentication and TLS settings of the connection
+    ///
+    /// # Returns
+    ///  client: [Web3Client] - client connected to the node
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn new_with_config(node_address: &str, config: &ConnectionConfig) -> VdrResult<Web3Client> {
+        #[cfg(not(feature = "wasm"))]
+        let transport = match config.token_provider.clone() {
+            Some(token_provider) => Either::Left(Either::Right(TokenHttp::new(
+                node_address,
+                config,
+                token_provider,
+            )?)),
+            None => Either::Left(Either::Left(build_http(node_address, config, None)?)),
+        };
+
+        #[cfg(feature = "wasm")]
+        let transport = match config.token_provider {
+            Some(_) => {
+                return Err(VdrError::ClientInvalidState(
+                    "Token provider is not supported for wasm".to_string(),
+                ))
+            }
+            None => build_http(node_address, config, None)?,
+        };
+
+        Ok(Web3Client::with_transport(transport))
+    }
+
+    fn with_transport(transport: Web3Transport) -> Web3Client {
+        Web3Client {
+            client: Web3::new(transport),
+            #[cfg(not(feature = "wasm"))]
+            subscriptions: None,
+        }
+    }
+
+    /// Create client connected to the node over WebSocket
+    ///     In contrast to HTTP, WebSocket connection allows subscribing to log events
+    ///
+    /// # Params
+    ///  - `node_address`: [String] - WebSocket RPC node endpoint (ws:// or wss://)
+    ///
+    /// # Returns
+    ///  client: [Web3Client] - client connected to the node
+    #[cfg(not(feature = "wasm"))]
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn new_ws(node_address: &str) -> VdrResult<Web3Client> {
+        let transport = WebSocket::new(node_address)
+            .await
+            .map_err(|_| VdrError::ClientNodeUnreachable)?;
+        let web3_client = Web3Client {
+            client: Web3::new(Either::Right(transport.clone())),
+            subscriptions: Some(Web3::new(transport)),
+        };
         Ok(web3_client)
     }
 
-    pub(crate) fn eth(&self) -> Eth<Http> {
-        self.client.eth()
+    /// Convert block tag of historical read into `eth_call` block parameter
+    fn build_block_id(block: &BlockTag) -> VdrResult<BlockId> {
+        match block {
+            BlockTag::Number(number) => {
+                Ok(BlockId::Number(BlockNumber::Number(U64::from(*number))))
+            }
+            BlockTag::Hash(hash) if hash.len() == H256::len_bytes() => {
+                Ok(BlockId::Hash(H256::from_slice(hash)))
+            }
+            BlockTag::Hash(hash) => Err(VdrError::ClientInvalidTransaction(format!(
+                "Invalid block hash {:?}",
+                hex::encode(hash)
+            ))),
+        }
+    }
+
+    /// Build `eth_call` / `eth_estimateGas` request executing the transaction on behalf of its sender
+    fn build_call_request(transaction: &Transaction) -> VdrResult<CallRequest> {
+        let to = EthAddress::from_str(transaction.to.as_ref()).map_err(|_| {
+            let vdr_error = VdrError::ClientInvalidTransaction(format!(
+                "Invalid transaction target address {:?}",
+                transaction.to
+            ));
+
+            warn!(
+                "Error: {} during building call request for transaction: {:?}",
+                vdr_error, transaction
+            );
+
+            vdr_error
+        })?;
+        let mut request = CallRequest::builder()
+            .to(to)
+            .data(Bytes(transaction.data.to_vec()));
+        if let Some(from) = transaction.from.as_ref() {
+            let from = EthAddress::from_str(from.as_ref()).map_err(|_| {
+                VdrError::ClientInvalidTransaction(format!(
+                    "Invalid transaction sender address {:?}",
+                    from
+                ))
+            })?;
+            request = request.from(from);
+        }
+        Ok(request.build())
+    }
+
+    fn build_contract_address(address: &Address) -> VdrResult<EthAddress> {
+        EthAddress::from_str(address.as_ref()).map_err(|_| {
+            VdrError::CommonInvalidData(format!("Invalid contract address {:?}", address))
+        })
+    }
+
+    fn build_event_filter(query: &EventQuery) -> VdrResult<FilterBuilder> {
+        let addresses = query
+            .addresses
+            .iter()
+            .map(|address| {
+                H160::from_str(address.as_ref()).map_err(|_| {
+                    VdrError::ClientInvalidTransaction(format!(
+                        "Invalid transaction target address {:?}",
+                        address
+                    ))
+                })
+            })
+            .collect::<VdrResult<Vec<H160>>>()?;
+
+        let mut topics = query
+            .topics
+            .iter()
+            .map(|topic| {
+                topic
+                    .as_ref()
+                    .map(|values| {
+                        values
+                            .iter()
+                            .map(|value| {
+                                H256::from_str(value).map_err(|_| {
+                                    VdrError::ClientInvalidTransaction(format!(
+                                        "Unable to convert event topic into H256 {:?}",
+                                        value
+                                    ))
+                                })
+                            })
+                            .collect::<VdrResult<Vec<H256>>>()
+                    })
+                    .transpose()
+            })
+            .collect::<VdrResult<Vec<Option<Vec<H256>>>>>()?
+            .into_iter();
+
+        let mut filter = FilterBuilder::default().topics(
+            topics.next().flatten(),
+            topics.next().flatten(),
+            topics.next().flatten(),
+            topics.next().flatten(),
+        );
+        // no addresses: events of any contract are matched
+        if !addresses.is_empty() {
+            filter = filter.address(addresses);
+        }
+        Ok(filter)
+    }
+
+    fn build_query_events_error(error: Web3Error) -> VdrError {
+        match error {
+            Web3Error::Rpc(ref rpc_error)
+                if rpc_error.code == ErrorCode::ServerError(LIMIT_EXCEEDED_ERROR_CODE)
+                    || QUERY_LIMIT_ERRORS
+                        .iter()
+                        .any(|message| rpc_error.message.to_lowercase().contains(message)) =>
+            {
+                VdrError::ClientQueryLimitExceeded(rpc_error.message.to_string())
+            }
+            Web3Error::Unreachable | Web3Error::Transport(_) => error.into(),
+            _ => VdrError::GetTransactionError("Could not query events".to_string()),
+        }
+    }
+
+    fn build_transaction(response: Value) -> VdrResult<Transaction> {
+        let chain_id = response
+            .get("chainId")
+            .cloned()
+            .map(serde_json::from_value::<U64>)
+            .transpose();
+        let transaction = serde_json::from_value::<EthTransaction>(response);
+        let (transaction, chain_id) = match (transaction, chain_id) {
+            (Ok(transaction), Ok(chain_id)) => (transaction, chain_id),
+            (Err(err), _) | (_, Err(err)) => {
+                let vdr_error = VdrError::ClientInvalidResponse(format!(
+                    "Unable to parse transaction: {}",
+                    err
+                ));
+
+                warn!("Error: {} during getting transaction", vdr_error);
+
+                return Err(vdr_error);
+            }
+        };
+        // legacy transactions may omit `chainId`: EIP-155 signature encodes it as `v = chain_id * 2 + 35 + parity`
+        let chain_id = match (chain_id, transaction.v) {
+            (Some(chain_id), _) => chain_id.as_u64(),
+            (None, Some(v)) if v.as_u64() >= 35 => (v.as_u64() - 35) / 2,
+            _ => 0,
+        };
+
+        Ok(Transaction {
+            type_: Default::default(),
+            from: transaction
+                .from
+                .map(|from| Address::from(format!("{:?}", from).as_str())),
+            to: transaction
+                .to
+                .map(|to| Address::from(format!("{:?}", to).as_str()))
+                .unwrap_or_default(),
+            nonce: Some(Self::to_u64(transaction.nonce, "Transaction nonce")?),
+            chain_id,
+            data: transaction.input.0.to_vec(),
+            signature: Default::default(),
+            hash: Some(transaction.hash.as_bytes().to_vec()),
+            envelope: match transaction.transaction_type.map(|type_| type_.as_u64()) {
+                Some(1) => TransactionEnvelope::AccessList,
+                Some(2) => TransactionEnvelope::DynamicFee,
+                _ => TransactionEnvelope::Legacy,
+            },
+            fees: TransactionFees {
+                gas_limit: Some(Self::to_u64(transaction.gas, "Transaction gas")?),
+                gas_price: transaction
+                    .gas_price
+                    .map(|gas_price| Self::to_u64(gas_price, "Transaction gas price"))
+                    .transpose()?,
+                max_fee_per_gas: transaction
+                    .max_fee_per_gas
+                    .map(|max_fee| Self::to_u64(max_fee, "Transaction max fee per gas"))
+                    .transpose()?,
+                max_priority_fee_per_gas: transaction
+                    .max_priority_fee_per_gas
+                    .map(|priority_fee| {
+                        Self::to_u64(priority_fee, "Transaction max priority fee per gas")
+                    })
+                    .transpose()?,
+            },
+            access_list: transaction
+                .access_list
+                .unwrap_or_default()
+                .into_iter()
+                .map(|item| AccessListItem {
+                    address: Address::from(format!("{:?}", item.address).as_str()),
+                    storage_keys: item.storage_keys,
+                })
+                .collect(),
+            ..Transaction::default()
+        })
+    }
+
+    /// Convert a number returned by the node into u64, failing instead of truncating values which do not fit
+    fn to_u64(value: U256, name: &str) -> VdrResult<u64> {
+        if value > U256::from(u64::MAX) {
+            let vdr_error = VdrError::ClientInvalidResponse(format!(
+                "{} {} does not fit into u64",
+                name, value
+            ));
+
+            warn!("Error: {} during parsing node response", vdr_error);
+
+            return Err(vdr_error);
+        }
+        Ok(value.as_u64())
+    }
+
+    fn build_event_log(log: Log) -> EventLog {
+        EventLog {
+            topics: log.topics,
+            data: log.data.0,
+            block: Block::from(log.block_number.unwrap_or_default().as_u64()),
+            block_hash: log.block_hash,
+            log_index: log.log_index.map(|log_index| log_index.as_u64()),
+            transaction_hash: log.transaction_hash,
+        }
     }
 }
 
@@ -77,7 +383,7 @@ impl Client for Web3Client {
         let count = self
             .client
             .eth()
-            .transaction_count(account_address, None)
+            .transaction_count(account_address, Some(BlockNumber::Pending))
             .await?
             .as_u64();
 
@@ -85,41 +391,76 @@ impl Client for Web3Client {
         Ok(count)
     }
 
-    async fn submit_transaction(&self, transaction: &[u8]) -> VdrResult<Vec<u8>> {
+    async fn send_transaction(&self, transaction: &[u8]) -> VdrResult<Vec<u8>> {
         trace!(
-            "Web3Client::submit_transaction(transaction: {:?})",
+            "Web3Client::send_transaction(transaction: {:?})",
             transaction
         );
 
-        let receipt = self
+        let transaction_hash = self
             .client
-            .send_raw_transaction_with_confirmation(
-                Bytes::from(transaction),
-                Duration::from_millis(POLL_INTERVAL),
-                NUMBER_TX_CONFIRMATIONS,
-            )
-            .await?;
+            .eth()
+            .send_raw_transaction(Bytes::from(transaction))
+            .await?
+            .0
+            .to_vec();
 
-        if receipt.is_txn_reverted() {
-            if let Some(revert_reason) = receipt.revert_reason {
-                return Err(VdrError::ClientTransactionReverted(revert_reason));
-            }
+        trace!("Web3Client::send_transaction() -> {:?}", transaction_hash);
+        Ok(transaction_hash)
+    }
+
+    async fn get_transaction_status(&self, hash: &[u8]) -> VdrResult<TransactionStatus> {
+        trace!("Web3Client::get_transaction_status(hash: {:?})", hash);
 
-            return Err(VdrError::ClientTransactionReverted("".to_string()));
+        if hash.len() != 32 {
+            let vdr_error =
+                VdrError::CommonInvalidData("Transaction hash length != 32 bytes".to_string());
+
+            warn!("Error: {} getting transaction status", vdr_error,);
+
+            return Err(vdr_error);
         }
 
-        trace!("Web3Client::submit_transaction() -> {:?}", receipt);
+        let receipt = self
+            .client
+            .eth()
+            .transaction_receipt(H256::from_slice(hash))
+            .await?;
 
-        let transaction_hash = receipt.transaction_hash.0.to_vec();
+        let status = match receipt {
+            Some(receipt) => match receipt.block_number {
+                Some(block_number) if receipt.is_txn_reverted() => TransactionStatus::Reverted {
+                    block_number: block_number.as_u64(),
+                    revert_reason: receipt.revert_reason.unwrap_or_default(),
+                },
+                Some(block_number) => {
+                    let latest_block = self.client.eth().block_number().await?;
+                    TransactionStatus::Included {
+                        block_number: block_number.as_u64(),
+                        confirmations: latest_block.as_u64().saturating_sub(block_number.as_u64())
+                            + 1,
+                    }
+                }
+                None => TransactionStatus::Pending,
+            },
+            None => TransactionStatus::Pending,
+        };
 
-        Ok(transaction_hash)
+        trace!("Web3Client::get_transaction_status() -> {:?}", status);
+        Ok(status)
     }
 
-    async fn call_transaction(&self, to: &str, transaction: &[u8]) -> VdrResult<Vec<u8>> {
+    async fn call_transaction(
+        &self,
+        to: &str,
+        transaction: &[u8],
+        block: Option<BlockTag>,
+    ) -> VdrResult<Vec<u8>> {
         trace!(
-            "Web3Client::call_transaction(to: {:?}, transaction: {:?})",
+            "Web3Client::call_transaction(to: {:?}, transaction: {:?}, block: {:?})",
             to,
-            transaction
+            transaction,
+            block
         );
 
         let address = EthAddress::from_str(to).map_err(|_| {
@@ -139,22 +480,96 @@ impl Client for Web3Client {
             .to(address)
             .data(Bytes(transaction.to_vec()))
             .build();
-        let response = self.client.eth().call(request, None).await?.0.to_vec();
+        let block = block.as_ref().map(Self::build_block_id).transpose()?;
+        let response = self.client.eth().call(request, block).await?.0.to_vec();
 
         trace!("Web3Client::call_transaction() -> {:?}", response);
         Ok(response)
     }
 
+    async fn simulate_transaction(&self, transaction: &Transaction) -> VdrResult<Vec<u8>> {
+        trace!(
+            "Web3Client::simulate_transaction(transaction: {:?})",
+            transaction
+        );
+
+        let request = Self::build_call_request(transaction)?;
+        let response = self
+            .client
+            .eth()
+            .call(request, Some(BlockId::Number(BlockNumber::Latest)))
+            .await?
+            .0
+            .to_vec();
+
+        trace!("Web3Client::simulate_transaction() -> {:?}", response);
+        Ok(response)
+    }
+
+    async fn estimate_gas(&self, transaction: &Transaction) -> VdrResult<u64> {
+        trace!("Web3Client::estimate_gas(transaction: {:?})", transaction);
+
+        let request = Self::build_call_request(transaction)?;
+        let gas = Self::to_u64(
+            self.client.eth().estimate_gas(request, None).await?,
+            "Estimated gas",
+        )?;
+
+        trace!("Web3Client::estimate_gas() -> {:?}", gas);
+        Ok(gas)
+    }
+
+    async fn get_gas_price(&self) -> VdrResult<u64> {
+        trace!("Web3Client::get_gas_price()");
+
+        let gas_price = Self::to_u64(self.client.eth().gas_price().await?, "Gas price")?;
+
+        trace!("Web3Client::get_gas_price() -> {:?}", gas_price);
+        Ok(gas_price)
+    }
+
+    async fn get_fee_history(
+        &self,
+        block_count: u64,
+        reward_percentile: f64,
+    ) -> VdrResult<FeeHistory> {
+        trace!(
+            "Web3Client::get_fee_history(block_count: {:?}, reward_percentile: {:?})",
+            block_count,
+            reward_percentile
+        );
+
+        let fee_history = self
+            .client
+            .eth()
+            .fee_history(
+                U256::from(block_count),
+                BlockNumber::Latest,
+                Some(vec![reward_percentile]),
+            )
+            .await?;
+        let fee_history = FeeHistory {
+            base_fee_per_gas: fee_history
+                .base_fee_per_gas
+                .into_iter()
+                .map(|fee| Self::to_u64(fee, "Base fee per gas"))
+                .collect::<VdrResult<_>>()?,
+            priority_fee_per_gas: fee_history
+                .reward
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|rewards| rewards.first().copied())
+                .map(|reward| Self::to_u64(reward, "Priority fee per gas"))
+                .collect::<VdrResult<_>>()?,
+        };
+
+        trace!("Web3Client::get_fee_history() -> {:?}", fee_history);
+        Ok(fee_history)
+    }
+
     async fn query_events(&self, query: &EventQuery) -> VdrResult<Vec<EventLog>> {
         trace!("Web3Client::query_events(query: {:?})", query);
 
-        let address = H160::from_str(query.address.as_ref()).map_err(|_| {
-            VdrError::ClientInvalidTransaction(format!(
-                "Invalid transaction target address {:?}",
-                query.address
-            ))
-        })?;
-
         let from_block = match query.from_block {
             Some(ref block) => BlockNumber::Number(U64::from(block.value())),
             None => BlockNumber::Earliest,
@@ -165,34 +580,7 @@ impl Client for Web3Client {
             None => BlockNumber::Latest,
         };
 
-        let event_signature = match query.event_signature {
-            Some(ref event_signature) => Some(H256::from_str(event_signature).map_err(|_| {
-                VdrError::ClientInvalidTransaction(format!(
-                    "Unable to convert event signature into H256 {:?}",
-                    event_signature
-                ))
-            })?),
-            None => None,
-        };
-
-        let event_filter = match query.event_filter {
-            Some(ref event_filter) => Some(H256::from_str(event_filter).map_err(|_| {
-                VdrError::ClientInvalidTransaction(format!(
-                    "Unable to convert event filter into H256 {:?}",
-                    event_filter
-                ))
-            })?),
-            None => None,
-        };
-
-        let filter = FilterBuilder::default()
-            .address(vec![address])
-            .topics(
-                event_signature.map(|event_signature| vec![event_signature]),
-                event_filter.map(|event_filter| vec![event_filter]),
-                None,
-                None,
-            )
+        let filter = Self::build_event_filter(query)?
             .from_block(from_block)
             .to_block(to_block)
             .build();
@@ -202,22 +590,86 @@ impl Client for Web3Client {
             .eth()
             .logs(filter)
             .await
-            .map_err(|_| VdrError::GetTransactionError("Could not query events".to_string()))?;
-
-        let events: Vec<EventLog> = logs
-            .into_iter()
-            .map(|log| EventLog {
-                topics: log.topics,
-                data: log.data.0,
-                block: Block::from(log.block_number.unwrap_or_default().as_u64()),
-            })
-            .collect();
+            .map_err(Self::build_query_events_error)?;
+
+        let events: Vec<EventLog> = logs.into_iter().map(Self::build_event_log).collect();
 
         trace!("Web3Client::query_events() -> {:?}", events);
         Ok(events)
     }
 
-    async fn get_receipt(&self, hash: &[u8]) -> VdrResult<String> {
+    #[cfg(not(feature = "wasm"))]
+    async fn subscribe_events(&self, query: &EventQuery) -> VdrResult<EventStream> {
+        trace!("Web3Client::subscribe_events(query: {:?})", query);
+
+        let subscriptions = self.subscriptions.as_ref().ok_or_else(|| {
+            let vdr_error = VdrError::ClientInvalidState(
+                "Event subscriptions require connection to the node over WebSocket".to_string(),
+            );
+
+            warn!("Error: {} during subscribing to events", vdr_error);
+
+            vdr_error
+        })?;
+
+        let filter = Self::build_event_filter(query)?.build();
+        let stream = subscriptions.eth_subscribe().subscribe_logs(filter).await?;
+
+        trace!("Web3Client::subscribe_events() -> {:?}", stream.id());
+
+        let events = stream.map(|log| log.map(Self::build_event_log).map_err(VdrError::from));
+        Ok(Box::pin(events))
+    }
+
+    async fn get_chain_id(&self) -> VdrResult<u64> {
+        trace!("Web3Client::get_chain_id()");
+
+        let chain_id = Self::to_u64(self.client.eth().chain_id().await?, "Chain id")?;
+
+        trace!("Web3Client::get_chain_id() -> {:?}", chain_id);
+        Ok(chain_id)
+    }
+
+    async fn get_code(&self, address: &Address) -> VdrResult<Vec<u8>> {
+        trace!("Web3Client::get_code(address: {:?})", address);
+
+        let contract_address = Self::build_contract_address(address)?;
+        let code = self.client.eth().code(contract_address, None).await?.0;
+
+        trace!("Web3Client::get_code() -> {:?}", code);
+        Ok(code)
+    }
+
+    async fn get_storage_at(&self, address: &Address, slot: &[u8]) -> VdrResult<Vec<u8>> {
+        trace!(
+            "Web3Client::get_storage_at(address: {:?}, slot: {:?})",
+            address,
+            slot
+        );
+
+        if slot.len() != 32 {
+            let vdr_error =
+                VdrError::CommonInvalidData("Storage slot length != 32 bytes".to_string());
+
+            warn!("Error: {} getting contract storage", vdr_error);
+
+            return Err(vdr_error);
+        }
+
+        let contract_address = Self::build_contract_address(address)?;
+        let value = self
+            .client
+            .eth()
+            .storage(contract_address, U256::from_big_endian(slot), None)
+            .await?
+            .0
+            .to_vec();
+
+        trace!("Web3Client::get_storage_at() -> {:?}", value);
+        Ok(value)
+    }
+
+    async fn get_receipt(&self, hash: &[u8]) -> VdrResult<TransactionReceipt> {
         trace!("Web3Client::get_receipt(hash: {:?})", hash);
 
         if hash.len() != 32 {
@@ -242,7 +694,35 @@ impl Client for Web3Client {
 
                 vdr_error
             })
-            .map(|receipt| json!(receipt).to_string())?;
+            .and_then(|receipt| {
+                Ok(TransactionReceipt {
+                    transaction_hash: receipt.transaction_hash.0.to_vec(),
+                    block_hash: receipt
+                        .block_hash
+                        .map(|block_hash| block_hash.0.to_vec())
+                        .unwrap_or_default(),
+                    block_number: receipt
+                        .block_number
+                        .map(|block_number| block_number.as_u64())
+                        .unwrap_or_default(),
+                    success: !receipt.is_txn_reverted(),
+                    gas_used: receipt
+                        .gas_used
+                        .map(|gas_used| Self::to_u64(gas_used, "Gas used"))
+                        .transpose()?
+                        .unwrap_or_default(),
+                    logs: receipt
+                        .logs
+                        .into_iter()
+                        .map(|log| ReceiptLog {
+                            address: Address::from(format!("{:?}", log.address).as_str()),
+                            topics: log.topics,
+                            data: log.data.0,
+                        })
+                        .collect(),
+                    revert_reason: receipt.revert_reason,
+                })
+            })?;
 
         trace!("Web3Client::get_receipt() -> {:?}", receipt);
         Ok(receipt)
@@ -261,6 +741,7 @@ impl Client for Web3Client {
                 number: block.number.unwrap().as_u64(),
                 timestamp: block.timestamp.as_u64(),
             }),
+            Err(error @ (Web3Error::Unreachable | Web3Error::Transport(_))) => Err(error.into()),
             _ => Err(VdrError::ClientInvalidState(
                 "Could not get current network block".to_string(),
             )),
@@ -273,31 +754,25 @@ impl Client for Web3Client {
             transaction_hash
         );
 
-        let transaction_id = TransactionId::Hash(H256::from_slice(transaction_hash));
-        let transaction = self
+        // requested as raw JSON: web3 transaction type does not contain `chainId` field
+        let response = self
             .client
-            .eth()
-            .transaction(transaction_id)
+            .transport()
+            .execute(
+                "eth_getTransactionByHash",
+                vec![json!(H256::from_slice(transaction_hash))],
+            )
             .await
-            .map_err(|_| {
-                VdrError::GetTransactionError("Could not get transaction by hash".to_string())
+            .map_err(|error| match error {
+                Web3Error::Unreachable | Web3Error::Transport(_) => error.into(),
+                _ => VdrError::GetTransactionError("Could not get transaction by hash".to_string()),
             })?;
 
-        let transaction = transaction.map(|transaction| Transaction {
-            type_: Default::default(),
-            from: transaction
-                .from
-                .map(|from| Address::from(from.to_string().as_str())),
-            to: transaction
-                .to
-                .map(|from| Address::from(from.to_string().as_str()))
-                .unwrap_or_default(),
-            nonce: Some(transaction.nonce.as_u64()),
-            chain_id: 0,
-            data: transaction.input.0.to_vec(),
-            signature: Default::default(),
-            hash: Some(transaction.hash.as_bytes().to_vec()),
-        });
+        let transaction = if response.is_null() {
+            None
+        } else {
+            Some(Self::build_transaction(response)?)
+        };
 
         trace!("Web3Client::get_transaction() -> {:?}", transaction);
         Ok(transaction)
@@ -309,3 +784,140 @@ impl Debug for Web3Client {
         write!(f, r#"Web3Client {{ }}"#)
     }
 }
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use jsonrpc_core::types::error::Error as RpcError;
+    use rstest::rstest;
+    #[cfg(not(feature = "wasm"))]
+    use web3::error::TransportError;
+    #[cfg(feature = "wasm")]
+    use web3_wasm::error::TransportError;
+
+    fn rpc_error(code: i64, message: &str) -> Web3Error {
+        Web3Error::Rpc(RpcError {
+            code: ErrorCode::ServerError(code),
+            message: message.to_string(),
+            data: None,
+        })
+    }
+
+    #[rstest]
+    #[case::besu(rpc_error(-32000, "Requested range exceeds maximum RPC range limit"))]
+    #[case::geth_range(rpc_error(-32000, "exceed maximum block range: 10000"))]
+    #[case::geth_results(rpc_error(-32000, "query returned more than 10000 results"))]
+    #[case::limit_exceeded_code(rpc_error(LIMIT_EXCEEDED_ERROR_CODE, "Limit exceeded"))]
+    fn build_query_limit_error_test(#[case] error: Web3Error) {
+        let error = Web3Client::build_query_events_error(error);
+
+        assert!(matches!(error, VdrError::ClientQueryLimitExceeded { .. }));
+    }
+
+    #[rstest]
+    #[case::rate_limit(rpc_error(-32000, "rate limit reached"))]
+    #[case::invalid_range(rpc_error(-32000, "invalid block range params"))]
+    fn build_query_events_error_test(#[case] error: Web3Error) {
+        let error = Web3Client::build_query_events_error(error);
+
+        assert!(matches!(error, VdrError::GetTransactionError { .. }));
+    }
+
+    #[rstest]
+    #[case::unreachable(Web3Error::Unreachable, VdrError::ClientNodeUnreachable)]
+    #[case::http_status(
+        Web3Error::Transport(TransportError::Code(503)),
+        VdrError::ClientHttpStatus(503)
+    )]
+    fn build_query_events_transport_error_test(
+        #[case] error: Web3Error,
+        #[case] expected_error: VdrError,
+    ) {
+        let error = Web3Client::build_query_events_error(error);
+
+        assert_eq!(expected_error, error);
+    }
+
+    fn rpc_transaction(fields: Value) -> Value {
+        let mut transaction = json!({
+            "hash": "0x5e2fc091e15119c97722e9b63d5d32b043d077d834f377b91f80d32872c78109",
+            "nonce": "0x41",
+            "blockHash": null,
+            "blockNumber": null,
+            "transactionIndex": null,
+            "from": "0xe66b278fa9fbb181522f6916ec2f6d66ab846e04",
+            "to": "0x11d7c2ab0d4aa26b7d8502f6a7ef6844908495c2",
+            "value": "0x0",
+            "gasPrice": "0x59682f07",
+            "gas": "0x1a0cf",
+            "input": "0xe5225381",
+        });
+        transaction
+            .as_object_mut()
+            .unwrap()
+            .extend(fields.as_object().unwrap().clone());
+        transaction
+    }
+
+    #[test]
+    fn build_dynamic_fee_transaction_test() {
+        let response = rpc_transaction(json!({
+            "type": "0x2",
+            "chainId": "0x5",
+            "maxFeePerGas": "0x59682f09",
+            "maxPriorityFeePerGas": "0x59682f00",
+            "accessList": [{
+                "address": "0x11d7c2ab0d4aa26b7d8502f6a7ef6844908495c2",
+                "storageKeys": ["0x0000000000000000000000000000000000000000000000000000000000000001"]
+            }],
+        }));
+
+        let transaction = Web3Client::build_transaction(response).unwrap();
+
+        assert_eq!(5, transaction.chain_id);
+        assert_eq!(Some(65), transaction.nonce);
+        assert_eq!(TransactionEnvelope::DynamicFee, transaction.envelope);
+        assert_eq!(
+            Some(Address::from("0xe66b278fa9fbb181522f6916ec2f6d66ab846e04")),
+            transaction.from
+        );
+        assert_eq!(
+            TransactionFees {
+                gas_limit: Some(106703),
+                gas_price: Some(1500000007),
+                max_fee_per_gas: Some(1500000009),
+                max_priority_fee_per_gas: Some(1500000000),
+            },
+            transaction.fees
+        );
+        assert_eq!(
+            vec![AccessListItem {
+                address: Address::from("0x11d7c2ab0d4aa26b7d8502f6a7ef6844908495c2"),
+                storage_keys: vec![H256::from_low_u64_be(1)],
+            }],
+            transaction.access_list
+        );
+    }
+
+    #[test]
+    fn build_legacy_transaction_test() {
+        // EIP-155 signature of the chain 1337: v = 1337 * 2 + 35 + 1
+        let response = rpc_transaction(json!({ "v": "0xa96" }));
+
+        let transaction = Web3Client::build_transaction(response).unwrap();
+
+        assert_eq!(1337, transaction.chain_id);
+        assert_eq!(TransactionEnvelope::Legacy, transaction.envelope);
+        assert_eq!(Some(1500000007), transaction.fees.gas_price);
+        assert!(transaction.access_list.is_empty());
+    }
+
+    #[test]
+    fn build_transaction_with_overflowing_nonce_test() {
+        let response = rpc_transaction(json!({ "nonce": "0x10000000000000000" }));
+
+        let err = Web3Client::build_transaction(response).unwrap_err();
+
+        assert!(matches!(err, VdrError::ClientInvalidResponse { .. }));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    client::{implementation::web3::client::Web3Client, Contract},
+    client::Contract,
     error::{VdrError, VdrResult},
     types::ContractSpec,
     Address,
@@ -12,31 +12,19 @@ use log_derive::{logfn, logfn_inputs};
 use std::str::FromStr;
 
 #[cfg(not(feature = "wasm"))]
-use web3::{
-    contract::Contract as Web3ContractImpl,
-    ethabi::{Address as EthAddress, Function},
-    transports::Http,
-};
+use web3::ethabi::{Address as EthAddress, Contract as EthContract, Function};
 #[cfg(feature = "wasm")]
-use web3_wasm::{
-    contract::Contract as Web3ContractImpl,
-    ethabi::{Address as EthAddress, Function},
-    transports::Http,
-};
+use web3_wasm::ethabi::{Address as EthAddress, Contract as EthContract, Function};
 
 pub struct Web3Contract {
     address: Address,
-    contract: Web3ContractImpl<Http>,
+    contract: EthContract,
 }
 
 impl Web3Contract {
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
-    pub fn new(
-        web3_client: &Web3Client,
-        address: &str,
-        contract_spec: &ContractSpec,
-    ) -> VdrResult<Web3Contract> {
+    pub fn new(address: &str, contract_spec: &ContractSpec) -> VdrResult<Web3Contract> {
         let abi = serde_json::to_vec(&contract_spec.abi).map_err(|err| {
             let vdr_error = VdrError::CommonInvalidData(format!(
                 "Unable to parse contract ABI from specification. Err: {:?}",
@@ -47,7 +35,7 @@ impl Web3Contract {
 
             vdr_error
         })?;
-        let parsed_address = EthAddress::from_str(address).map_err(|err| {
+        EthAddress::from_str(address).map_err(|err| {
             let vdr_error = VdrError::CommonInvalidData(format!(
                 "Unable to parse contract address. Err: {:?}",
                 err.to_string()
@@ -57,8 +45,7 @@ impl Web3Contract {
 
             vdr_error
         })?;
-        let contract =
-            Web3ContractImpl::from_json(web3_client.eth(), parsed_address, abi.as_slice())?;
+        let contract = EthContract::load(abi.as_slice())?;
 
         Ok(Web3Contract {
             contract,
@@ -77,7 +64,7 @@ impl Contract for Web3Contract {
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
     fn function(&self, name: &str) -> VdrResult<&Function> {
-        self.contract.abi().function(name).map_err(|err| {
+        self.contract.function(name).map_err(|err| {
             let vdr_error = VdrError::from(err);
 
             warn!(
@@ -92,7 +79,7 @@ impl Contract for Web3Contract {
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
     fn event(&self, name: &str) -> VdrResult<&Event> {
-        self.contract.abi().event(name).map_err(|err| {
+        self.contract.event(name).map_err(|err| {
             let vdr_error = VdrError::from(err);
 
             warn!(
@@ -107,7 +94,13 @@ impl Contract for Web3Contract {
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
     fn errors(&self) -> Vec<&AbiError> {
-        self.contract.abi().errors().collect()
+        self.contract.errors().collect()
+    }
+
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    fn functions(&self) -> Vec<&Function> {
+        self.contract.functions().collect()
     }
 }
 
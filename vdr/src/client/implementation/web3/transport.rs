@@ -0,0 +1,436 @@
+use crate::{
+    client::connection::ConnectionConfig,
+    error::{VdrError, VdrResult},
+};
+use base64::Engine;
+use log::warn;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION},
+    Client as HttpClient, Url,
+};
+use std::str::FromStr;
+
+#[cfg(not(feature = "wasm"))]
+use crate::client::connection::{TlsConfig, TokenProvider};
+#[cfg(not(feature = "wasm"))]
+use futures::future::BoxFuture;
+#[cfg(not(feature = "wasm"))]
+use reqwest::{Certificate, ClientBuilder, Identity};
+#[cfg(not(feature = "wasm"))]
+use std::{
+    fmt::{Debug, Formatter},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, PoisonError, RwLock,
+    },
+};
+#[cfg(not(feature = "wasm"))]
+use web3::{
+    error::TransportError, helpers::build_request, transports::Http, Error as Web3Error, RequestId,
+    Transport,
+};
+
+#[cfg(feature = "wasm")]
+use web3_wasm::transports::Http;
+
+/// Create HTTP transport sending authentication headers and using TLS settings of the connection config
+///
+/// # Params
+///  - `node_address`: [String] - RPC node endpoint
+///  - `config`: [ConnectionConfig] - connection settings
+///  - `token`: Option<[String]> - bearer token overriding the token set in the config
+///
+/// # Returns
+///  transport: [Http] - HTTP transport
+pub(crate) fn build_http(
+    node_address: &str,
+    config: &ConnectionConfig,
+    token: Option<&str>,
+) -> VdrResult<Http> {
+    config.validate()?;
+
+    let url = Url::from_str(node_address).map_err(|err| {
+        let vdr_error =
+            VdrError::ClientInvalidState(format!("Invalid node address {}: {}", node_address, err));
+
+        warn!("Error: {} during building HTTP transport", vdr_error);
+
+        vdr_error
+    })?;
+
+    #[cfg(feature = "wasm")]
+    if config.tls.is_some() {
+        return Err(VdrError::ClientInvalidState(
+            "TLS settings are not supported for wasm: certificates are managed by the browser"
+                .to_string(),
+        ));
+    }
+
+    let builder = HttpClient::builder().default_headers(build_headers(config, token)?);
+    #[cfg(not(feature = "wasm"))]
+    let builder = match config.tls.as_ref() {
+        Some(tls) => apply_tls(builder, tls)?,
+        None => builder,
+    };
+
+    let client = builder.build().map_err(|err| {
+        let vdr_error =
+            VdrError::ClientInvalidState(format!("Unable to create HTTP client: {}", err));
+
+        warn!("Error: {} during building HTTP transport", vdr_error);
+
+        vdr_error
+    })?;
+
+    Ok(Http::with_client(client, url))
+}
+
+fn build_headers(config: &ConnectionConfig, token: Option<&str>) -> VdrResult<HeaderMap> {
+    let mut headers = HeaderMap::new();
+    for (name, value) in config.headers.iter().flatten() {
+        let name = HeaderName::from_str(name).map_err(|_| invalid_header(name))?;
+        let value = HeaderValue::from_str(value).map_err(|_| invalid_header(name.as_str()))?;
+        headers.insert(name, value);
+    }
+
+    let authorization = match (token.or(config.bearer_token.as_deref()), &config.basic_auth) {
+        (Some(token), _) => Some(format!("Bearer {}", token)),
+        (None, Some(basic_auth)) => {
+            let credentials = format!(
+                "{}:{}",
+                basic_auth.username,
+                basic_auth.password.as_deref().unwrap_or_default()
+            );
+            Some(format!(
+                "Basic {}",
+                base64::engine::general_purpose::STANDARD.encode(credentials)
+            ))
+        }
+        (None, None) => None,
+    };
+    if let Some(authorization) = authorization {
+        let mut value = HeaderValue::from_str(&authorization)
+            .map_err(|_| invalid_header(AUTHORIZATION.as_str()))?;
+        value.set_sensitive(true);
+        headers.insert(AUTHORIZATION, value);
+    }
+
+    Ok(headers)
+}
+
+fn invalid_header(name: &str) -> VdrError {
+    let vdr_error = VdrError::ClientInvalidState(format!("Invalid value of header {}", name));
+
+    warn!("Error: {} during building HTTP transport", vdr_error);
+
+    vdr_error
+}
+
+#[cfg(not(feature = "wasm"))]
+fn apply_tls(mut builder: ClientBuilder, tls: &TlsConfig) -> VdrResult<ClientBuilder> {
+    if let Some(ca_certificate) = tls.ca_certificate.as_ref() {
+        let certificate = Certificate::from_pem(ca_certificate.as_bytes())
+            .map_err(|err| invalid_tls("CA certificate", err))?;
+        builder = builder.add_root_certificate(certificate);
+    }
+    if let (Some(certificate), Some(key)) =
+        (tls.client_certificate.as_ref(), tls.client_key.as_ref())
+    {
+        let identity = Identity::from_pkcs8_pem(certificate.as_bytes(), key.as_bytes())
+            .map_err(|err| invalid_tls("client certificate", err))?;
+        builder = builder.identity(identity);
+    }
+    if let Some(accept_invalid_certificates) = tls.accept_invalid_certificates {
+        builder = builder.danger_accept_invalid_certs(accept_invalid_certificates);
+    }
+    Ok(builder)
+}
+
+#[cfg(not(feature = "wasm"))]
+fn invalid_tls(name: &str, err: reqwest::Error) -> VdrError {
+    let vdr_error = VdrError::ClientInvalidState(format!("Invalid {}: {}", name, err));
+
+    warn!("Error: {} during building HTTP transport", vdr_error);
+
+    vdr_error
+}
+
+/// HTTP transport authenticating requests with tokens of the [TokenProvider]
+///
+/// The token is requested before every request. HTTP transport is re-created when the token changes.
+#[cfg(not(feature = "wasm"))]
+#[derive(Clone)]
+pub(crate) struct TokenHttp {
+    node_address: String,
+    config: ConnectionConfig,
+    token_provider: Arc<dyn TokenProvider>,
+    /// Last received token and the transport sending it
+    current: Arc<RwLock<Option<(String, Http)>>>,
+    id: Arc<AtomicUsize>,
+}
+
+#[cfg(not(feature = "wasm"))]
+impl TokenHttp {
+    pub(crate) fn new(
+        node_address: &str,
+        config: &ConnectionConfig,
+        token_provider: Arc<dyn TokenProvider>,
+    ) -> VdrResult<TokenHttp> {
+        // check the config before the first request
+        build_http(node_address, config, None)?;
+
+        Ok(TokenHttp {
+            node_address: node_address.to_string(),
+            config: config.clone(),
+            token_provider,
+            current: Arc::new(RwLock::new(None)),
+            id: Arc::new(AtomicUsize::new(1)),
+        })
+    }
+
+    async fn transport(&self) -> VdrResult<Http> {
+        let token = self.token_provider.token().await?;
+
+        if let Some((current_token, http)) = self.current.read().map_err(poisoned)?.as_ref() {
+            if *current_token == token {
+                return Ok(http.clone());
+            }
+        }
+
+        let http = build_http(&self.node_address, &self.config, Some(&token))?;
+        *self.current.write().map_err(poisoned)? = Some((token, http.clone()));
+        Ok(http)
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+fn poisoned<T>(_: PoisonError<T>) -> VdrError {
+    let vdr_error = VdrError::ClientInvalidState("Token transport state is poisoned".to_string());
+
+    warn!("Error: {} during accessing token transport", vdr_error);
+
+    vdr_error
+}
+
+#[cfg(not(feature = "wasm"))]
+impl Transport for TokenHttp {
+    type Out = BoxFuture<'static, web3::error::Result<jsonrpc_core::Value>>;
+
+    fn prepare(
+        &self,
+        method: &str,
+        params: Vec<jsonrpc_core::Value>,
+    ) -> (RequestId, jsonrpc_core::Call) {
+        let id = self.id.fetch_add(1, Ordering::AcqRel);
+        (id, build_request(id, method, params))
+    }
+
+    fn send(&self, id: RequestId, request: jsonrpc_core::Call) -> Self::Out {
+        let token_http = self.clone();
+        Box::pin(async move {
+            let http = token_http.transport().await.map_err(|err| {
+                Web3Error::Transport(TransportError::Message(format!(
+                    "Unable to get authentication token: {}",
+                    err
+                )))
+            })?;
+            http.send(id, request).await
+        })
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+impl Debug for TokenHttp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, r#"TokenHttp {{ node_address: {} }}"#, self.node_address)
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::client::connection::BasicAuth;
+    use async_trait::async_trait;
+    use serde_json::json;
+    use std::{
+        collections::HashMap,
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{mpsc, Mutex},
+        thread,
+    };
+
+    const NODE_ADDRESS: &str = "https://127.0.0.1:8545";
+
+    #[test]
+    fn build_headers_with_bearer_token() {
+        let config = ConnectionConfig {
+            headers: Some(HashMap::from([(
+                "X-Api-Key".to_string(),
+                "key".to_string(),
+            )])),
+            bearer_token: Some("token".to_string()),
+            ..ConnectionConfig::default()
+        };
+
+        let headers = build_headers(&config, None).unwrap();
+
+        assert_eq!("key", headers.get("x-api-key").unwrap());
+        assert_eq!("Bearer token", headers.get(AUTHORIZATION).unwrap());
+        assert!(headers.get(AUTHORIZATION).unwrap().is_sensitive());
+    }
+
+    #[test]
+    fn build_headers_with_refreshed_token() {
+        let config = ConnectionConfig {
+            bearer_token: Some("token".to_string()),
+            ..ConnectionConfig::default()
+        };
+
+        let headers = build_headers(&config, Some("refreshed")).unwrap();
+
+        assert_eq!("Bearer refreshed", headers.get(AUTHORIZATION).unwrap());
+    }
+
+    #[test]
+    fn build_headers_with_basic_auth() {
+        let config = ConnectionConfig {
+            basic_auth: Some(BasicAuth {
+                username: "user".to_string(),
+                password: Some("password".to_string()),
+            }),
+            ..ConnectionConfig::default()
+        };
+
+        let headers = build_headers(&config, None).unwrap();
+
+        assert_eq!(
+            "Basic dXNlcjpwYXNzd29yZA==",
+            headers.get(AUTHORIZATION).unwrap()
+        );
+    }
+
+    #[test]
+    fn build_headers_invalid_header() {
+        let config = ConnectionConfig {
+            headers: Some(HashMap::from([(
+                "Invalid Header".to_string(),
+                "value".to_string(),
+            )])),
+            ..ConnectionConfig::default()
+        };
+
+        let err = build_headers(&config, None).unwrap_err();
+
+        assert!(matches!(err, VdrError::ClientInvalidState { .. }));
+    }
+
+    #[test]
+    fn build_http_invalid_certificate() {
+        let config = ConnectionConfig {
+            tls: Some(TlsConfig {
+                ca_certificate: Some("invalid".to_string()),
+                ..TlsConfig::default()
+            }),
+            ..ConnectionConfig::default()
+        };
+
+        let err = build_http(NODE_ADDRESS, &config, None).unwrap_err();
+
+        assert!(matches!(err, VdrError::ClientInvalidState { .. }));
+    }
+
+    /// Token provider returning the given tokens one by one
+    struct SequenceTokenProvider(Mutex<Vec<String>>);
+
+    #[async_trait]
+    impl TokenProvider for SequenceTokenProvider {
+        async fn token(&self) -> VdrResult<String> {
+            Ok(self.0.lock().unwrap().remove(0))
+        }
+    }
+
+    /// Start HTTP server standing in for the node: it answers `requests` JSON-RPC requests over separate connections
+    /// and passes their `Authorization` headers to the returned channel
+    fn mock_node(requests: usize) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for _ in 0..requests {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut authorization = String::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line == "\r\n" {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case(AUTHORIZATION.as_str()) {
+                            authorization = value.trim().to_string();
+                        }
+                    }
+                }
+
+                let response = json!({"jsonrpc": "2.0", "id": 1, "result": "0x1"}).to_string();
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    response.len(),
+                    response
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+                sender.send(authorization).unwrap();
+            }
+        });
+        (address, receiver)
+    }
+
+    fn token_http(node_address: &str, tokens: &[&str]) -> TokenHttp {
+        let token_provider = SequenceTokenProvider(Mutex::new(
+            tokens.iter().map(|token| token.to_string()).collect(),
+        ));
+        TokenHttp::new(
+            node_address,
+            &ConnectionConfig::default(),
+            Arc::new(token_provider),
+        )
+        .unwrap()
+    }
+
+    #[async_std::test]
+    async fn token_http_recreates_transport_on_token_change() {
+        let (node_address, authorizations) = mock_node(3);
+        let transport = token_http(&node_address, &["first", "first", "second"]);
+
+        for _ in 0..3 {
+            transport.execute("eth_blockNumber", vec![]).await.unwrap();
+        }
+
+        assert_eq!(
+            vec!["Bearer first", "Bearer first", "Bearer second"],
+            authorizations.iter().take(3).collect::<Vec<String>>()
+        );
+        assert_eq!(
+            "second",
+            transport.current.read().unwrap().as_ref().unwrap().0
+        );
+    }
+
+    #[async_std::test]
+    async fn token_http_poisoned_state() {
+        let transport = token_http(NODE_ADDRESS, &["token"]);
+        let current = transport.current.clone();
+        thread::spawn(move || {
+            let _guard = current.write().unwrap();
+            panic!("poison token transport state");
+        })
+        .join()
+        .unwrap_err();
+
+        let err = transport.transport().await.unwrap_err();
+
+        assert!(matches!(err, VdrError::ClientInvalidState { .. }));
+    }
+}
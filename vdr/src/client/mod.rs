@@ -1,18 +1,40 @@
 pub mod client;
+pub mod confirmation_policy;
+pub mod connection;
 pub mod constants;
+pub mod event_pagination;
+pub mod failover;
+pub mod fee_strategy;
 pub mod implementation;
+pub mod nonce_manager;
+pub mod pending_transaction;
 pub mod quorum;
+mod verification;
 
-use crate::{error::VdrResult, types::Address, BlockDetails, Transaction};
+use crate::{
+    error::{VdrError, VdrResult},
+    types::Address,
+    BlockDetails, Transaction,
+};
 use async_trait::async_trait;
 use ethabi::{AbiError, Event, Function};
 use std::fmt::Debug;
 
-pub use client::LedgerClient;
+pub use client::{LedgerClient, LedgerClientBuilder};
+pub use confirmation_policy::ConfirmationPolicy;
+pub use connection::{BasicAuth, ConnectionConfig, TlsConfig, TokenProvider};
 pub use constants::*;
-pub use quorum::{QuorumConfig, QuorumHandler};
+pub use event_pagination::EventPagination;
+pub use failover::FailoverClient;
+pub use fee_strategy::FeeStrategy;
+pub use implementation::web3::client::Web3Client;
+pub use nonce_manager::NonceManager;
+pub use pending_transaction::PendingTransaction;
+pub use quorum::{QuorumConfig, QuorumHandler, QuorumPolicy};
 
-use crate::types::{EventLog, EventQuery};
+use crate::types::{
+    BlockTag, EventLog, EventQuery, EventStream, FeeHistory, TransactionReceipt, TransactionStatus,
+};
 #[cfg(test)]
 use mockall::automock;
 
@@ -21,6 +43,7 @@ use mockall::automock;
 #[cfg_attr(feature = "wasm", async_trait(?Send))]
 pub trait Client: Sync + Send + Debug {
     /// Retrieve count of transaction for the given account
+    ///     Transactions from the node transaction pool are counted as well
     ///
     /// # Params
     /// - `address` [Address] address of an account to get number of written transactions
@@ -29,23 +52,39 @@ pub trait Client: Sync + Send + Debug {
     /// number of transactions
     async fn get_transaction_count(&self, address: &Address) -> VdrResult<u64>;
 
-    /// Submit transaction to the ledger
+    /// Send transaction to the ledger without waiting for its inclusion into a block
     ///
     /// # Params
-    /// - `transaction` [Transaction] transaction to submit
+    /// - `transaction` [Transaction] transaction to send
     ///
     /// # Returns
-    /// hash of a block in which transaction included
-    async fn submit_transaction(&self, transaction: &[u8]) -> VdrResult<Vec<u8>>;
+    /// hash of the sent transaction
+    async fn send_transaction(&self, transaction: &[u8]) -> VdrResult<Vec<u8>>;
+
+    /// Get the status of the transaction sent to the ledger
+    ///
+    /// # Params
+    /// - `hash` hash of a transaction to get status
+    ///
+    /// # Returns
+    /// transaction status: pending, included with the number of confirmations or reverted
+    async fn get_transaction_status(&self, hash: &[u8]) -> VdrResult<TransactionStatus>;
 
     /// Submit read transaction to the ledger
     ///
     /// # Params
+    /// - `to` address of the contract to call
     /// - `transaction` [Transaction] prepared transaction to submit
+    /// - `block` [BlockTag] block at which transaction is executed. The latest block is used if not set
     ///
     /// # Returns
     /// result data of transaction execution
-    async fn call_transaction(&self, to: &str, transaction: &[u8]) -> VdrResult<Vec<u8>>;
+    async fn call_transaction(
+        &self,
+        to: &str,
+        transaction: &[u8],
+        block: Option<BlockTag>,
+    ) -> VdrResult<Vec<u8>>;
 
     /// Send a prepared query for retrieving log events on the ledger
     ///
@@ -56,14 +95,119 @@ pub trait Client: Sync + Send + Debug {
     ///   logs - list of received events
     async fn query_events(&self, query: &EventQuery) -> VdrResult<Vec<EventLog>>;
 
-    /// Get the receipt for the given block hash
+    /// Subscribe to log events matching the given query
+    ///     Events are pushed by the node as soon as they appear on the ledger
+    ///
+    /// #Params
+    /// - `query` [EventQuery] query defining events to subscribe to. Block range is ignored
+    ///
+    /// #Returns
+    ///   stream - stream of received events
+    async fn subscribe_events(&self, _query: &EventQuery) -> VdrResult<EventStream> {
+        Err(VdrError::ClientInvalidState(
+            "Event subscriptions are not supported by the client".to_string(),
+        ))
+    }
+
+    /// Execute write transaction on behalf of its sender at the latest block without sending it to the ledger
     ///
     /// # Params
-    /// - `hash` hash of a block to get the receipt
+    /// - `transaction` [Transaction] write transaction to simulate
     ///
     /// # Returns
-    /// receipt as JSON string for the requested block
-    async fn get_receipt(&self, hash: &[u8]) -> VdrResult<String>;
+    /// result data of transaction execution
+    async fn simulate_transaction(&self, _transaction: &Transaction) -> VdrResult<Vec<u8>> {
+        Err(VdrError::ClientInvalidState(
+            "Transaction simulation is not supported by the client".to_string(),
+        ))
+    }
+
+    /// Estimate amount of gas needed to execute the transaction
+    ///
+    /// # Params
+    /// - `transaction` [Transaction] transaction to estimate
+    ///
+    /// # Returns
+    /// estimated amount of gas
+    async fn estimate_gas(&self, _transaction: &Transaction) -> VdrResult<u64> {
+        Err(VdrError::ClientInvalidState(
+            "Gas estimation is not supported by the client".to_string(),
+        ))
+    }
+
+    /// Get current gas price of the network (`eth_gasPrice`)
+    ///
+    /// # Returns
+    /// gas price in wei
+    async fn get_gas_price(&self) -> VdrResult<u64> {
+        Err(VdrError::ClientInvalidState(
+            "Gas price lookup is not supported by the client".to_string(),
+        ))
+    }
+
+    /// Get base fees and priority fees of the latest blocks (`eth_feeHistory`)
+    ///
+    /// # Params
+    /// - `block_count` number of latest blocks to get fees for
+    /// - `reward_percentile` percentile of priority fees paid in each block
+    ///
+    /// # Returns
+    /// fee history of the requested blocks
+    async fn get_fee_history(
+        &self,
+        _block_count: u64,
+        _reward_percentile: f64,
+    ) -> VdrResult<FeeHistory> {
+        Err(VdrError::ClientInvalidState(
+            "Fee history lookup is not supported by the client".to_string(),
+        ))
+    }
+
+    /// Get chain id of the network the node belongs to (`eth_chainId`)
+    ///
+    /// # Returns
+    /// chain id of the network
+    async fn get_chain_id(&self) -> VdrResult<u64> {
+        Err(VdrError::ClientInvalidState(
+            "Chain id lookup is not supported by the client".to_string(),
+        ))
+    }
+
+    /// Get bytecode deployed at the given address at the latest block (`eth_getCode`)
+    ///
+    /// # Params
+    /// - `address` [Address] address of the contract
+    ///
+    /// # Returns
+    /// deployed bytecode. Empty if there is no contract at the address
+    async fn get_code(&self, _address: &Address) -> VdrResult<Vec<u8>> {
+        Err(VdrError::ClientInvalidState(
+            "Contract code lookup is not supported by the client".to_string(),
+        ))
+    }
+
+    /// Get value of the contract storage slot at the latest block (`eth_getStorageAt`)
+    ///
+    /// # Params
+    /// - `address` [Address] address of the contract
+    /// - `slot` 32 bytes position of the storage slot
+    ///
+    /// # Returns
+    /// 32 bytes value stored in the slot
+    async fn get_storage_at(&self, _address: &Address, _slot: &[u8]) -> VdrResult<Vec<u8>> {
+        Err(VdrError::ClientInvalidState(
+            "Contract storage lookup is not supported by the client".to_string(),
+        ))
+    }
+
+    /// Get the receipt for the given transaction hash
+    ///
+    /// # Params
+    /// - `hash` hash of a transaction to get the receipt
+    ///
+    /// # Returns
+    /// receipt of the requested transaction
+    async fn get_receipt(&self, hash: &[u8]) -> VdrResult<TransactionReceipt>;
 
     /// Get details for the given block
     ///
@@ -111,4 +255,10 @@ pub trait Contract: Sync + Send + Debug {
     /// # Returns
     /// Contract errors
     fn errors(&self) -> Vec<&AbiError>;
+
+    /// Get the contract functions
+    ///
+    /// # Returns
+    /// Contract functions defined in the ABI
+    fn functions(&self) -> Vec<&Function>;
 }
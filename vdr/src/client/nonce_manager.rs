@@ -0,0 +1,307 @@
+use log::{trace, warn};
+use log_derive::{logfn, logfn_inputs};
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::{Mutex, MutexGuard},
+};
+
+use crate::{
+    client::Client,
+    error::{VdrError, VdrResult},
+    types::Address,
+};
+
+/// Number of not confirmed nonces of the account after which the manager requests the node on reserve to forget
+/// nonces of the transactions already included into blocks (sent transactions which were never confirmed)
+const MAX_PENDING_NONCES: usize = 64;
+
+/// Local nonce manager allowing to build and send many write transactions from one account concurrently
+///
+/// Nonces are reserved per account without requesting the node for every transaction.
+/// The manager tracks reserved nonces which are not confirmed yet and resyncs the account state with the node
+/// when a transaction fails to be sent, so the gap left by the failed transaction is filled first.
+///
+/// Note: a reserved nonce of a transaction which is not going to be sent must be returned with
+/// [LedgerClient::release_nonce](crate::LedgerClient::release_nonce). Otherwise, the following transactions of
+/// the account will be stuck in the node transaction pool.
+#[derive(Debug, Default)]
+pub struct NonceManager {
+    accounts: Mutex<HashMap<String, AccountNonces>>,
+}
+
+#[derive(Debug, Default)]
+struct AccountNonces {
+    /// next nonce to reserve
+    next: u64,
+    /// reserved nonces of the transactions which are not confirmed yet
+    pending: BTreeSet<u64>,
+    /// account state must be synced with the node before reserving next nonce
+    resync: bool,
+}
+
+impl AccountNonces {
+    fn requires_sync(&self) -> bool {
+        self.resync || self.pending.len() >= MAX_PENDING_NONCES
+    }
+
+    fn sync(&mut self, transaction_count: u64) {
+        self.prune(transaction_count);
+        self.next = transaction_count;
+        self.resync = false;
+    }
+
+    fn prune(&mut self, transaction_count: u64) {
+        // transactions with lower nonces are already included into blocks
+        self.pending.retain(|nonce| *nonce >= transaction_count);
+        self.next = self.next.max(transaction_count);
+    }
+
+    fn reserve(&mut self) -> u64 {
+        let mut nonce = self.next;
+        while self.pending.contains(&nonce) {
+            nonce += 1;
+        }
+        self.pending.insert(nonce);
+        self.next = nonce + 1;
+        nonce
+    }
+}
+
+impl NonceManager {
+    pub fn new() -> NonceManager {
+        NonceManager::default()
+    }
+
+    /// Reserve next nonce for the account
+    ///
+    /// # Params
+    ///  - `client`: [Client] - client used to sync account state with the node
+    ///  - `address`: [Address] - account address
+    ///
+    /// # Returns
+    ///  nonce: [u64] - reserved nonce
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub(crate) async fn reserve(&self, client: &dyn Client, address: &Address) -> VdrResult<u64> {
+        let key = Self::key(address);
+
+        let requires_sync = self
+            .accounts()?
+            .get(&key)
+            .map(|account| account.requires_sync())
+            .unwrap_or(true);
+
+        // request the node without holding the lock. Concurrent requests for the same account get the same count
+        let transaction_count = if requires_sync {
+            Some(client.get_transaction_count(address).await?)
+        } else {
+            None
+        };
+
+        let mut accounts = self.accounts()?;
+        let account = accounts.entry(key).or_insert_with(|| AccountNonces {
+            resync: true,
+            ..AccountNonces::default()
+        });
+        match (account.resync, transaction_count) {
+            (true, Some(transaction_count)) => account.sync(transaction_count),
+            (false, Some(transaction_count)) => account.prune(transaction_count),
+            _ => {}
+        }
+        let nonce = account.reserve();
+
+        trace!(
+            "NonceManager: reserved nonce {} for account {:?}",
+            nonce,
+            address
+        );
+        Ok(nonce)
+    }
+
+    /// Mark nonce as used by the transaction included into a block
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub(crate) fn confirm(&self, address: &Address, nonce: u64) -> VdrResult<()> {
+        if let Some(account) = self.accounts()?.get_mut(&Self::key(address)) {
+            account.pending.remove(&nonce);
+        }
+        Ok(())
+    }
+
+    /// Return nonce of the transaction which was not sent to the ledger.
+    /// Account state will be synced with the node before reserving next nonce
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub(crate) fn release(&self, address: &Address, nonce: u64) -> VdrResult<()> {
+        if let Some(account) = self.accounts()?.get_mut(&Self::key(address)) {
+            account.pending.remove(&nonce);
+            account.resync = true;
+        }
+        Ok(())
+    }
+
+    /// Mark the account state to be synced with the node before reserving next nonce.
+    /// Used when it is unknown whether the node got the transaction: its nonce stays reserved unless the node
+    /// counts the transaction, so the same transaction can be sent again
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub(crate) fn resync(&self, address: &Address) -> VdrResult<()> {
+        if let Some(account) = self.accounts()?.get_mut(&Self::key(address)) {
+            account.resync = true;
+        }
+        Ok(())
+    }
+
+    /// Forget the state of the account. Next nonce will be requested from the node
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub fn reset(&self, address: &Address) -> VdrResult<()> {
+        self.accounts()?.remove(&Self::key(address));
+        Ok(())
+    }
+
+    fn key(address: &Address) -> String {
+        address.as_ref().to_lowercase()
+    }
+
+    fn accounts(&self) -> VdrResult<MutexGuard<'_, HashMap<String, AccountNonces>>> {
+        self.accounts.lock().map_err(|_| {
+            let vdr_error =
+                VdrError::ClientInvalidState("Nonce manager state is poisoned".to_string());
+
+            warn!("Error: {} during accessing nonce manager", vdr_error);
+
+            vdr_error
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::client::{client::test::TRUSTEE_ACCOUNT, MockClient};
+    use futures::future::join_all;
+    use rstest::rstest;
+
+    const TRANSACTION_COUNT: u64 = 5;
+
+    fn mock_client(transaction_counts: Vec<u64>) -> MockClient {
+        let mut client = MockClient::new();
+        let mut sequence = mockall::Sequence::new();
+        for count in transaction_counts {
+            client
+                .expect_get_transaction_count()
+                .times(1)
+                .in_sequence(&mut sequence)
+                .returning(move |_| Ok(count));
+        }
+        client
+    }
+
+    #[async_std::test]
+    async fn reserve_sequential_nonces() {
+        let client = mock_client(vec![TRANSACTION_COUNT]);
+        let manager = NonceManager::new();
+
+        let mut nonces = vec![];
+        for _ in 0..3 {
+            nonces.push(manager.reserve(&client, &TRUSTEE_ACCOUNT).await.unwrap());
+        }
+
+        assert_eq!(vec![5, 6, 7], nonces);
+    }
+
+    #[async_std::test]
+    async fn reserve_concurrent_nonces() {
+        let mut client = MockClient::new();
+        client
+            .expect_get_transaction_count()
+            .returning(|_| Ok(TRANSACTION_COUNT));
+        let manager = NonceManager::new();
+
+        let mut nonces = join_all((0..5).map(|_| manager.reserve(&client, &TRUSTEE_ACCOUNT)))
+            .await
+            .into_iter()
+            .collect::<VdrResult<Vec<u64>>>()
+            .unwrap();
+        nonces.sort();
+
+        assert_eq!(vec![5, 6, 7, 8, 9], nonces);
+    }
+
+    #[async_std::test]
+    async fn released_nonce_fills_gap_after_resync() {
+        let client = mock_client(vec![TRANSACTION_COUNT, TRANSACTION_COUNT + 1]);
+        let manager = NonceManager::new();
+
+        for _ in 0..3 {
+            manager.reserve(&client, &TRUSTEE_ACCOUNT).await.unwrap();
+        }
+        manager.confirm(&TRUSTEE_ACCOUNT, 5).unwrap();
+        // transaction with nonce 6 failed to be sent, transaction with nonce 7 is pending
+        manager.release(&TRUSTEE_ACCOUNT, 6).unwrap();
+
+        let gap = manager.reserve(&client, &TRUSTEE_ACCOUNT).await.unwrap();
+        let next = manager.reserve(&client, &TRUSTEE_ACCOUNT).await.unwrap();
+
+        assert_eq!(6, gap);
+        assert_eq!(8, next);
+    }
+
+    #[rstest]
+    #[case::transaction_received(TRANSACTION_COUNT + 1, 6)]
+    #[case::transaction_lost(TRANSACTION_COUNT, 6)]
+    #[async_std::test]
+    async fn resync_keeps_nonce_of_possibly_sent_transaction(
+        #[case] pending_count: u64,
+        #[case] expected: u64,
+    ) {
+        let client = mock_client(vec![TRANSACTION_COUNT, pending_count]);
+        let manager = NonceManager::new();
+
+        let nonce = manager.reserve(&client, &TRUSTEE_ACCOUNT).await.unwrap();
+        // node became unreachable while sending transaction with nonce 5
+        manager.resync(&TRUSTEE_ACCOUNT).unwrap();
+
+        assert_eq!(TRANSACTION_COUNT, nonce);
+        assert_eq!(
+            expected,
+            manager.reserve(&client, &TRUSTEE_ACCOUNT).await.unwrap()
+        );
+    }
+
+    #[async_std::test]
+    async fn not_confirmed_nonces_pruned_against_node() {
+        let sent = MAX_PENDING_NONCES as u64;
+        let client = mock_client(vec![TRANSACTION_COUNT, TRANSACTION_COUNT + sent]);
+        let manager = NonceManager::new();
+
+        // transactions are sent and included into blocks but never confirmed with the manager
+        for _ in 0..sent {
+            manager.reserve(&client, &TRUSTEE_ACCOUNT).await.unwrap();
+        }
+        let nonce = manager.reserve(&client, &TRUSTEE_ACCOUNT).await.unwrap();
+
+        assert_eq!(TRANSACTION_COUNT + sent, nonce);
+        assert_eq!(
+            1,
+            manager.accounts().unwrap()[&NonceManager::key(&TRUSTEE_ACCOUNT)]
+                .pending
+                .len()
+        );
+    }
+
+    #[async_std::test]
+    async fn reset_account_state() {
+        let client = mock_client(vec![TRANSACTION_COUNT, TRANSACTION_COUNT]);
+        let manager = NonceManager::new();
+
+        manager.reserve(&client, &TRUSTEE_ACCOUNT).await.unwrap();
+        manager.reset(&TRUSTEE_ACCOUNT).unwrap();
+
+        assert_eq!(
+            TRANSACTION_COUNT,
+            manager.reserve(&client, &TRUSTEE_ACCOUNT).await.unwrap()
+        );
+    }
+}
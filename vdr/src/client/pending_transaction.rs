@@ -0,0 +1,588 @@
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+use log::warn;
+use log_derive::{logfn, logfn_inputs};
+
+use crate::{
+    client::{ConfirmationPolicy, LedgerClient},
+    error::{VdrError, VdrResult},
+    types::{Transaction, TransactionReceipt, TransactionStatus},
+};
+
+/// Handle to the transaction sent to the ledger without waiting for its inclusion into a block
+///
+/// Returned by [LedgerClient::send_transaction]. Allows many write transactions to be sent one after another
+/// and awaited later.
+#[derive(Debug)]
+pub struct PendingTransaction<'a> {
+    client: &'a LedgerClient,
+    transaction: Transaction,
+    hash: Vec<u8>,
+    cancelled: AtomicBool,
+}
+
+impl<'a> PendingTransaction<'a> {
+    pub(crate) fn new(
+        client: &'a LedgerClient,
+        transaction: &Transaction,
+        hash: Vec<u8>,
+    ) -> PendingTransaction<'a> {
+        PendingTransaction {
+            client,
+            transaction: transaction.clone(),
+            hash,
+            cancelled: AtomicBool::new(false),
+        }
+    }
+
+    /// Get hash of the sent transaction
+    pub fn hash(&self) -> &[u8] {
+        &self.hash
+    }
+
+    /// Get current status of the sent transaction
+    ///
+    /// # Returns
+    ///  status: [TransactionStatus] - transaction status
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn status(&self) -> VdrResult<TransactionStatus> {
+        if self.is_cancelled() {
+            return Ok(TransactionStatus::Cancelled);
+        }
+        self.client.get_transaction_status(&self.hash).await
+    }
+
+    /// Status of the transaction with not decoded revert reason
+    async fn raw_status(&self) -> VdrResult<TransactionStatus> {
+        if self.is_cancelled() {
+            return Ok(TransactionStatus::Cancelled);
+        }
+        self.client.get_raw_transaction_status(&self.hash).await
+    }
+
+    /// Wait until the transaction is included into a block and gets the requested number of confirmations
+    ///
+    /// # Params
+    ///  - `confirmations`: [u64] - number of blocks (including the block with the transaction) to wait for
+    ///  - `timeout`: [Duration] - max time to wait
+    ///
+    /// # Returns
+    ///  receipt: [TransactionReceipt] - receipt of the transaction
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn wait_for_receipt(
+        &self,
+        confirmations: u64,
+        timeout: Duration,
+    ) -> VdrResult<TransactionReceipt> {
+        let policy = ConfirmationPolicy {
+            confirmations: Some(confirmations),
+            timeout: Some(timeout.as_millis() as u64),
+            ..ConfirmationPolicy::default()
+        };
+        self.wait(&policy).await
+    }
+
+    /// Wait until the transaction is confirmed according to the given policy
+    ///
+    /// # Params
+    ///  - `policy`: [ConfirmationPolicy] - policy defining confirmation depth, poll interval and timeout
+    ///
+    /// # Returns
+    ///  receipt: [TransactionReceipt] - receipt of the transaction
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn wait(&self, policy: &ConfirmationPolicy) -> VdrResult<TransactionReceipt> {
+        self.confirm(policy).await?;
+        self.client.get_receipt(&self.hash).await
+    }
+
+    pub(crate) async fn confirm(&self, policy: &ConfirmationPolicy) -> VdrResult<()> {
+        let confirm = async {
+            loop {
+                match self.raw_status().await? {
+                    TransactionStatus::Included { confirmations, .. }
+                        if policy.is_instant_finality()
+                            || confirmations >= policy.confirmations() =>
+                    {
+                        self.client.confirm_nonce(&self.transaction)?;
+                        break;
+                    }
+                    TransactionStatus::Reverted { revert_reason, .. } => {
+                        // reverted transaction is included into a block and uses its nonce
+                        self.client.confirm_nonce(&self.transaction)?;
+                        let decoded_reason = self
+                            .client
+                            .decode_revert_reason(&revert_reason)
+                            .unwrap_or(revert_reason);
+                        return Err(VdrError::ClientTransactionReverted(decoded_reason));
+                    }
+                    TransactionStatus::Cancelled => {
+                        return Err(VdrError::ClientInvalidState(
+                            "Waiting for the transaction was cancelled".to_string(),
+                        ));
+                    }
+                    _ => async_std::task::sleep(policy.poll_interval()).await,
+                }
+            }
+
+            self.client
+                .check_quorum(&self.transaction, &self.hash)
+                .await
+        };
+
+        let timeout = match policy.timeout() {
+            Some(timeout) => timeout,
+            None => return confirm.await,
+        };
+
+        async_std::future::timeout(timeout, confirm)
+            .await
+            .map_err(|_| {
+                let vdr_error = VdrError::ClientTimeout(format!(
+                    "Transaction {} is not confirmed within {:?}",
+                    hex::encode(&self.hash),
+                    timeout
+                ));
+
+                warn!("Error: {} during waiting for transaction", vdr_error);
+
+                vdr_error
+            })?
+    }
+
+    /// Stop waiting for the transaction
+    ///
+    /// Pending and future calls of [PendingTransaction::wait] will fail.
+    /// Note: the transaction is not removed from the node transaction pool and still can be included into a block.
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed)
+    }
+
+    /// Check whether waiting for the transaction was cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::{
+        client::{
+            client::test::{contracts, CONFIG, TEST_NETWORK, TRUSTEE_ACCOUNT},
+            Client, LedgerClientBuilder, MockClient, NonceManager, QuorumConfig,
+        },
+        signer::MockSigner,
+        types::{
+            transaction::test::{read_transaction, write_transaction},
+            SignatureData,
+        },
+    };
+    use mockall::{predicate::eq, Sequence};
+    use rstest::rstest;
+
+    const TX_HASH: [u8; 32] = [1; 32];
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    fn ledger_client(client: MockClient) -> LedgerClient {
+        let client: Box<dyn Client> = Box::new(client);
+        LedgerClientBuilder::new()
+            .set_chain_id(CONFIG.chain_id)
+            .set_client(client)
+            .set_contract_configs(&contracts())
+            .set_network(TEST_NETWORK)
+            .build()
+            .unwrap()
+    }
+
+    fn signed_write_transaction() -> Transaction {
+        let mut transaction = Transaction {
+            to: CONFIG.contracts.ethereum_did_registry.address.clone(),
+            ..write_transaction()
+        };
+        transaction.set_signature(SignatureData {
+            recovery_id: 1,
+            signature: vec![1; 64],
+        });
+        transaction
+    }
+
+    fn receipt() -> TransactionReceipt {
+        TransactionReceipt {
+            transaction_hash: TX_HASH.to_vec(),
+            block_number: 1,
+            success: true,
+            ..TransactionReceipt::default()
+        }
+    }
+
+    fn mock_sent_transaction(statuses: Vec<TransactionStatus>) -> MockClient {
+        let mut client = MockClient::new();
+        client
+            .expect_send_transaction()
+            .returning(|_| Ok(TX_HASH.to_vec()));
+        let mut sequence = Sequence::new();
+        for status in statuses {
+            client
+                .expect_get_transaction_status()
+                .with(eq(TX_HASH.to_vec()))
+                .times(1)
+                .in_sequence(&mut sequence)
+                .returning(move |_| Ok(status.clone()));
+        }
+        client
+    }
+
+    #[async_std::test]
+    async fn send_read_transaction_not_allowed() {
+        let client = ledger_client(MockClient::new());
+
+        let err = client
+            .send_transaction(&read_transaction())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, VdrError::ClientInvalidTransaction { .. }));
+    }
+
+    #[async_std::test]
+    async fn wait_for_receipt_positive() {
+        let mut mock = mock_sent_transaction(vec![
+            TransactionStatus::Pending,
+            TransactionStatus::Included {
+                block_number: 1,
+                confirmations: 1,
+            },
+            TransactionStatus::Included {
+                block_number: 1,
+                confirmations: 2,
+            },
+        ]);
+        mock.expect_get_receipt()
+            .with(eq(TX_HASH.to_vec()))
+            .returning(|_| Ok(receipt()));
+        let client = ledger_client(mock);
+
+        let pending = client
+            .send_transaction(&signed_write_transaction())
+            .await
+            .unwrap();
+        let actual_receipt = pending.wait_for_receipt(2, TIMEOUT).await.unwrap();
+
+        assert_eq!(TX_HASH.to_vec(), pending.hash());
+        assert_eq!(receipt(), actual_receipt);
+    }
+
+    #[async_std::test]
+    async fn wait_with_instant_finality() {
+        let mut mock = mock_sent_transaction(vec![
+            TransactionStatus::Pending,
+            TransactionStatus::Included {
+                block_number: 1,
+                confirmations: 1,
+            },
+        ]);
+        mock.expect_get_receipt().returning(|_| Ok(receipt()));
+        let client = ledger_client(mock);
+        let policy = ConfirmationPolicy {
+            confirmations: Some(10),
+            poll_interval: Some(10),
+            ..ConfirmationPolicy::instant_finality()
+        };
+
+        let pending = client
+            .send_transaction(&signed_write_transaction())
+            .await
+            .unwrap();
+
+        assert_eq!(receipt(), pending.wait(&policy).await.unwrap());
+    }
+
+    #[async_std::test]
+    async fn submit_write_transaction_with_policy() {
+        let client = ledger_client(mock_sent_transaction(vec![
+            TransactionStatus::Included {
+                block_number: 1,
+                confirmations: 1,
+            },
+            TransactionStatus::Included {
+                block_number: 1,
+                confirmations: 3,
+            },
+        ]));
+        let policy = ConfirmationPolicy {
+            confirmations: Some(3),
+            poll_interval: Some(10),
+            ..ConfirmationPolicy::default()
+        };
+
+        let hash = client
+            .submit_transaction_with_policy(&signed_write_transaction(), &policy)
+            .await
+            .unwrap();
+
+        assert_eq!(TX_HASH.to_vec(), hash);
+    }
+
+    #[async_std::test]
+    async fn sign_and_submit_write_transaction() {
+        let client = ledger_client(mock_sent_transaction(vec![TransactionStatus::Included {
+            block_number: 1,
+            confirmations: 1,
+        }]));
+        let mut signer = MockSigner::new();
+        signer
+            .expect_sign_transaction()
+            .withf(|transaction| transaction.from == Some(TRUSTEE_ACCOUNT.clone()))
+            .times(1)
+            .returning(|_| {
+                Ok(SignatureData {
+                    recovery_id: 1,
+                    signature: vec![1; 64],
+                })
+            });
+        let transaction = Transaction {
+            to: CONFIG.contracts.ethereum_did_registry.address.clone(),
+            ..write_transaction()
+        };
+
+        let hash = client.sign_and_submit(&transaction, &signer).await.unwrap();
+
+        assert_eq!(TX_HASH.to_vec(), hash);
+    }
+
+    #[async_std::test]
+    async fn submit_write_transaction_quorum_receipt_mismatch() {
+        let mut mock = mock_sent_transaction(vec![TransactionStatus::Included {
+            block_number: 1,
+            confirmations: 1,
+        }]);
+        mock.expect_get_receipt().returning(|_| Ok(receipt()));
+        let mut quorum_client = MockClient::new();
+        quorum_client.expect_get_receipt().returning(|_| {
+            Ok(TransactionReceipt {
+                block_hash: vec![2; 32],
+                ..receipt()
+            })
+        });
+        let client = LedgerClientBuilder::new()
+            .set_chain_id(CONFIG.chain_id)
+            .set_client(Box::new(mock))
+            .set_contract_configs(&contracts())
+            .set_quorum_config(&QuorumConfig {
+                nodes: vec![],
+                retry_interval: Some(10),
+                ..QuorumConfig::default()
+            })
+            .add_quorum_client(Box::new(quorum_client))
+            .build()
+            .unwrap();
+
+        let err = client
+            .submit_transaction(&signed_write_transaction())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, VdrError::QuorumNotReached { .. }));
+    }
+
+    #[async_std::test]
+    async fn wait_for_receipt_reverted() {
+        let client = ledger_client(mock_sent_transaction(vec![TransactionStatus::Reverted {
+            block_number: 1,
+            revert_reason:
+                "0x4e487b710000000000000000000000000000000000000000000000000000000000000011"
+                    .to_string(),
+        }]));
+
+        let pending = client
+            .send_transaction(&signed_write_transaction())
+            .await
+            .unwrap();
+        let err = pending.wait_for_receipt(1, TIMEOUT).await.unwrap_err();
+
+        assert_eq!(
+            VdrError::ClientTransactionReverted("Panic(code: 11)".to_string()),
+            err
+        );
+    }
+
+    #[async_std::test]
+    async fn status_of_reverted_transaction_with_unknown_error() {
+        let client = ledger_client(mock_sent_transaction(vec![TransactionStatus::Reverted {
+            block_number: 1,
+            revert_reason: "0x9999999e".to_string(),
+        }]));
+
+        let pending = client
+            .send_transaction(&signed_write_transaction())
+            .await
+            .unwrap();
+        let status = pending.status().await.unwrap();
+
+        assert_eq!(
+            TransactionStatus::Reverted {
+                block_number: 1,
+                revert_reason: "0x9999999e".to_string(),
+            },
+            status
+        );
+    }
+
+    #[async_std::test]
+    async fn wait_for_receipt_timeout() {
+        let mut mock = MockClient::new();
+        mock.expect_send_transaction()
+            .returning(|_| Ok(TX_HASH.to_vec()));
+        mock.expect_get_transaction_status()
+            .returning(|_| Ok(TransactionStatus::Pending));
+        let client = ledger_client(mock);
+
+        let pending = client
+            .send_transaction(&signed_write_transaction())
+            .await
+            .unwrap();
+        let err = pending
+            .wait_for_receipt(1, Duration::from_millis(500))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, VdrError::ClientTimeout { .. }));
+    }
+
+    const REJECTED_ERROR: &str = "Transaction gas limit exceeds block gas limit";
+
+    #[async_std::test]
+    async fn send_failure_releases_reserved_nonce() {
+        let mut mock = MockClient::new();
+        mock.expect_get_transaction_count().returning(|_| Ok(1));
+        mock.expect_send_transaction().returning(|_| {
+            Err(VdrError::ClientInvalidTransaction(
+                REJECTED_ERROR.to_string(),
+            ))
+        });
+        let client: Box<dyn Client> = Box::new(mock);
+        let client = LedgerClientBuilder::new()
+            .set_chain_id(CONFIG.chain_id)
+            .set_client(client)
+            .set_contract_configs(&contracts())
+            .set_nonce_manager(NonceManager::new())
+            .build()
+            .unwrap();
+
+        let first_nonce = client.reserve_nonce(&TRUSTEE_ACCOUNT).await.unwrap();
+        let second_nonce = client.reserve_nonce(&TRUSTEE_ACCOUNT).await.unwrap();
+        let transaction = Transaction {
+            nonce: Some(second_nonce),
+            ..signed_write_transaction()
+        };
+
+        let err = client.send_transaction(&transaction).await.unwrap_err();
+
+        assert_eq!(
+            VdrError::ClientInvalidTransaction(REJECTED_ERROR.to_string()),
+            err
+        );
+        assert_eq!((1, 2), (first_nonce, second_nonce));
+        assert_eq!(
+            second_nonce,
+            client.reserve_nonce(&TRUSTEE_ACCOUNT).await.unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case::unreachable(VdrError::ClientNodeUnreachable)]
+    #[case::rpc_error(VdrError::ClientUnexpectedError(
+        r#"{"code":-32000,"message":"Transaction gas limit exceeds block gas limit"}"#.to_string()
+    ))]
+    #[async_std::test]
+    async fn unknown_send_failure_keeps_reserved_nonce(#[case] send_error: VdrError) {
+        let mut mock = MockClient::new();
+        mock.expect_get_transaction_count().returning(|_| Ok(1));
+        let error = send_error.clone();
+        mock.expect_send_transaction()
+            .returning(move |_| Err(error.clone()));
+        let client: Box<dyn Client> = Box::new(mock);
+        let client = LedgerClientBuilder::new()
+            .set_chain_id(CONFIG.chain_id)
+            .set_client(client)
+            .set_contract_configs(&contracts())
+            .set_nonce_manager(NonceManager::new())
+            .build()
+            .unwrap();
+
+        let nonce = client.reserve_nonce(&TRUSTEE_ACCOUNT).await.unwrap();
+        let transaction = Transaction {
+            nonce: Some(nonce),
+            ..signed_write_transaction()
+        };
+
+        let err = client.send_transaction(&transaction).await.unwrap_err();
+
+        assert_eq!(send_error, err);
+        // the node may have got the transaction, so its nonce is not reused
+        assert_eq!(
+            nonce + 1,
+            client.reserve_nonce(&TRUSTEE_ACCOUNT).await.unwrap()
+        );
+    }
+
+    #[async_std::test]
+    async fn signing_failure_releases_reserved_nonce() {
+        let mut mock = MockClient::new();
+        mock.expect_get_transaction_count().returning(|_| Ok(1));
+        mock.expect_send_transaction().never();
+        let client: Box<dyn Client> = Box::new(mock);
+        let client = LedgerClientBuilder::new()
+            .set_chain_id(CONFIG.chain_id)
+            .set_client(client)
+            .set_contract_configs(&contracts())
+            .set_nonce_manager(NonceManager::new())
+            .build()
+            .unwrap();
+        let mut signer = MockSigner::new();
+        signer
+            .expect_sign_transaction()
+            .returning(|_| Err(VdrError::SignerMissingKey("key".to_string())));
+
+        let nonce = client.reserve_nonce(&TRUSTEE_ACCOUNT).await.unwrap();
+        let transaction = Transaction {
+            to: CONFIG.contracts.ethereum_did_registry.address.clone(),
+            nonce: Some(nonce),
+            ..write_transaction()
+        };
+
+        let err = client
+            .sign_and_submit(&transaction, &signer)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, VdrError::SignerMissingKey { .. }));
+        assert_eq!(nonce, client.reserve_nonce(&TRUSTEE_ACCOUNT).await.unwrap());
+    }
+
+    #[async_std::test]
+    async fn wait_for_cancelled_transaction() {
+        let client = ledger_client(mock_sent_transaction(vec![]));
+
+        let pending = client
+            .send_transaction(&signed_write_transaction())
+            .await
+            .unwrap();
+        pending.cancel();
+
+        assert_eq!(
+            TransactionStatus::Cancelled,
+            pending.status().await.unwrap()
+        );
+        let err = pending.wait_for_receipt(1, TIMEOUT).await.unwrap_err();
+        assert!(matches!(err, VdrError::ClientInvalidState { .. }));
+    }
+}
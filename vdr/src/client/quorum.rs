@@ -1,22 +1,24 @@
-use futures::{
-    channel::{
-        mpsc,
-        mpsc::{Receiver, Sender},
-    },
-    StreamExt,
-};
-use log::trace;
+use chrono::{DateTime, Utc};
+use futures::{lock::Mutex, stream::FuturesUnordered, Future, StreamExt};
+use log::{trace, warn};
 use log_derive::{logfn, logfn_inputs};
 use serde_derive::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
 use std::{
-    fmt::{Debug, Formatter},
+    collections::{HashMap, HashSet},
+    fmt::{Debug, Display, Formatter},
     sync::Arc,
     time::Duration,
 };
 
 use crate::{
-    client::implementation::web3::client::Web3Client, Client, Transaction, TransactionType,
-    VdrError, VdrResult,
+    client::{implementation::web3::client::Web3Client, ConnectionConfig},
+    contracts::validator_control::{
+        build_get_validator_events_query, build_get_validators_transaction,
+        parse_get_validators_result,
+    },
+    Address, Block, BlockTag, Client, EventLog, EventQuery, LedgerClient, Transaction,
+    TransactionReceipt, TransactionType, VdrError, VdrResult,
 };
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -25,14 +27,353 @@ pub struct QuorumConfig {
     pub request_retries: Option<u8>,
     pub request_timeout: Option<u64>,
     pub retry_interval: Option<u64>,
+    /// Policy used to check results of read transactions and event queries (`Minimal` if not set)
+    pub read_policy: Option<QuorumPolicy>,
+    /// Policy used to check write transactions (`Minimal` if not set)
+    pub write_policy: Option<QuorumPolicy>,
+    /// Weights of the nodes listed in `nodes` or `validator_nodes` (1 if not set). Custom quorum clients have weight 1
+    pub weights: Option<HashMap<String, u64>>,
+    /// RPC endpoints of the validators by validator address. If set, the validators returned by
+    /// `ValidatorControl.getValidators` are used as quorum nodes in addition to `nodes`. The validator list is
+    /// refreshed when `ValidatorAdded` or `ValidatorRemoved` events appear, checked at most once per 10 seconds
+    pub validator_nodes: Option<HashMap<String, String>>,
+    /// Headers, authentication and TLS settings of the connections to `nodes` and `validator_nodes`
+    pub connection: Option<ConnectionConfig>,
+}
+
+/// Policy defining how many quorum nodes must return the same result as the primary node
+///
+/// Policies count weights of the agreed nodes (every node has weight 1 unless configured otherwise).
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum QuorumPolicy {
+    /// More than one third of the total weight: at least one honest node confirms the result
+    #[default]
+    Minimal,
+    /// Fixed number (weight) of agreed nodes. Must not exceed the total weight of quorum nodes
+    Count(u64),
+    /// Fraction of the total weight in range (0, 1]
+    Fraction(f64),
+    /// Byzantine fault tolerant agreement: 2f + 1 where f = (n - 1) / 3 is the number of faulty nodes tolerated
+    Bft,
+    /// All nodes must agree
+    All,
+}
+
+impl QuorumPolicy {
+    fn validate(&self) -> VdrResult<()> {
+        match self {
+            QuorumPolicy::Count(0) => Err(VdrError::ClientInvalidState(
+                "Quorum policy count must be positive".to_string(),
+            )),
+            QuorumPolicy::Fraction(fraction) if !(*fraction > 0.0 && *fraction <= 1.0) => {
+                Err(VdrError::ClientInvalidState(format!(
+                    "Quorum policy fraction must be in range (0, 1], got {}",
+                    fraction
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Check that the quorum can be reached with the total weight of quorum nodes
+    fn validate_weight(&self, total_weight: u64) -> VdrResult<()> {
+        match self {
+            QuorumPolicy::Count(count) if *count > total_weight => {
+                Err(VdrError::ClientInvalidState(format!(
+                    "Quorum policy count {} exceeds the total weight {} of quorum nodes",
+                    count, total_weight
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Weight of the agreed nodes required to reach the quorum
+    pub(crate) fn approvals_needed(&self, total_weight: u64) -> u64 {
+        let approvals_needed = match self {
+            QuorumPolicy::Minimal => total_weight / 3 + 1,
+            QuorumPolicy::Count(count) => *count,
+            QuorumPolicy::Fraction(fraction) => (total_weight as f64 * fraction).ceil() as u64,
+            QuorumPolicy::Bft => 2 * (total_weight.saturating_sub(1) / 3) + 1,
+            QuorumPolicy::All => total_weight,
+        };
+        approvals_needed.max(1)
+    }
 }
 
 const DEFAULT_REQUEST_RETRIES: u8 = 4;
 const DEFAULT_REQUEST_TIMEOUT: u64 = 2000;
 const DEFAULT_RETRY_INTERVAL: u64 = 500;
+const DEFAULT_NODE_WEIGHT: u64 = 1;
+const DEFAULT_VALIDATOR_SYNC_INTERVAL: i64 = 10000;
+/// Validator events are not queried for more blocks as nodes limit the block range of the logs query
+const MAX_VALIDATOR_EVENTS_RANGE: u64 = 1000;
+
+/// Create HTTP client connected to the quorum node
+fn node_client(
+    node_address: &str,
+    connection: Option<&ConnectionConfig>,
+) -> VdrResult<Box<dyn Client>> {
+    let client = match connection {
+        Some(connection) => Web3Client::new_with_config(node_address, connection)?,
+        None => Web3Client::new(node_address)?,
+    };
+    Ok(Box::new(client))
+}
+
+/// Node used for quorum checks
+#[derive(Clone, Debug)]
+struct QuorumNode {
+    /// Node address or name of the custom client
+    name: String,
+    client: Arc<Box<dyn Client>>,
+    weight: u64,
+}
+
+/// Response of the node: `None` if the node returned errors or did not respond in time
+type NodeResponse = (usize, Option<Vec<u8>>);
+
+/// Request sent to every quorum node to compare its result with the result of the primary node
+#[derive(Clone, Debug)]
+enum QuorumRequest {
+    /// Get receipt of the transaction included into a block. Result is the hash of the receipt
+    GetReceipt { hash: Vec<u8> },
+    /// Execute read transaction. Result is the transaction output
+    CallTransaction {
+        to: String,
+        data: Vec<u8>,
+        block: Option<BlockTag>,
+    },
+    /// Query event logs. Result is the hash of the logs
+    QueryEvents { query: EventQuery },
+}
+
+impl QuorumRequest {
+    async fn send(&self, client: &dyn Client) -> VdrResult<Option<Vec<u8>>> {
+        match self {
+            QuorumRequest::GetReceipt { hash } => client
+                .get_receipt(hash)
+                .await
+                .map(|receipt| Some(receipt.hash())),
+            QuorumRequest::CallTransaction { to, data, block } => client
+                .call_transaction(to, data, block.clone())
+                .await
+                .map(Some),
+            QuorumRequest::QueryEvents { query } => client
+                .query_events(query)
+                .await
+                .map(|events| Some(QuorumHandler::events_hash(&events))),
+        }
+    }
+}
+
+/// Outcome of the quorum check: names of the nodes agreed, disagreed and failed to respond
+#[derive(Debug, Default)]
+struct QuorumReport {
+    reached: bool,
+    agreed: Vec<String>,
+    disagreed: Vec<String>,
+    failed: Vec<String>,
+}
+
+impl Display for QuorumReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "agreed: {:?}, disagreed: {:?}, failed or timed out: {:?}",
+            self.agreed, self.disagreed, self.failed
+        )
+    }
+}
+
+/// Quorum nodes derived from the validator list of `ValidatorControl` contract
+struct ValidatorNodes {
+    /// RPC endpoints by validator address (lowercase)
+    endpoints: HashMap<String, String>,
+    weights: HashMap<String, u64>,
+    connection: Option<ConnectionConfig>,
+    /// Minimal interval between validator list refreshes
+    sync_interval: chrono::Duration,
+    /// Held while the validator list is being refreshed, so that only one refresh is in flight
+    refresh: Mutex<()>,
+    state: Mutex<ValidatorNodesState>,
+}
+
+#[derive(Default)]
+struct ValidatorNodesState {
+    /// Block the validator list was last synced at
+    synced_block: Option<u64>,
+    /// Time of the last sync
+    synced_at: Option<DateTime<Utc>>,
+    nodes: Vec<QuorumNode>,
+}
+
+impl ValidatorNodes {
+    fn new(
+        endpoints: HashMap<String, String>,
+        weights: HashMap<String, u64>,
+        connection: Option<ConnectionConfig>,
+    ) -> ValidatorNodes {
+        ValidatorNodes {
+            endpoints: endpoints
+                .into_iter()
+                .map(|(validator, endpoint)| (ValidatorNodes::key(&validator), endpoint))
+                .collect(),
+            weights,
+            connection,
+            sync_interval: chrono::Duration::milliseconds(DEFAULT_VALIDATOR_SYNC_INTERVAL),
+            refresh: Mutex::new(()),
+            state: Mutex::new(ValidatorNodesState::default()),
+        }
+    }
+
+    fn key(validator: &str) -> String {
+        Address::from(validator).as_ref().to_lowercase()
+    }
+
+    /// Refresh the validator list if it has changed since the last sync
+    ///
+    /// The list is refreshed at most once per `sync_interval`. Validators are requested on the first sync and then
+    /// only if `ValidatorAdded` or `ValidatorRemoved` events were emitted in the blocks produced since the last
+    /// sync. If the events can not be queried (too many blocks passed or the node rejected the query), validators
+    /// are requested directly. Concurrent calls do not wait for a refresh in flight and use the current list.
+    async fn sync(&self, ledger: &LedgerClient, client: &dyn Client) -> VdrResult<()> {
+        let _refresh = match self.refresh.try_lock() {
+            Some(refresh) => refresh,
+            None if self.synced().await => return Ok(()),
+            None => self.refresh.lock().await,
+        };
+
+        let synced_block = {
+            let state = self.state.lock().await;
+            if let Some(synced_at) = state.synced_at {
+                if Utc::now() - synced_at < self.sync_interval {
+                    return Ok(());
+                }
+            }
+            state.synced_block
+        };
+
+        let latest_block = client.get_block(None).await?.number;
+
+        let changed = match synced_block {
+            None => true,
+            Some(synced_block) if synced_block >= latest_block => false,
+            Some(synced_block) if latest_block - synced_block > MAX_VALIDATOR_EVENTS_RANGE => true,
+            Some(synced_block) => {
+                self.validator_events_emitted(ledger, client, synced_block + 1, latest_block)
+                    .await
+            }
+        };
+
+        let validators = if changed {
+            let mut transaction = build_get_validators_transaction(ledger).await?;
+            transaction.set_block_tag(BlockTag::Number(latest_block));
+            let output = client
+                .call_transaction(
+                    transaction.to.as_ref(),
+                    &transaction.data,
+                    transaction.block_tag.clone(),
+                )
+                .await?;
+            Some(parse_get_validators_result(ledger, &output)?)
+        } else {
+            None
+        };
+
+        let mut state = self.state.lock().await;
+        if let Some(validators) = validators {
+            state.nodes = self.build_nodes(&validators, &state.nodes)?;
+            trace!(
+                "Quorum validator nodes synced at block {}: {:?}",
+                latest_block,
+                state
+                    .nodes
+                    .iter()
+                    .map(|node| &node.name)
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        state.synced_block = Some(latest_block);
+        state.synced_at = Some(Utc::now());
+        Ok(())
+    }
+
+    async fn synced(&self) -> bool {
+        self.state.lock().await.synced_block.is_some()
+    }
+
+    /// Whether validator events were emitted in the block range. Query failures are treated as changes
+    async fn validator_events_emitted(
+        &self,
+        ledger: &LedgerClient,
+        client: &dyn Client,
+        from_block: u64,
+        to_block: u64,
+    ) -> bool {
+        let events = match build_get_validator_events_query(
+            ledger,
+            Some(&Block::from(from_block)),
+            Some(&Block::from(to_block)),
+        ) {
+            Ok(query) => client.query_events(&query).await,
+            Err(err) => Err(err),
+        };
+
+        match events {
+            Ok(events) => !events.is_empty(),
+            Err(err) => {
+                warn!(
+                    "Error: {} during querying validator events. Validators are requested directly",
+                    err
+                );
+                true
+            }
+        }
+    }
+
+    fn build_nodes(
+        &self,
+        validators: &[Address],
+        current_nodes: &[QuorumNode],
+    ) -> VdrResult<Vec<QuorumNode>> {
+        let mut nodes = Vec::new();
+        for validator in validators {
+            let endpoint = match self.endpoints.get(&ValidatorNodes::key(validator.as_ref())) {
+                Some(endpoint) => endpoint,
+                None => {
+                    warn!(
+                        "RPC endpoint is not configured for validator {}. Validator is not used for quorum checks",
+                        validator.as_ref()
+                    );
+                    continue;
+                }
+            };
+
+            let node = match current_nodes.iter().find(|node| &node.name == endpoint) {
+                Some(node) => node.clone(),
+                None => QuorumNode {
+                    name: endpoint.to_string(),
+                    client: Arc::new(node_client(endpoint, self.connection.as_ref())?),
+                    weight: self
+                        .weights
+                        .get(endpoint)
+                        .copied()
+                        .unwrap_or(DEFAULT_NODE_WEIGHT),
+                },
+            };
+            nodes.push(node);
+        }
+        Ok(nodes)
+    }
+}
 
 pub struct QuorumHandler {
-    clients: Vec<Arc<Box<dyn Client>>>,
+    nodes: Vec<QuorumNode>,
+    validator_nodes: Option<ValidatorNodes>,
+    read_policy: QuorumPolicy,
+    write_policy: QuorumPolicy,
     request_retries: u8,
     request_timeout: Duration,
     retry_interval: Duration,
@@ -42,17 +383,101 @@ impl QuorumHandler {
     #[logfn(Info)]
     #[logfn_inputs(Debug)]
     pub fn new(config: QuorumConfig) -> VdrResult<QuorumHandler> {
-        let clients = config
+        QuorumHandler::with_clients(config, vec![])
+    }
+
+    /// Create quorum handler using custom client implementations in addition to configured nodes
+    ///
+    /// # Params
+    ///  - `config`: [QuorumConfig] - quorum configuration. Web3 HTTP client is created for each node in `nodes`
+    ///  - `clients`: [Vec] - additional client implementations to use for quorum checks
+    ///
+    /// # Returns
+    ///  handler: [QuorumHandler] - handler checking quorum across all nodes and clients
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn with_clients(
+        config: QuorumConfig,
+        clients: Vec<Box<dyn Client>>,
+    ) -> VdrResult<QuorumHandler> {
+        let weights = config.weights.unwrap_or_default();
+        let validator_endpoints = config.validator_nodes.unwrap_or_default();
+        if let Some(node) = weights.keys().find(|node| {
+            !config.nodes.contains(*node) && !validator_endpoints.values().any(|e| e == *node)
+        }) {
+            return Err(VdrError::ClientInvalidState(format!(
+                "Weight is set for node {} which is not listed in quorum nodes",
+                node
+            )));
+        }
+
+        let read_policy = config.read_policy.unwrap_or_default();
+        read_policy.validate()?;
+        let write_policy = config.write_policy.unwrap_or_default();
+        write_policy.validate()?;
+
+        let configured_nodes = config
             .nodes
             .iter()
             .map(|node_address| {
-                let client: Box<dyn Client> = Box::new(Web3Client::new(node_address)?);
-                Ok(Arc::new(client))
+                Ok(QuorumNode {
+                    name: node_address.to_string(),
+                    client: Arc::new(node_client(node_address, config.connection.as_ref())?),
+                    weight: weights
+                        .get(node_address)
+                        .copied()
+                        .unwrap_or(DEFAULT_NODE_WEIGHT),
+                })
             })
             .collect::<Result<Vec<_>, VdrError>>()?;
 
+        let custom_nodes = clients
+            .into_iter()
+            .enumerate()
+            .map(|(index, client)| QuorumNode {
+                name: format!("custom client {}", index),
+                client: Arc::new(client),
+                weight: DEFAULT_NODE_WEIGHT,
+            })
+            .collect::<Vec<_>>();
+
+        // validator nodes are not known until the first sync, so all configured endpoints are counted
+        let validator_weight: u64 = validator_endpoints
+            .values()
+            .filter(|endpoint| !config.nodes.contains(*endpoint))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .map(|endpoint| {
+                weights
+                    .get(endpoint)
+                    .copied()
+                    .unwrap_or(DEFAULT_NODE_WEIGHT)
+            })
+            .sum();
+        let total_weight = configured_nodes
+            .iter()
+            .chain(custom_nodes.iter())
+            .map(|node| node.weight)
+            .sum::<u64>()
+            + validator_weight;
+        read_policy.validate_weight(total_weight)?;
+        write_policy.validate_weight(total_weight)?;
+
+        let validator_nodes = if validator_endpoints.is_empty() {
+            None
+        } else {
+            Some(ValidatorNodes::new(
+                validator_endpoints,
+                weights,
+                config.connection,
+            ))
+        };
+
         let handler = QuorumHandler {
-            clients,
+            nodes: configured_nodes.into_iter().chain(custom_nodes).collect(),
+            validator_nodes,
+            read_policy,
+            write_policy,
             request_retries: config.request_retries.unwrap_or(DEFAULT_REQUEST_RETRIES),
             request_timeout: Duration::from_millis(
                 config.request_timeout.unwrap_or(DEFAULT_REQUEST_TIMEOUT),
@@ -66,85 +491,180 @@ impl QuorumHandler {
 
     #[logfn(Info)]
     #[logfn_inputs(Debug)]
-    #[allow(clippy::too_many_arguments)]
-    async fn send_transaction_with_retries(
-        mut sender: Sender<Vec<u8>>,
-        client: Arc<Box<dyn Client>>,
-        type_: TransactionType,
-        to: String,
-        data: Vec<u8>,
+    async fn send_request_with_retries(
+        node: usize,
+        client: &dyn Client,
+        request: &QuorumRequest,
         request_retries: u8,
         request_timeout: Duration,
         retry_interval: Duration,
-    ) {
+    ) -> NodeResponse {
+        let mut response = None;
         for _ in 1..request_retries {
-            match type_ {
-                TransactionType::Write => {
-                    let future = client.get_transaction(&data);
-                    match async_std::future::timeout(request_timeout, future).await {
-                        Ok(Ok(Some(transaction))) if transaction.hash.is_some() => {
-                            let hash = transaction.hash.unwrap();
-                            if sender.try_send(hash).is_err() {
-                                trace!("Receiver is closed for sender: {:?}", sender);
-                            }
-                            break;
-                        }
-                        _ => {
-                            trace!(
-                                "eth_getTransaction not succeed for transaction_hash: {:?}. retry",
-                                data
-                            );
-                            async_std::task::sleep(retry_interval).await;
-                        }
-                    }
+            let future = request.send(client);
+            match async_std::future::timeout(request_timeout, future).await {
+                Ok(Ok(Some(result))) => {
+                    response = Some(result);
+                    break;
                 }
-                TransactionType::Read => {
-                    let future = client.call_transaction(&to, &data);
-                    match async_std::future::timeout(request_timeout, future).await {
-                        Ok(Ok(transaction)) => {
-                            if sender.try_send(transaction).is_err() {
-                                trace!("Receiver is closed for sender: {:?}", sender);
-                            }
-                            break;
-                        }
-                        _ => {
-                            trace!(
-                                "call_transaction not succeed for transaction_hash: {:?}. retry",
-                                data
-                            );
-                            async_std::task::sleep(retry_interval).await;
-                        }
-                    }
+                _ => {
+                    trace!(
+                        "Quorum request {:?} not succeed for node {}. retry",
+                        request,
+                        node
+                    );
+                    async_std::task::sleep(retry_interval).await;
                 }
-            };
+            }
         }
+
+        (node, response)
+    }
+
+    /// Hash of the event logs list. Logs are identified by their block hash, log index, transaction hash and content
+    fn events_hash(events: &[EventLog]) -> Vec<u8> {
+        let mut hasher = Keccak256::new();
+        for event in events {
+            hasher.update(event.hash());
+        }
+        hasher.finalize().to_vec()
     }
 
     #[logfn(Info)]
     #[logfn_inputs(Debug)]
-    async fn wait_for_quorum(
-        &self,
-        mut receiver: Receiver<Vec<u8>>,
+    async fn wait_for_quorum<F: Future<Output = NodeResponse>>(
+        nodes: &[QuorumNode],
+        mut responses: FuturesUnordered<F>,
         expected_result: &[u8],
-    ) -> bool {
-        let approvals_needed = self.clients.len() / 3 + 1;
+        approvals_needed: u64,
+    ) -> QuorumReport {
+        let mut report = QuorumReport::default();
         let mut approvals_counter = 0;
-        let mut quorum_reached = false;
 
-        while let Some(result) = receiver.next().await {
-            if result == expected_result {
-                approvals_counter += 1;
+        while let Some((node, result)) = responses.next().await {
+            let node = &nodes[node];
+            match result {
+                Some(result) if result == expected_result => {
+                    approvals_counter += node.weight;
+                    report.agreed.push(node.name.to_string());
 
-                quorum_reached = approvals_counter >= approvals_needed;
-                if quorum_reached {
-                    break;
+                    report.reached = approvals_counter >= approvals_needed;
+                    if report.reached {
+                        break;
+                    }
                 }
+                Some(_) => report.disagreed.push(node.name.to_string()),
+                None => report.failed.push(node.name.to_string()),
             }
         }
 
-        quorum_reached
+        report
+    }
+
+    /// Configured nodes and custom clients followed by the nodes of the current validators
+    ///
+    /// Validator nodes already listed in the configured nodes are skipped, so that each node is counted once
+    async fn current_nodes(&self) -> Vec<QuorumNode> {
+        let mut nodes = self.nodes.clone();
+        if let Some(validator_nodes) = &self.validator_nodes {
+            let state = validator_nodes.state.lock().await;
+            nodes.extend(
+                state
+                    .nodes
+                    .iter()
+                    .filter(|node| !self.nodes.iter().any(|n| n.name == node.name))
+                    .cloned(),
+            );
+        }
+        nodes
+    }
+
+    /// Refresh quorum nodes derived from the validator list. Does nothing if `validator_nodes` are not configured
+    ///
+    /// If the refresh fails after a successful sync, the error is logged and the last synced list is kept. The error
+    /// is returned only if the validator list has never been synced.
+    ///
+    /// # Params
+    ///  - `ledger`: [LedgerClient] - client used to build `ValidatorControl` requests
+    ///  - `client`: [Client] - primary node client used to query the validator list and events
+    pub(crate) async fn sync_validator_nodes(
+        &self,
+        ledger: &LedgerClient,
+        client: &dyn Client,
+    ) -> VdrResult<()> {
+        let validator_nodes = match &self.validator_nodes {
+            Some(validator_nodes) => validator_nodes,
+            None => return Ok(()),
+        };
+
+        match validator_nodes.sync(ledger, client).await {
+            Ok(()) => {
+                let total_weight = self
+                    .current_nodes()
+                    .await
+                    .iter()
+                    .map(|node| node.weight)
+                    .sum();
+                self.read_policy.validate_weight(total_weight)?;
+                self.write_policy.validate_weight(total_weight)
+            }
+            Err(err) if validator_nodes.synced().await => {
+                warn!(
+                    "Error: {} during syncing validator nodes. The last synced list is used",
+                    err
+                );
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Send the request to all quorum nodes and compare their results with the expected one
+    ///
+    /// Requests are polled concurrently within the calling task, so the same dispatch works on native and wasm
+    /// runtimes. Requests still in flight are dropped as soon as the quorum is reached.
+    async fn check_request(
+        &self,
+        request: QuorumRequest,
+        policy: &QuorumPolicy,
+        expected_result: &[u8],
+    ) -> QuorumReport {
+        let nodes = self.current_nodes().await;
+
+        let responses: FuturesUnordered<_> = nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| {
+                QuorumHandler::send_request_with_retries(
+                    index,
+                    node.client.as_ref().as_ref(),
+                    &request,
+                    self.request_retries,
+                    self.request_timeout,
+                    self.retry_interval,
+                )
+            })
+            .collect();
+
+        let total_weight = nodes.iter().map(|node| node.weight).sum();
+        QuorumHandler::wait_for_quorum(
+            &nodes,
+            responses,
+            expected_result,
+            policy.approvals_needed(total_weight),
+        )
+        .await
     }
 
+    /// Check that the quorum of nodes returns the same result of the read transaction
+    ///
+    /// # Params
+    ///  - `transaction`: [Transaction] - executed read transaction. Write transactions are checked with
+    ///     [QuorumHandler::check_receipt]
+    ///  - `expected_result`: [Vec] - transaction output received from the primary node
+    ///
+    /// # Returns
+    ///  quorum_reached: [bool] - whether the quorum is reached
     #[logfn(Info)]
     #[logfn_inputs(Debug)]
     pub async fn check(
@@ -152,60 +672,91 @@ impl QuorumHandler {
         transaction: &Transaction,
         expected_result: &[u8],
     ) -> VdrResult<bool> {
-        let clients_count = self.clients.len();
-        let (sender, receiver) = mpsc::channel::<Vec<u8>>(clients_count);
-
-        for client in self.clients.iter() {
-            let type_ = transaction.type_.clone();
-            let to = transaction.to.clone();
-            let transaction_data = match transaction.type_ {
-                TransactionType::Write => expected_result.to_vec(),
-                TransactionType::Read => transaction.data.to_vec(),
-            };
+        if transaction.type_ == TransactionType::Write {
+            let vdr_error = VdrError::ClientInvalidTransaction(
+                "Write transactions must be checked against their receipts".to_string(),
+            );
 
-            #[cfg(feature = "wasm")]
-            {
-                let sender = sender.clone();
-                let client = client.clone();
-                let request_retries = self.request_retries;
-                let request_timeout = self.request_timeout;
-                let retry_interval = self.retry_interval;
-                async_std::task::block_on(QuorumHandler::send_transaction_with_retries(
-                    sender,
-                    client,
-                    type_,
-                    to.to_string(),
-                    transaction_data,
-                    request_retries,
-                    request_timeout,
-                    retry_interval,
-                ));
-            }
+            warn!("Error: {} during quorum check", vdr_error);
 
-            #[cfg(not(feature = "wasm"))]
-            {
-                async_std::task::spawn(QuorumHandler::send_transaction_with_retries(
-                    sender.clone(),
-                    client.clone(),
-                    type_,
-                    to.to_string(),
-                    transaction_data,
-                    self.request_retries,
-                    self.request_timeout,
-                    self.retry_interval,
-                ));
-            }
+            return Err(vdr_error);
         }
 
-        drop(sender);
+        let request = QuorumRequest::CallTransaction {
+            to: transaction.to.to_string(),
+            data: transaction.data.to_vec(),
+            block: transaction.block_tag.clone(),
+        };
 
-        let quorum_reached = self.wait_for_quorum(receiver, expected_result).await;
-        if quorum_reached {
-            Ok(quorum_reached)
+        let report = self
+            .check_request(request, &self.read_policy, expected_result)
+            .await;
+        if report.reached {
+            Ok(true)
         } else {
             Err(VdrError::QuorumNotReached(format!(
-                "Quorum not reached for transaction: {:?}",
-                transaction
+                "Quorum not reached for transaction: {:?}. Nodes {}",
+                transaction, report
+            )))
+        }
+    }
+
+    /// Check that the quorum of nodes has included the write transaction into the same block with the same outcome
+    ///
+    /// Receipts are compared by their block hash, execution status, used gas and emitted logs. Nodes which have
+    /// only seen the transaction in their mempool have no receipt and do not confirm the transaction.
+    ///
+    /// # Params
+    ///  - `receipt`: [TransactionReceipt] - receipt of the transaction received from the primary node
+    ///
+    /// # Returns
+    ///  quorum_reached: [bool] - whether the quorum is reached
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn check_receipt(&self, receipt: &TransactionReceipt) -> VdrResult<bool> {
+        let request = QuorumRequest::GetReceipt {
+            hash: receipt.transaction_hash.to_vec(),
+        };
+
+        let report = self
+            .check_request(request, &self.write_policy, &receipt.hash())
+            .await;
+        if report.reached {
+            Ok(true)
+        } else {
+            Err(VdrError::QuorumNotReached(format!(
+                "Quorum not reached for transaction receipt: {:?}. Nodes {}",
+                receipt, report
+            )))
+        }
+    }
+
+    /// Check that the quorum of nodes returns the same event logs for the query
+    ///
+    /// # Params
+    ///  - `query`: [EventQuery] - events query. Block range must be fixed (`to_block` set),
+    ///     so that the nodes return logs of the same blocks
+    ///  - `events`: [Vec] - event logs received from the primary node
+    ///
+    /// # Returns
+    ///  quorum_reached: [bool] - whether the quorum is reached
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn check_events(&self, query: &EventQuery, events: &[EventLog]) -> VdrResult<bool> {
+        let request = QuorumRequest::QueryEvents {
+            query: query.clone(),
+        };
+        let expected_result = QuorumHandler::events_hash(events);
+
+        let report = self
+            .check_request(request, &self.read_policy, &expected_result)
+            .await;
+        if report.reached {
+            Ok(true)
+        } else {
+            Err(VdrError::QuorumNotReached(format!(
+                "Quorum not reached for events query: {:?}. Nodes {}",
+                query, report
             )))
         }
     }
@@ -243,6 +794,11 @@ pub mod test {
                 request_retries: Some(DEFAULT_REQUEST_RETRIES),
                 request_timeout: Some(DEFAULT_REQUEST_TIMEOUT),
                 retry_interval: Some(DEFAULT_RETRY_INTERVAL),
+                read_policy: None,
+                write_policy: None,
+                weights: None,
+                validator_nodes: None,
+                connection: None,
             }
         }
     }
@@ -250,7 +806,10 @@ pub mod test {
     impl Default for QuorumHandler {
         fn default() -> Self {
             QuorumHandler {
-                clients: vec![],
+                nodes: vec![],
+                validator_nodes: None,
+                read_policy: QuorumPolicy::default(),
+                write_policy: QuorumPolicy::default(),
                 request_retries: DEFAULT_REQUEST_RETRIES,
                 request_timeout: Duration::from_millis(DEFAULT_REQUEST_TIMEOUT),
                 retry_interval: Duration::from_millis(DEFAULT_RETRY_INTERVAL),
@@ -258,136 +817,202 @@ pub mod test {
         }
     }
 
+    fn nodes(clients: Vec<Arc<Box<dyn Client>>>) -> Vec<QuorumNode> {
+        clients
+            .into_iter()
+            .enumerate()
+            .map(|(index, client)| QuorumNode {
+                name: format!("node {}", index),
+                client,
+                weight: DEFAULT_NODE_WEIGHT,
+            })
+            .collect()
+    }
+
     const TIMEOUT_TIME: u64 = 1000;
     const RETRIES: u8 = 5;
+    const RETRY_INTERVAL: u64 = 10;
 
     #[cfg(test)]
     mod write_quorum_test {
         use super::*;
+        use crate::ReceiptLog;
+        use ethabi::Hash;
 
         static TXN_HASH: Lazy<Vec<u8>> = Lazy::new(|| vec![1; 32]);
 
         static WRITE_TRANSACTION: Lazy<Transaction> = Lazy::new(|| Transaction {
             type_: TransactionType::Write,
-            hash: Some(TXN_HASH.clone()),
             ..Transaction::default()
         });
 
-        fn mock_client(
-            txn_hash: &[u8],
-            expected_output: Option<Transaction>,
-        ) -> Arc<Box<dyn Client>> {
+        static RECEIPT: Lazy<TransactionReceipt> = Lazy::new(|| TransactionReceipt {
+            transaction_hash: TXN_HASH.clone(),
+            block_hash: vec![2; 32],
+            block_number: 1,
+            success: true,
+            gas_used: 21000,
+            logs: vec![ReceiptLog {
+                address: Address::from("0x0000000000000000000000000000000000003333"),
+                topics: vec![Hash::repeat_byte(3)],
+                data: vec![4; 32],
+            }],
+            revert_reason: None,
+        });
+
+        fn missing_receipt() -> VdrResult<TransactionReceipt> {
+            Err(VdrError::ClientInvalidResponse(
+                "Missing transaction receipt".to_string(),
+            ))
+        }
+
+        fn mock_client(expected_output: VdrResult<TransactionReceipt>) -> Arc<Box<dyn Client>> {
             let mut mock_client = MockClient::new();
             mock_client
-                .expect_get_transaction()
-                .with(eq(txn_hash.to_vec()))
-                .returning(move |_| Ok(expected_output.clone()));
+                .expect_get_receipt()
+                .with(eq(TXN_HASH.clone()))
+                .returning(move |_| expected_output.clone());
 
             Arc::new(Box::new(mock_client))
         }
 
         fn mock_client_sleep_before_return(
-            txn_hash: &[u8],
-            expected_output: Option<Transaction>,
+            expected_output: VdrResult<TransactionReceipt>,
             sleep_time_sec: u64,
         ) -> Arc<Box<dyn Client>> {
             let mut mock_client = MockClient::new();
             mock_client
-                .expect_get_transaction()
-                .with(eq(txn_hash.to_vec()))
+                .expect_get_receipt()
+                .with(eq(TXN_HASH.clone()))
                 .returning(move |_| {
                     thread::sleep(time::Duration::from_millis(sleep_time_sec.into()));
-                    Ok(expected_output.clone())
+                    expected_output.clone()
                 });
 
             Arc::new(Box::new(mock_client))
         }
 
         fn mock_client_retries(
-            txn_hash: &[u8],
-            expected_output: Option<Transaction>,
+            expected_output: VdrResult<TransactionReceipt>,
             retries_num: u8,
         ) -> Arc<Box<dyn Client>> {
             let mut mock_client = MockClient::new();
             mock_client
-                .expect_get_transaction()
-                .with(eq(txn_hash.to_vec()))
+                .expect_get_receipt()
+                .with(eq(TXN_HASH.clone()))
                 .times(retries_num as usize - 1)
-                .returning(move |_| Ok(None));
+                .returning(move |_| missing_receipt());
 
             mock_client
-                .expect_get_transaction()
-                .with(eq(txn_hash.to_vec()))
-                .returning(move |_| Ok(expected_output.clone()));
+                .expect_get_receipt()
+                .with(eq(TXN_HASH.clone()))
+                .returning(move |_| expected_output.clone());
 
             Arc::new(Box::new(mock_client))
         }
 
         #[async_std::test]
         async fn test_quorum_check_positive_case() {
-            let client1 = mock_client(&TXN_HASH, Some(WRITE_TRANSACTION.clone()));
-            let client2 = mock_client(&TXN_HASH, Some(WRITE_TRANSACTION.clone()));
+            let client1 = mock_client(Ok(RECEIPT.clone()));
+            let client2 = mock_client(Ok(RECEIPT.clone()));
             let quorum = QuorumHandler {
-                clients: vec![client1, client2],
+                nodes: nodes(vec![client1, client2]),
                 ..QuorumHandler::default()
             };
-            assert!(quorum.check(&WRITE_TRANSACTION, &TXN_HASH).await.unwrap());
+            assert!(quorum.check_receipt(&RECEIPT).await.unwrap());
         }
 
         #[async_std::test]
         async fn test_quorum_check_failed_with_timeout() {
-            let client1 = mock_client(&TXN_HASH, None);
-            let client2 = mock_client_sleep_before_return(&TXN_HASH, None, TIMEOUT_TIME + 3000);
+            let client1 = mock_client(missing_receipt());
+            let client2 = mock_client_sleep_before_return(missing_receipt(), TIMEOUT_TIME + 3000);
             let quorum = QuorumHandler {
-                clients: vec![client1, client2],
+                nodes: nodes(vec![client1, client2]),
                 request_timeout: Duration::from_millis(TIMEOUT_TIME),
                 ..QuorumHandler::default()
             };
-            let _err = quorum
-                .check(&WRITE_TRANSACTION, &TXN_HASH)
-                .await
-                .unwrap_err();
+            let _err = quorum.check_receipt(&RECEIPT).await.unwrap_err();
         }
 
         #[async_std::test]
         async fn test_quorum_check_not_reached() {
-            let client1: Arc<Box<dyn Client>> =
-                mock_client(&TXN_HASH, Some(WRITE_TRANSACTION.clone()));
-            let client2 = mock_client(
-                &TXN_HASH,
-                Some(Transaction {
-                    hash: Some([2; 32].to_vec()),
-                    ..Default::default()
-                }),
-            );
-            let client3 = mock_client(
-                &TXN_HASH,
-                Some(Transaction {
-                    hash: Some([3; 32].to_vec()),
-                    ..Default::default()
-                }),
-            );
+            let client1 = mock_client(Ok(RECEIPT.clone()));
+            let client2 = mock_client(Ok(TransactionReceipt {
+                block_hash: vec![5; 32],
+                ..RECEIPT.clone()
+            }));
+            let client3 = mock_client(Ok(TransactionReceipt {
+                success: false,
+                ..RECEIPT.clone()
+            }));
             let quorum = QuorumHandler {
-                clients: vec![client1, client2, client3],
+                nodes: nodes(vec![client1, client2, client3]),
                 ..QuorumHandler::default()
             };
 
-            let _err = quorum
-                .check(&WRITE_TRANSACTION, &TXN_HASH)
-                .await
-                .unwrap_err();
+            let _err = quorum.check_receipt(&RECEIPT).await.unwrap_err();
         }
 
         #[async_std::test]
-        async fn test_quorum_check_got_transaction_after_retries() {
-            let client1 = mock_client_retries(&TXN_HASH, Some(WRITE_TRANSACTION.clone()), RETRIES);
-            let client2 = mock_client(&TXN_HASH, Some(WRITE_TRANSACTION.clone()));
+        async fn test_quorum_check_different_logs() {
+            let receipt = TransactionReceipt {
+                logs: vec![],
+                ..RECEIPT.clone()
+            };
+            let client1 = mock_client(Ok(receipt.clone()));
+            let client2 = mock_client(Ok(receipt));
             let quorum = QuorumHandler {
-                clients: vec![client1, client2],
+                nodes: nodes(vec![client1, client2]),
+                ..QuorumHandler::default()
+            };
+
+            let err = quorum.check_receipt(&RECEIPT).await.unwrap_err();
+
+            assert!(matches!(err, VdrError::QuorumNotReached { .. }));
+        }
+
+        #[async_std::test]
+        async fn test_quorum_check_transaction_not_included() {
+            let client1 = mock_client(missing_receipt());
+            let client2 = mock_client(missing_receipt());
+            let quorum = QuorumHandler {
+                nodes: nodes(vec![client1, client2]),
+                retry_interval: Duration::from_millis(10),
+                ..QuorumHandler::default()
+            };
+
+            let err = quorum.check_receipt(&RECEIPT).await.unwrap_err();
+
+            assert!(matches!(err, VdrError::QuorumNotReached { .. }));
+        }
+
+        #[async_std::test]
+        async fn test_quorum_check_got_receipt_after_retries() {
+            let client1 = mock_client_retries(Ok(RECEIPT.clone()), RETRIES - 1);
+            let client2 = mock_client(Ok(RECEIPT.clone()));
+            let quorum = QuorumHandler {
+                nodes: nodes(vec![client1, client2]),
+                write_policy: QuorumPolicy::All,
                 request_retries: RETRIES,
+                retry_interval: Duration::from_millis(RETRY_INTERVAL),
                 ..QuorumHandler::default()
             };
-            assert!(quorum.check(&WRITE_TRANSACTION, &TXN_HASH).await.unwrap());
+            assert!(quorum.check_receipt(&RECEIPT).await.unwrap());
+        }
+
+        #[async_std::test]
+        async fn test_quorum_check_write_transaction_result() {
+            let quorum = QuorumHandler {
+                nodes: nodes(vec![mock_client(Ok(RECEIPT.clone()))]),
+                ..QuorumHandler::default()
+            };
+
+            let err = quorum
+                .check(&WRITE_TRANSACTION, &TXN_HASH)
+                .await
+                .unwrap_err();
+
+            assert!(matches!(err, VdrError::ClientInvalidTransaction { .. }));
         }
     }
 
@@ -412,8 +1037,9 @@ pub mod test {
                 .with(
                     eq(transaction.to.to_string()),
                     eq(transaction.data.to_vec()),
+                    eq(transaction.block_tag.clone()),
                 )
-                .returning(move |_, _| expected_output.clone());
+                .returning(move |_, _, _| expected_output.clone());
 
             Arc::new(Box::new(mock_client))
         }
@@ -429,8 +1055,9 @@ pub mod test {
                 .with(
                     eq(transaction.to.to_string()),
                     eq(transaction.data.to_vec()),
+                    eq(transaction.block_tag.clone()),
                 )
-                .returning(move |_, _| {
+                .returning(move |_, _, _| {
                     thread::sleep(time::Duration::from_millis(sleep_time_sec.into()));
                     expected_output.clone()
                 });
@@ -450,17 +1077,21 @@ pub mod test {
                 .with(
                     eq(transaction.to.to_string()),
                     eq(transaction.data.to_vec()),
+                    eq(transaction.block_tag.clone()),
                 )
                 .times(retries_num as usize - 1)
-                .returning(move |_, _| Err(VdrError::ContractInvalidResponseData("".to_string())));
+                .returning(move |_, _, _| {
+                    Err(VdrError::ContractInvalidResponseData("".to_string()))
+                });
 
             mock_client
                 .expect_call_transaction()
                 .with(
                     eq(transaction.to.to_string()),
                     eq(transaction.data.to_vec()),
+                    eq(transaction.block_tag.clone()),
                 )
-                .returning(move |_, _| expected_output.clone());
+                .returning(move |_, _, _| expected_output.clone());
 
             Arc::new(Box::new(mock_client))
         }
@@ -470,7 +1101,7 @@ pub mod test {
             let client1 = mock_client(READ_TRANSACTION.clone(), Ok(RESPONSE.clone()));
             let client2 = mock_client(READ_TRANSACTION.clone(), Ok(RESPONSE.clone()));
             let quorum = QuorumHandler {
-                clients: vec![client1, client2],
+                nodes: nodes(vec![client1, client2]),
                 ..QuorumHandler::default()
             };
             assert!(quorum.check(&READ_TRANSACTION, &RESPONSE).await.unwrap());
@@ -488,7 +1119,7 @@ pub mod test {
                 TIMEOUT_TIME + 3000,
             );
             let quorum = QuorumHandler {
-                clients: vec![client1, client2],
+                nodes: nodes(vec![client1, client2]),
                 request_timeout: Duration::from_millis(TIMEOUT_TIME),
                 ..QuorumHandler::default()
             };
@@ -505,7 +1136,7 @@ pub mod test {
             let client2 = mock_client(READ_TRANSACTION.clone(), Ok(vec![1, 1, 1, 2]));
             let client3 = mock_client(READ_TRANSACTION.clone(), Ok(vec![1, 1, 1, 3]));
             let quorum = QuorumHandler {
-                clients: vec![client1, client2, client3],
+                nodes: nodes(vec![client1, client2, client3]),
                 ..QuorumHandler::default()
             };
             let _err = quorum
@@ -517,14 +1148,705 @@ pub mod test {
         #[async_std::test]
         async fn test_quorum_check_got_transaction_after_retries() {
             let client1 =
-                mock_client_retries(READ_TRANSACTION.clone(), Ok(RESPONSE.clone()), RETRIES);
+                mock_client_retries(READ_TRANSACTION.clone(), Ok(RESPONSE.clone()), RETRIES - 1);
             let client2 = mock_client(READ_TRANSACTION.clone(), Ok(RESPONSE.clone()));
             let quorum = QuorumHandler {
-                clients: vec![client1, client2],
+                nodes: nodes(vec![client1, client2]),
+                read_policy: QuorumPolicy::All,
                 request_retries: RETRIES,
+                retry_interval: Duration::from_millis(RETRY_INTERVAL),
                 ..QuorumHandler::default()
             };
             assert!(quorum.check(&READ_TRANSACTION, &RESPONSE).await.unwrap());
         }
     }
+
+    #[cfg(test)]
+    mod concurrency_test {
+        use super::*;
+        use crate::{BlockDetails, TransactionStatus};
+        use async_trait::async_trait;
+        use std::{
+            sync::atomic::{AtomicUsize, Ordering},
+            time::Instant,
+        };
+
+        const DELAY: u64 = 300;
+        const SLOW_DELAY: u64 = 5000;
+
+        static RESPONSE: Lazy<Vec<u8>> = Lazy::new(|| vec![1, 1, 1, 1]);
+
+        /// Client answering read transactions after a delay without blocking the thread
+        #[derive(Debug, Default)]
+        struct DelayedClient {
+            delay: u64,
+            completed: Arc<AtomicUsize>,
+        }
+
+        #[async_trait]
+        impl Client for DelayedClient {
+            async fn get_transaction_count(&self, _address: &Address) -> VdrResult<u64> {
+                unimplemented!()
+            }
+
+            async fn send_transaction(&self, _transaction: &[u8]) -> VdrResult<Vec<u8>> {
+                unimplemented!()
+            }
+
+            async fn get_transaction_status(&self, _hash: &[u8]) -> VdrResult<TransactionStatus> {
+                unimplemented!()
+            }
+
+            async fn call_transaction(
+                &self,
+                _to: &str,
+                _transaction: &[u8],
+                _block: Option<BlockTag>,
+            ) -> VdrResult<Vec<u8>> {
+                async_std::task::sleep(Duration::from_millis(self.delay)).await;
+                self.completed.fetch_add(1, Ordering::SeqCst);
+                Ok(RESPONSE.clone())
+            }
+
+            async fn query_events(&self, _query: &EventQuery) -> VdrResult<Vec<EventLog>> {
+                unimplemented!()
+            }
+
+            async fn get_receipt(&self, _hash: &[u8]) -> VdrResult<TransactionReceipt> {
+                unimplemented!()
+            }
+
+            async fn get_block(&self, _block: Option<u64>) -> VdrResult<BlockDetails> {
+                unimplemented!()
+            }
+
+            async fn get_transaction(&self, _hash: &[u8]) -> VdrResult<Option<Transaction>> {
+                unimplemented!()
+            }
+        }
+
+        fn delayed_client(delay: u64, completed: &Arc<AtomicUsize>) -> Arc<Box<dyn Client>> {
+            Arc::new(Box::new(DelayedClient {
+                delay,
+                completed: completed.clone(),
+            }))
+        }
+
+        #[async_std::test]
+        async fn test_quorum_check_requests_nodes_concurrently() {
+            let completed = Arc::new(AtomicUsize::new(0));
+            let quorum = QuorumHandler {
+                nodes: nodes(vec![
+                    delayed_client(DELAY, &completed),
+                    delayed_client(DELAY, &completed),
+                    delayed_client(DELAY, &completed),
+                ]),
+                read_policy: QuorumPolicy::All,
+                ..QuorumHandler::default()
+            };
+
+            let start = Instant::now();
+            assert!(quorum
+                .check(&Transaction::default(), &RESPONSE)
+                .await
+                .unwrap());
+
+            // latency of the slowest node rather than the sum of the node delays
+            assert!(start.elapsed() < Duration::from_millis(2 * DELAY));
+            assert_eq!(3, completed.load(Ordering::SeqCst));
+        }
+
+        #[async_std::test]
+        async fn test_quorum_check_drops_requests_after_quorum() {
+            let completed = Arc::new(AtomicUsize::new(0));
+            let slow_completed = Arc::new(AtomicUsize::new(0));
+            let quorum = QuorumHandler {
+                nodes: nodes(vec![
+                    delayed_client(DELAY, &completed),
+                    delayed_client(DELAY, &completed),
+                    delayed_client(SLOW_DELAY, &slow_completed),
+                ]),
+                ..QuorumHandler::default()
+            };
+
+            let start = Instant::now();
+            assert!(quorum
+                .check(&Transaction::default(), &RESPONSE)
+                .await
+                .unwrap());
+
+            assert!(start.elapsed() < Duration::from_millis(2 * DELAY));
+            async_std::task::sleep(Duration::from_millis(DELAY)).await;
+            assert_eq!(2, completed.load(Ordering::SeqCst));
+            assert_eq!(0, slow_completed.load(Ordering::SeqCst));
+        }
+
+        #[async_std::test]
+        async fn test_quorum_check_node_timeout() {
+            let completed = Arc::new(AtomicUsize::new(0));
+            let slow_completed = Arc::new(AtomicUsize::new(0));
+            let quorum = QuorumHandler {
+                nodes: nodes(vec![
+                    delayed_client(DELAY, &completed),
+                    delayed_client(SLOW_DELAY, &slow_completed),
+                ]),
+                read_policy: QuorumPolicy::All,
+                request_retries: 2,
+                request_timeout: Duration::from_millis(2 * DELAY),
+                retry_interval: Duration::from_millis(RETRY_INTERVAL),
+                ..QuorumHandler::default()
+            };
+
+            let start = Instant::now();
+            let err = quorum
+                .check(&Transaction::default(), &RESPONSE)
+                .await
+                .unwrap_err();
+
+            assert!(matches!(err, VdrError::QuorumNotReached { .. }));
+            assert!(start.elapsed() < Duration::from_millis(4 * DELAY));
+            assert_eq!(1, completed.load(Ordering::SeqCst));
+            assert_eq!(0, slow_completed.load(Ordering::SeqCst));
+        }
+    }
+
+    #[cfg(test)]
+    mod events_quorum_test {
+        use super::*;
+        use crate::{types::Block, Address};
+        use ethabi::Hash;
+
+        static QUERY: Lazy<EventQuery> = Lazy::new(|| EventQuery {
+            from_block: Some(Block::from(1)),
+            to_block: Some(Block::from(10)),
+            ..EventQuery::new(&Address::null())
+        });
+
+        fn event(block_hash: u8) -> EventLog {
+            EventLog {
+                block_hash: Some(Hash::repeat_byte(block_hash)),
+                log_index: Some(0),
+                transaction_hash: Some(Hash::repeat_byte(1)),
+                ..EventLog::new(vec![vec![1; 32]], vec![1; 32], 5)
+            }
+        }
+
+        fn mock_client(expected_output: VdrResult<Vec<EventLog>>) -> Arc<Box<dyn Client>> {
+            let mut mock_client = MockClient::new();
+            mock_client
+                .expect_query_events()
+                .with(eq(QUERY.clone()))
+                .returning(move |_| expected_output.clone());
+
+            Arc::new(Box::new(mock_client))
+        }
+
+        #[async_std::test]
+        async fn test_quorum_check_positive_case() {
+            let client1 = mock_client(Ok(vec![event(1)]));
+            let client2 = mock_client(Ok(vec![event(1)]));
+            let quorum = QuorumHandler {
+                nodes: nodes(vec![client1, client2]),
+                ..QuorumHandler::default()
+            };
+            assert!(quorum.check_events(&QUERY, &[event(1)]).await.unwrap());
+        }
+
+        #[async_std::test]
+        async fn test_quorum_check_hidden_event() {
+            let client1 = mock_client(Ok(vec![]));
+            let client2 = mock_client(Ok(vec![]));
+            let client3 = mock_client(Ok(vec![]));
+            let quorum = QuorumHandler {
+                nodes: nodes(vec![client1, client2, client3]),
+                ..QuorumHandler::default()
+            };
+
+            let err = quorum.check_events(&QUERY, &[event(1)]).await.unwrap_err();
+
+            assert!(matches!(err, VdrError::QuorumNotReached { .. }));
+        }
+
+        #[async_std::test]
+        async fn test_quorum_check_different_block_hash() {
+            let client1 = mock_client(Ok(vec![event(2)]));
+            let client2 = mock_client(Ok(vec![event(3)]));
+            let client3 = mock_client(Err(VdrError::ClientNodeUnreachable));
+            let quorum = QuorumHandler {
+                nodes: nodes(vec![client1, client2, client3]),
+                retry_interval: Duration::from_millis(10),
+                ..QuorumHandler::default()
+            };
+
+            let err = quorum.check_events(&QUERY, &[event(1)]).await.unwrap_err();
+
+            assert!(matches!(err, VdrError::QuorumNotReached { .. }));
+        }
+    }
+
+    #[cfg(test)]
+    mod quorum_policy_test {
+        use super::*;
+        use rstest::rstest;
+
+        static READ_TRANSACTION: Lazy<Transaction> = Lazy::new(|| Transaction {
+            type_: TransactionType::Read,
+            ..Transaction::default()
+        });
+
+        static RESPONSE: Lazy<Vec<u8>> = Lazy::new(|| vec![1; 32]);
+
+        fn receipt(block_hash: Vec<u8>) -> TransactionReceipt {
+            TransactionReceipt {
+                transaction_hash: vec![9; 32],
+                block_hash,
+                success: true,
+                ..TransactionReceipt::default()
+            }
+        }
+
+        fn mock_client(expected_output: Vec<u8>) -> Arc<Box<dyn Client>> {
+            let mut mock_client = MockClient::new();
+            let read_output = expected_output.clone();
+            mock_client
+                .expect_call_transaction()
+                .returning(move |_, _, _| Ok(read_output.clone()));
+            mock_client
+                .expect_get_receipt()
+                .returning(move |_| Ok(receipt(expected_output.clone())));
+
+            Arc::new(Box::new(mock_client))
+        }
+
+        fn quorum(policy: QuorumPolicy, clients: Vec<Arc<Box<dyn Client>>>) -> QuorumHandler {
+            QuorumHandler {
+                nodes: nodes(clients),
+                read_policy: policy.clone(),
+                write_policy: policy,
+                ..QuorumHandler::default()
+            }
+        }
+
+        #[rstest]
+        #[case::minimal(QuorumPolicy::Minimal, 4, 2)]
+        #[case::count(QuorumPolicy::Count(3), 4, 3)]
+        #[case::fraction(QuorumPolicy::Fraction(0.5), 5, 3)]
+        #[case::bft(QuorumPolicy::Bft, 4, 3)]
+        #[case::bft_seven_nodes(QuorumPolicy::Bft, 7, 5)]
+        #[case::all(QuorumPolicy::All, 4, 4)]
+        #[case::no_nodes(QuorumPolicy::All, 0, 1)]
+        fn approvals_needed(
+            #[case] policy: QuorumPolicy,
+            #[case] total_weight: u64,
+            #[case] expected_approvals: u64,
+        ) {
+            assert_eq!(expected_approvals, policy.approvals_needed(total_weight));
+        }
+
+        #[rstest]
+        #[case::zero_count(QuorumPolicy::Count(0))]
+        #[case::zero_fraction(QuorumPolicy::Fraction(0.0))]
+        #[case::fraction_above_one(QuorumPolicy::Fraction(1.5))]
+        fn invalid_policy(#[case] policy: QuorumPolicy) {
+            let config = QuorumConfig {
+                nodes: vec![],
+                write_policy: Some(policy),
+                ..QuorumConfig::default()
+            };
+
+            let err = QuorumHandler::new(config).unwrap_err();
+
+            assert!(matches!(err, VdrError::ClientInvalidState { .. }));
+        }
+
+        #[test]
+        fn count_exceeds_total_weight() {
+            let config = QuorumConfig {
+                nodes: vec![
+                    CLIENT_NODE_ADDRESSES[0].to_string(),
+                    CLIENT_NODE_ADDRESSES[1].to_string(),
+                ],
+                weights: Some(HashMap::from([(CLIENT_NODE_ADDRESSES[0].to_string(), 2)])),
+                read_policy: Some(QuorumPolicy::Count(4)),
+                ..QuorumConfig::default()
+            };
+
+            let err = QuorumHandler::new(config).unwrap_err();
+
+            assert!(matches!(err, VdrError::ClientInvalidState { .. }));
+            assert!(err
+                .to_string()
+                .contains("count 4 exceeds the total weight 3"));
+        }
+
+        #[test]
+        fn weight_of_unknown_node() {
+            let config = QuorumConfig {
+                nodes: vec![],
+                weights: Some(HashMap::from([(CLIENT_NODE_ADDRESSES[0].to_string(), 2)])),
+                ..QuorumConfig::default()
+            };
+
+            let err = QuorumHandler::new(config).unwrap_err();
+
+            assert!(matches!(err, VdrError::ClientInvalidState { .. }));
+        }
+
+        #[async_std::test]
+        async fn stricter_write_policy() {
+            let quorum = QuorumHandler {
+                write_policy: QuorumPolicy::All,
+                ..quorum(
+                    QuorumPolicy::Minimal,
+                    vec![
+                        mock_client(RESPONSE.clone()),
+                        mock_client(RESPONSE.clone()),
+                        mock_client(vec![2; 32]),
+                    ],
+                )
+            };
+
+            assert!(quorum.check(&READ_TRANSACTION, &RESPONSE).await.unwrap());
+            let err = quorum
+                .check_receipt(&receipt(RESPONSE.clone()))
+                .await
+                .unwrap_err();
+            assert!(matches!(err, VdrError::QuorumNotReached { .. }));
+        }
+
+        #[async_std::test]
+        async fn weighted_quorum() {
+            let mut quorum = quorum(
+                QuorumPolicy::Fraction(0.5),
+                vec![
+                    mock_client(RESPONSE.clone()),
+                    mock_client(vec![2; 32]),
+                    mock_client(vec![2; 32]),
+                ],
+            );
+            quorum.nodes[0].weight = 3;
+
+            assert!(quorum.check(&READ_TRANSACTION, &RESPONSE).await.unwrap());
+        }
+
+        #[async_std::test]
+        async fn report_nodes_outcome() {
+            let mut failed_client = MockClient::new();
+            failed_client
+                .expect_call_transaction()
+                .returning(|_, _, _| Err(VdrError::ClientNodeUnreachable));
+            let quorum = QuorumHandler {
+                retry_interval: Duration::from_millis(10),
+                ..quorum(
+                    QuorumPolicy::All,
+                    vec![
+                        mock_client(RESPONSE.clone()),
+                        mock_client(vec![2; 32]),
+                        Arc::new(Box::new(failed_client)),
+                    ],
+                )
+            };
+
+            let err = quorum
+                .check(&READ_TRANSACTION, &RESPONSE)
+                .await
+                .unwrap_err();
+
+            assert!(matches!(err, VdrError::QuorumNotReached { .. }));
+            assert!(err.to_string().ends_with(
+                "Nodes agreed: [\"node 0\"], disagreed: [\"node 1\"], failed or timed out: [\"node 2\"]"
+            ));
+        }
+    }
+
+    mod validator_nodes_test {
+        use super::*;
+        use crate::{
+            client::client::test::mock_client as ledger_client,
+            contracts::validator_control::test::VALIDATOR_LIST_BYTES, BlockDetails,
+        };
+        use mockall::{predicate::always, Sequence};
+
+        fn expect_block(client: &mut MockClient, sequence: &mut Sequence, number: u64) {
+            client
+                .expect_get_block()
+                .times(1)
+                .in_sequence(sequence)
+                .returning(move |_| {
+                    Ok(BlockDetails {
+                        number,
+                        timestamp: 0,
+                    })
+                });
+        }
+
+        fn expect_validators(client: &mut MockClient, sequence: &mut Sequence, block: u64) {
+            client
+                .expect_call_transaction()
+                .with(always(), always(), eq(Some(BlockTag::Number(block))))
+                .times(1)
+                .in_sequence(sequence)
+                .returning(|_, _, _| Ok(VALIDATOR_LIST_BYTES.clone()));
+        }
+
+        fn expect_events(client: &mut MockClient, sequence: &mut Sequence, events: Vec<EventLog>) {
+            client
+                .expect_query_events()
+                .times(1)
+                .in_sequence(sequence)
+                .returning(move |_| Ok(events.clone()));
+        }
+
+        fn validator_quorum(sync_interval: chrono::Duration) -> QuorumHandler {
+            validator_quorum_with_nodes(vec![], sync_interval)
+        }
+
+        fn validator_quorum_with_nodes(
+            nodes: Vec<String>,
+            sync_interval: chrono::Duration,
+        ) -> QuorumHandler {
+            let mut quorum = QuorumHandler::new(QuorumConfig {
+                nodes,
+                validator_nodes: Some(HashMap::from([
+                    (
+                        "0x93917CADBACE5DFCE132B991732C6CDA9BCC5B8A".to_string(),
+                        CLIENT_NODE_ADDRESSES[0].to_string(),
+                    ),
+                    (
+                        "0xce412f988377e31f4d0ff12d74df73b51c42d0ca".to_string(),
+                        CLIENT_NODE_ADDRESSES[2].to_string(),
+                    ),
+                ])),
+                ..QuorumConfig::default()
+            })
+            .unwrap();
+            quorum.validator_nodes.as_mut().unwrap().sync_interval = sync_interval;
+            quorum
+        }
+
+        async fn node_names(quorum: &QuorumHandler) -> Vec<String> {
+            quorum
+                .current_nodes()
+                .await
+                .into_iter()
+                .map(|node| node.name)
+                .collect()
+        }
+
+        #[async_std::test]
+        async fn sync_validator_nodes() {
+            let ledger = ledger_client();
+            let mut client = MockClient::new();
+            let mut sequence = Sequence::new();
+            // first sync requests validators
+            expect_block(&mut client, &mut sequence, 10);
+            expect_validators(&mut client, &mut sequence, 10);
+            // no new blocks
+            expect_block(&mut client, &mut sequence, 10);
+            // no validator events in new blocks
+            expect_block(&mut client, &mut sequence, 12);
+            expect_events(&mut client, &mut sequence, vec![]);
+            // validator events in new blocks
+            expect_block(&mut client, &mut sequence, 14);
+            expect_events(
+                &mut client,
+                &mut sequence,
+                vec![EventLog::new(vec![vec![1; 32]], vec![], 14)],
+            );
+            expect_validators(&mut client, &mut sequence, 14);
+
+            let quorum = validator_quorum(chrono::Duration::zero());
+            for _ in 0..4 {
+                quorum.sync_validator_nodes(&ledger, &client).await.unwrap();
+            }
+
+            assert_eq!(
+                vec![CLIENT_NODE_ADDRESSES[0], CLIENT_NODE_ADDRESSES[2]],
+                node_names(&quorum).await
+            );
+        }
+
+        #[async_std::test]
+        async fn sync_validator_nodes_throttled() {
+            let ledger = ledger_client();
+            let mut client = MockClient::new();
+            let mut sequence = Sequence::new();
+            expect_block(&mut client, &mut sequence, 10);
+            expect_validators(&mut client, &mut sequence, 10);
+
+            let quorum = validator_quorum(chrono::Duration::seconds(60));
+
+            for _ in 0..3 {
+                quorum.sync_validator_nodes(&ledger, &client).await.unwrap();
+            }
+
+            assert_eq!(
+                vec![CLIENT_NODE_ADDRESSES[0], CLIENT_NODE_ADDRESSES[2]],
+                node_names(&quorum).await
+            );
+        }
+
+        #[async_std::test]
+        async fn sync_validator_nodes_falls_back_to_validators_request() {
+            let ledger = ledger_client();
+            let mut client = MockClient::new();
+            let mut sequence = Sequence::new();
+            expect_block(&mut client, &mut sequence, 10);
+            expect_validators(&mut client, &mut sequence, 10);
+            // events query rejected by the node
+            expect_block(&mut client, &mut sequence, 20);
+            client
+                .expect_query_events()
+                .times(1)
+                .in_sequence(&mut sequence)
+                .returning(|_| {
+                    Err(VdrError::ClientQueryLimitExceeded(
+                        "query returned more than 10000 results".to_string(),
+                    ))
+                });
+            expect_validators(&mut client, &mut sequence, 20);
+            // block range is too large to query events
+            let latest_block = 21 + MAX_VALIDATOR_EVENTS_RANGE;
+            expect_block(&mut client, &mut sequence, latest_block);
+            expect_validators(&mut client, &mut sequence, latest_block);
+
+            let quorum = validator_quorum(chrono::Duration::zero());
+
+            for _ in 0..3 {
+                quorum.sync_validator_nodes(&ledger, &client).await.unwrap();
+            }
+
+            assert_eq!(
+                vec![CLIENT_NODE_ADDRESSES[0], CLIENT_NODE_ADDRESSES[2]],
+                node_names(&quorum).await
+            );
+        }
+
+        #[async_std::test]
+        async fn sync_validator_nodes_skips_configured_nodes() {
+            let ledger = ledger_client();
+            let mut client = MockClient::new();
+            let mut sequence = Sequence::new();
+            expect_block(&mut client, &mut sequence, 10);
+            expect_validators(&mut client, &mut sequence, 10);
+
+            let quorum = validator_quorum_with_nodes(
+                vec![CLIENT_NODE_ADDRESSES[0].to_string()],
+                chrono::Duration::zero(),
+            );
+            quorum.sync_validator_nodes(&ledger, &client).await.unwrap();
+
+            assert_eq!(
+                vec![CLIENT_NODE_ADDRESSES[0], CLIENT_NODE_ADDRESSES[2]],
+                node_names(&quorum).await
+            );
+        }
+
+        #[async_std::test]
+        async fn sync_validator_nodes_count_exceeds_total_weight() {
+            let ledger = ledger_client();
+            let mut client = MockClient::new();
+            let mut sequence = Sequence::new();
+            expect_block(&mut client, &mut sequence, 10);
+            expect_validators(&mut client, &mut sequence, 10);
+
+            // the third validator endpoint is counted on creation, but the validator is not in the list
+            let quorum = QuorumHandler::new(QuorumConfig {
+                nodes: vec![],
+                validator_nodes: Some(HashMap::from([
+                    (
+                        "0x93917CADBACE5DFCE132B991732C6CDA9BCC5B8A".to_string(),
+                        CLIENT_NODE_ADDRESSES[0].to_string(),
+                    ),
+                    (
+                        "0xce412f988377e31f4d0ff12d74df73b51c42d0ca".to_string(),
+                        CLIENT_NODE_ADDRESSES[2].to_string(),
+                    ),
+                    (
+                        "0x0000000000000000000000000000000000003333".to_string(),
+                        CLIENT_NODE_ADDRESSES[1].to_string(),
+                    ),
+                ])),
+                read_policy: Some(QuorumPolicy::Count(3)),
+                ..QuorumConfig::default()
+            })
+            .unwrap();
+
+            let err = quorum
+                .sync_validator_nodes(&ledger, &client)
+                .await
+                .unwrap_err();
+
+            assert!(matches!(err, VdrError::ClientInvalidState { .. }));
+            assert!(err
+                .to_string()
+                .contains("count 3 exceeds the total weight 2"));
+        }
+
+        #[async_std::test]
+        async fn sync_validator_nodes_keeps_last_synced_list_on_failure() {
+            let ledger = ledger_client();
+            let mut client = MockClient::new();
+            let mut sequence = Sequence::new();
+            expect_block(&mut client, &mut sequence, 10);
+            expect_validators(&mut client, &mut sequence, 10);
+            client
+                .expect_get_block()
+                .times(1)
+                .in_sequence(&mut sequence)
+                .returning(|_| Err(VdrError::ClientNodeUnreachable));
+
+            let quorum = validator_quorum(chrono::Duration::zero());
+            quorum.sync_validator_nodes(&ledger, &client).await.unwrap();
+            quorum.sync_validator_nodes(&ledger, &client).await.unwrap();
+
+            assert_eq!(
+                vec![CLIENT_NODE_ADDRESSES[0], CLIENT_NODE_ADDRESSES[2]],
+                node_names(&quorum).await
+            );
+        }
+
+        #[async_std::test]
+        async fn sync_validator_nodes_fails_if_never_synced() {
+            let ledger = ledger_client();
+            let mut client = MockClient::new();
+            client
+                .expect_get_block()
+                .times(1)
+                .returning(|_| Err(VdrError::ClientNodeUnreachable));
+
+            let quorum = validator_quorum(chrono::Duration::zero());
+            let err = quorum
+                .sync_validator_nodes(&ledger, &client)
+                .await
+                .unwrap_err();
+
+            assert!(matches!(err, VdrError::ClientNodeUnreachable));
+            assert!(node_names(&quorum).await.is_empty());
+        }
+
+        #[async_std::test]
+        async fn sync_validator_nodes_does_not_wait_for_refresh_in_flight() {
+            let ledger = ledger_client();
+            let mut client = MockClient::new();
+            let mut sequence = Sequence::new();
+            expect_block(&mut client, &mut sequence, 10);
+            expect_validators(&mut client, &mut sequence, 10);
+
+            let quorum = validator_quorum(chrono::Duration::zero());
+            quorum.sync_validator_nodes(&ledger, &client).await.unwrap();
+
+            let validator_nodes = quorum.validator_nodes.as_ref().unwrap();
+            let _refresh = validator_nodes.refresh.lock().await;
+            quorum
+                .sync_validator_nodes(&ledger, &MockClient::new())
+                .await
+                .unwrap();
+
+            assert_eq!(
+                vec![CLIENT_NODE_ADDRESSES[0], CLIENT_NODE_ADDRESSES[2]],
+                node_names(&quorum).await
+            );
+        }
+    }
 }
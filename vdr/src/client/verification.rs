@@ -0,0 +1,124 @@
+use ethereum_types::U256;
+use sha3::{Digest, Keccak256};
+
+use crate::{
+    client::{Client, Contract},
+    error::VdrResult,
+    types::{Address, VerificationMismatch},
+};
+
+/// Name hashed to get the ERC-1967 storage slot holding the implementation address of the proxy
+const IMPLEMENTATION_SLOT_NAME: &[u8] = b"eip1967.proxy.implementation";
+/// Opcode pushing one byte constant. Opcodes of longer constants follow it
+const PUSH1: u8 = 0x60;
+/// Opcode pushing zero constant
+const PUSH0: u8 = 0x5f;
+
+/// Check that the contract is deployed at the configured address and implements functions of its ABI
+///
+/// Contracts deployed behind ERC-1967 proxies (upgradable contracts) are checked against the code of the
+/// implementation contract.
+///
+/// # Params
+/// - `client` client to request the contract code
+/// - `name` name of the contract
+/// - `contract` contract to check
+///
+/// # Returns
+/// mismatch found for the contract, if any
+pub(crate) async fn verify_contract(
+    client: &dyn Client,
+    name: &str,
+    contract: &dyn Contract,
+) -> VdrResult<Option<VerificationMismatch>> {
+    let address = contract.address();
+    let code = client.get_code(address).await?;
+    if code.is_empty() {
+        return Ok(Some(VerificationMismatch::ContractNotDeployed {
+            contract: name.to_string(),
+            address: address.clone(),
+        }));
+    }
+
+    let code = match implementation_address(client, address).await? {
+        Some(implementation) => client.get_code(&implementation).await?,
+        None => code,
+    };
+
+    let functions: Vec<String> = contract
+        .functions()
+        .into_iter()
+        .filter(|function| !contains_selector(&code, function.short_signature()))
+        .map(|function| function.signature())
+        .collect();
+    if functions.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(VerificationMismatch::ContractFunctionsMissing {
+        contract: name.to_string(),
+        address: address.clone(),
+        functions,
+    }))
+}
+
+/// Get address of the implementation contract stored in the ERC-1967 slot of the proxy
+async fn implementation_address(
+    client: &dyn Client,
+    address: &Address,
+) -> VdrResult<Option<Address>> {
+    let value = client
+        .get_storage_at(address, &implementation_slot())
+        .await?;
+    if value.len() != 32 || value.iter().all(|byte| *byte == 0) {
+        return Ok(None);
+    }
+    Ok(Some(Address::from(hex::encode(&value[12..]).as_str())))
+}
+
+/// `keccak256("eip1967.proxy.implementation") - 1`
+fn implementation_slot() -> [u8; 32] {
+    let hash = Keccak256::digest(IMPLEMENTATION_SLOT_NAME);
+    let mut slot = [0u8; 32];
+    (U256::from_big_endian(&hash) - 1).to_big_endian(&mut slot);
+    slot
+}
+
+/// Check whether the bytecode pushes the function selector to the stack, as the Solidity function dispatcher does.
+/// Leading zero bytes of the selector are omitted by the compiler
+fn contains_selector(code: &[u8], selector: [u8; 4]) -> bool {
+    let significant = match selector.iter().position(|byte| *byte != 0) {
+        Some(position) => &selector[position..],
+        None => return code.contains(&PUSH0),
+    };
+    let mut pattern = vec![PUSH1 + significant.len() as u8 - 1];
+    pattern.extend_from_slice(significant);
+    code.windows(pattern.len()).any(|window| window == pattern)
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn implementation_slot_test() {
+        assert_eq!(
+            "360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc",
+            hex::encode(implementation_slot())
+        );
+    }
+
+    #[rstest]
+    #[case::push4([0x12, 0x34, 0x56, 0x78], vec![0x80, 0x63, 0x12, 0x34, 0x56, 0x78, 0x14], true)]
+    #[case::push3_leading_zero([0x00, 0x34, 0x56, 0x78], vec![0x80, 0x62, 0x34, 0x56, 0x78, 0x14], true)]
+    #[case::other_selector([0x12, 0x34, 0x56, 0x78], vec![0x80, 0x63, 0x12, 0x34, 0x56, 0x79, 0x14], false)]
+    #[case::selector_as_data([0x12, 0x34, 0x56, 0x78], vec![0x80, 0x12, 0x34, 0x56, 0x78, 0x14], false)]
+    fn contains_selector_test(
+        #[case] selector: [u8; 4],
+        #[case] code: Vec<u8>,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(expected, contains_selector(&code, selector));
+    }
+}
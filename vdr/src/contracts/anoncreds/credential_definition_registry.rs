@@ -3,21 +3,23 @@ use log_derive::{logfn, logfn_inputs};
 use crate::{
     client::LedgerClient,
     contracts::anoncreds::types::{
+        anoncreds_events::CredentialDefinitionCreated,
         credential_definition::{CredentialDefinition, CredentialDefinitionRecord},
         credential_definition_id::{CredentialDefinitionId, ParsedCredentialDefinitionId},
     },
     error::VdrResult,
     types::{
-        Address, Transaction, TransactionBuilder, TransactionEndorsingDataBuilder,
-        TransactionParser, TransactionType,
+        Address, EventLog, EventParser, Transaction, TransactionBuilder,
+        TransactionEndorsingDataBuilder, TransactionParser, TransactionType,
     },
     TransactionEndorsingData, VdrError,
 };
 
-const CONTRACT_NAME: &str = "CredentialDefinitionRegistry";
+pub(crate) const CONTRACT_NAME: &str = "CredentialDefinitionRegistry";
 const METHOD_CREATE_CREDENTIAL_DEFINITION: &str = "createCredentialDefinition";
 const METHOD_CREATE_CREDENTIAL_DEFINITION_SIGNED: &str = "createCredentialDefinitionSigned";
 const METHOD_RESOLVE_CREDENTIAL_DEFINITION: &str = "resolveCredentialDefinition";
+const EVENT_CREDENTIAL_DEFINITION_CREATED: &str = "CredentialDefinitionCreated";
 
 /// Build a transaction to create a new Credential Definition record (CredentialDefinitionRegistry.createCredentialDefinition contract method)
 ///
@@ -125,6 +127,26 @@ pub fn parse_resolve_credential_definition_result(
         .parse::<CredentialDefinitionRecord>(client, bytes)
 }
 
+/// Parse CredentialDefinitionCreated event from the event log.
+///
+/// # Params
+/// - `client`: [LedgerClient] - client connected to the network where contract will be executed
+/// - `log`: [EventLog] - event log returned from the ledger
+///
+/// # Returns
+///   event: [CredentialDefinitionCreated] - Parsed CredentialDefinitionCreated event object
+#[logfn(Info)]
+#[logfn_inputs(Debug)]
+pub fn parse_credential_definition_created_event_response(
+    client: &LedgerClient,
+    log: &EventLog,
+) -> VdrResult<CredentialDefinitionCreated> {
+    EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_CREDENTIAL_DEFINITION_CREATED)
+        .parse::<CredentialDefinitionCreated>(client, log)
+}
+
 /// Single step function to resolve a Credential Definition for the given ID
 ///
 /// # Params
@@ -242,6 +264,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -302,6 +325,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
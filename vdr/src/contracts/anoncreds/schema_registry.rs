@@ -3,21 +3,23 @@ use log_derive::{logfn, logfn_inputs};
 use crate::{
     client::LedgerClient,
     contracts::anoncreds::types::{
+        anoncreds_events::SchemaCreated,
         schema::{Schema, SchemaRecord},
         schema_id::{ParsedSchemaId, SchemaId},
     },
     error::VdrResult,
     types::{
-        Address, Transaction, TransactionBuilder, TransactionEndorsingDataBuilder,
-        TransactionParser, TransactionType,
+        Address, EventLog, EventParser, Transaction, TransactionBuilder,
+        TransactionEndorsingDataBuilder, TransactionParser, TransactionType,
     },
     TransactionEndorsingData, VdrError,
 };
 
-const CONTRACT_NAME: &str = "SchemaRegistry";
+pub(crate) const CONTRACT_NAME: &str = "SchemaRegistry";
 const METHOD_CREATE_SCHEMA: &str = "createSchema";
 const METHOD_CREATE_SCHEMA_SIGNED: &str = "createSchemaSigned";
 const METHOD_RESOLVE_SCHEMA: &str = "resolveSchema";
+const EVENT_SCHEMA_CREATED: &str = "SchemaCreated";
 
 /// Build a transaction to create a new Schema (SchemaRegistry.createSchema contract method)
 ///
@@ -118,6 +120,26 @@ pub fn parse_resolve_schema_result(client: &LedgerClient, bytes: &[u8]) -> VdrRe
         .parse::<SchemaRecord>(client, bytes)
 }
 
+/// Parse SchemaCreated event from the event log.
+///
+/// # Params
+/// - `client`: [LedgerClient] - client connected to the network where contract will be executed
+/// - `log`: [EventLog] - event log returned from the ledger
+///
+/// # Returns
+///   event: [SchemaCreated] - Parsed SchemaCreated event object
+#[logfn(Info)]
+#[logfn_inputs(Debug)]
+pub fn parse_schema_created_event_response(
+    client: &LedgerClient,
+    log: &EventLog,
+) -> VdrResult<SchemaCreated> {
+    EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_SCHEMA_CREATED)
+        .parse::<SchemaCreated>(client, log)
+}
+
 /// Single step function to resolve a Schema for the given ID
 ///
 /// # Params
@@ -219,6 +241,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -269,6 +292,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
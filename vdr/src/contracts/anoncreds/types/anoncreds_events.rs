@@ -0,0 +1,48 @@
+use crate::{types::ContractEvent, Address, VdrError};
+use serde_derive::{Deserialize, Serialize};
+
+/// Event emitted by SchemaRegistry contract when a new Schema is created
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaCreated {
+    /// Keccak256 hash of the created schema id (see [SchemaId](crate::SchemaId))
+    pub schema_id_hash: Vec<u8>,
+    pub identity: Address,
+}
+
+/// Event emitted by CredentialDefinitionRegistry contract when a new Credential Definition is created
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialDefinitionCreated {
+    /// Keccak256 hash of the created credential definition id (see [CredentialDefinitionId](crate::CredentialDefinitionId))
+    pub credential_definition_id_hash: Vec<u8>,
+    pub identity: Address,
+}
+
+impl TryFrom<ContractEvent> for SchemaCreated {
+    type Error = VdrError;
+
+    fn try_from(log: ContractEvent) -> Result<Self, Self::Error> {
+        let schema_id_hash = log.get_fixed_bytes(0)?;
+        let identity = log.get_address(1)?;
+
+        Ok(SchemaCreated {
+            schema_id_hash,
+            identity,
+        })
+    }
+}
+
+impl TryFrom<ContractEvent> for CredentialDefinitionCreated {
+    type Error = VdrError;
+
+    fn try_from(log: ContractEvent) -> Result<Self, Self::Error> {
+        let credential_definition_id_hash = log.get_fixed_bytes(0)?;
+        let identity = log.get_address(1)?;
+
+        Ok(CredentialDefinitionCreated {
+            credential_definition_id_hash,
+            identity,
+        })
+    }
+}
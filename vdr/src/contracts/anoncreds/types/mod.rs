@@ -1,3 +1,4 @@
+pub mod anoncreds_events;
 pub mod credential_definition;
 pub mod credential_definition_id;
 pub mod schema;
@@ -1,16 +1,22 @@
 use crate::{
     client::LedgerClient,
-    contracts::auth::{HasRole, Role},
+    contracts::auth::{HasRole, Role, RoleAssigned, RoleEvents, RoleRevoked},
     error::VdrResult,
-    types::{Address, Transaction, TransactionBuilder, TransactionParser, TransactionType},
+    types::{
+        Address, Block, EventLog, EventParser, EventQuery, EventQueryBuilder, Transaction,
+        TransactionBuilder, TransactionParser, TransactionType,
+    },
+    VdrError,
 };
 use log_derive::{logfn, logfn_inputs};
 
-const CONTRACT_NAME: &str = "RoleControl";
+pub(crate) const CONTRACT_NAME: &str = "RoleControl";
 const METHOD_ASSIGN_ROLE: &str = "assignRole";
 const METHOD_REVOKE_ROLE: &str = "revokeRole";
 const METHOD_HAS_ROLE: &str = "hasRole";
 const METHOD_GET_ROLE: &str = "getRole";
+const EVENT_ROLE_ASSIGNED: &str = "RoleAssigned";
+const EVENT_ROLE_REVOKED: &str = "RoleRevoked";
 
 /// Build transaction to execute RoleControl.assignRole contract method to assign a role to an account
 ///
@@ -153,6 +159,112 @@ pub fn parse_get_role_result(client: &LedgerClient, bytes: &[u8]) -> VdrResult<R
         .parse::<Role>(client, bytes)
 }
 
+/// Build event query to obtain RoleAssigned and RoleRevoked events from the ledger
+///
+/// # Params
+/// - `client`: [LedgerClient] - client connected to the network where contract will be executed
+/// - `account`: [Address] - (Optional) account the role was assigned to / revoked from
+/// - `sender`: [Address] - (Optional) account assigned / revoked the role
+/// - `from_block`: [Block] - start block
+/// - `to_block`: [Block] - finish block
+///
+/// # Returns
+///   query: [EventQuery] - prepared event query to send
+#[logfn(Info)]
+#[logfn_inputs(Debug)]
+pub fn build_get_role_events_query(
+    client: &LedgerClient,
+    account: Option<&Address>,
+    sender: Option<&Address>,
+    from_block: Option<&Block>,
+    to_block: Option<&Block>,
+) -> VdrResult<EventQuery> {
+    let mut builder = EventQueryBuilder::new()
+        .set_contract(CONTRACT_NAME)
+        .set_from_block(from_block.cloned())
+        .set_to_block(to_block.cloned())
+        .add_event(EVENT_ROLE_ASSIGNED)
+        .add_event(EVENT_ROLE_REVOKED);
+    if let Some(account) = account {
+        builder = builder.set_topic(1, vec![account.to_filter()]);
+    }
+    if let Some(sender) = sender {
+        builder = builder.set_topic(2, vec![sender.to_filter()]);
+    }
+    builder.build(client)
+}
+
+/// Parse RoleAssigned event from the event log.
+///
+/// # Params
+/// - `client`: [LedgerClient] - client connected to the network where contract will be executed
+/// - `log`: [EventLog] - event log returned from the ledger
+///
+/// # Returns
+///   event: [RoleAssigned] - Parsed RoleAssigned event object
+#[logfn(Info)]
+#[logfn_inputs(Debug)]
+pub fn parse_role_assigned_event_response(
+    client: &LedgerClient,
+    log: &EventLog,
+) -> VdrResult<RoleAssigned> {
+    EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_ROLE_ASSIGNED)
+        .parse::<RoleAssigned>(client, log)
+}
+
+/// Parse RoleRevoked event from the event log.
+///
+/// # Params
+/// - `client`: [LedgerClient] - client connected to the network where contract will be executed
+/// - `log`: [EventLog] - event log returned from the ledger
+///
+/// # Returns
+///   event: [RoleRevoked] - Parsed RoleRevoked event object
+#[logfn(Info)]
+#[logfn_inputs(Debug)]
+pub fn parse_role_revoked_event_response(
+    client: &LedgerClient,
+    log: &EventLog,
+) -> VdrResult<RoleRevoked> {
+    EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_ROLE_REVOKED)
+        .parse::<RoleRevoked>(client, log)
+}
+
+/// Parse RoleControl event from the event log.
+///
+/// # Params
+/// - `client`: [LedgerClient] - client connected to the network where contract will be executed
+/// - `log`: [EventLog] - event log returned from the ledger
+///
+/// # Returns
+///   event: [RoleEvents] - Parsed RoleControl event object
+#[logfn(Info)]
+#[logfn_inputs(Debug)]
+pub fn parse_role_event_response(client: &LedgerClient, log: &EventLog) -> VdrResult<RoleEvents> {
+    let contract = client.contract(CONTRACT_NAME)?;
+
+    let event_signature = log.topics.first().ok_or_else(|| {
+        VdrError::ContractInvalidResponseData("Unable to get event topic".to_string())
+    })?;
+
+    if event_signature.eq(&contract.event(EVENT_ROLE_ASSIGNED)?.signature()) {
+        return parse_role_assigned_event_response(client, log).map(RoleEvents::RoleAssigned);
+    }
+
+    if event_signature.eq(&contract.event(EVENT_ROLE_REVOKED)?.signature()) {
+        return parse_role_revoked_event_response(client, log).map(RoleEvents::RoleRevoked);
+    }
+
+    Err(VdrError::ContractInvalidResponseData(format!(
+        "Unexpected contract event. Event signature: {:?}",
+        event_signature
+    )))
+}
+
 #[cfg(test)]
 pub mod test {
     use super::*;
@@ -193,6 +305,7 @@ pub mod test {
                 data: expected_data,
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
 
             assert_eq!(expected_transaction, transaction);
@@ -229,6 +342,7 @@ pub mod test {
                 data: expected_data,
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
 
             assert_eq!(expected_transaction, transaction);
@@ -259,6 +373,7 @@ pub mod test {
                 data: expected_data,
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
 
             assert_eq!(expected_transaction, transaction);
@@ -305,12 +420,51 @@ pub mod test {
                 data: expected_data,
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
 
             assert_eq!(expected_transaction, transaction);
         }
     }
 
+    mod build_get_role_events_query {
+        use super::*;
+
+        #[test]
+        fn build_get_role_events_query_test() {
+            let client = mock_client();
+            let contract = client.contract(CONTRACT_NAME).unwrap();
+            let expected_signatures = vec![
+                hex::encode(contract.event(EVENT_ROLE_ASSIGNED).unwrap().signature()),
+                hex::encode(contract.event(EVENT_ROLE_REVOKED).unwrap().signature()),
+            ];
+
+            let query = build_get_role_events_query(
+                &client,
+                None,
+                Some(&TRUSTEE_ACCOUNT),
+                Some(&Block::from(10)),
+                None,
+            )
+            .unwrap();
+
+            assert_eq!(
+                EventQuery {
+                    addresses: vec![CONFIG.contracts.role_control.address.clone()],
+                    from_block: Some(Block::from(10)),
+                    to_block: None,
+                    topics: [
+                        Some(expected_signatures),
+                        None,
+                        Some(vec![TRUSTEE_ACCOUNT.to_filter()]),
+                        None
+                    ],
+                },
+                query
+            );
+        }
+    }
+
     mod parse_has_role_result {
         use super::*;
 
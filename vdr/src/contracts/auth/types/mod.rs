@@ -1,3 +1,5 @@
 pub mod role;
+pub mod role_events;
 
 pub use role::*;
+pub use role_events::*;
@@ -2,10 +2,11 @@ use crate::{
     error::VdrError,
     types::{ContractOutput, ContractParam},
 };
+use serde_derive::{Deserialize, Serialize};
 
 /// Enum listing roles defined on the ledger
 #[repr(u8)]
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
 pub enum Role {
     Empty = 0,
     Trustee = 1,
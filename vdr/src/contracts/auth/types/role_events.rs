@@ -0,0 +1,61 @@
+use crate::{
+    contracts::auth::types::role::{Role, RoleIndex},
+    types::ContractEvent,
+    Address, VdrError,
+};
+use serde_derive::{Deserialize, Serialize};
+
+/// Events emitted by RoleControl contract
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum RoleEvents {
+    RoleAssigned(RoleAssigned),
+    RoleRevoked(RoleRevoked),
+}
+
+/// Event emitted by RoleControl contract when a role is assigned to an account
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RoleAssigned {
+    pub role: Role,
+    pub account: Address,
+    pub sender: Address,
+}
+
+/// Event emitted by RoleControl contract when a role is revoked from an account
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RoleRevoked {
+    pub role: Role,
+    pub account: Address,
+    pub sender: Address,
+}
+
+impl TryFrom<ContractEvent> for RoleAssigned {
+    type Error = VdrError;
+
+    fn try_from(log: ContractEvent) -> Result<Self, Self::Error> {
+        let role = Role::try_from(log.get_uint(0)? as RoleIndex)?;
+        let account = log.get_address(1)?;
+        let sender = log.get_address(2)?;
+
+        Ok(RoleAssigned {
+            role,
+            account,
+            sender,
+        })
+    }
+}
+
+impl TryFrom<ContractEvent> for RoleRevoked {
+    type Error = VdrError;
+
+    fn try_from(log: ContractEvent) -> Result<Self, Self::Error> {
+        let role = Role::try_from(log.get_uint(0)? as RoleIndex)?;
+        let account = log.get_address(1)?;
+        let sender = log.get_address(2)?;
+
+        Ok(RoleRevoked {
+            role,
+            account,
+            sender,
+        })
+    }
+}
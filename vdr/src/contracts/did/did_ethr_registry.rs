@@ -15,7 +15,7 @@ use crate::{
     Block, Nonce, TransactionEndorsingData, VdrError, DID,
 };
 
-const CONTRACT_NAME: &str = "EthereumExtDidRegistry";
+pub(crate) const CONTRACT_NAME: &str = "EthereumExtDidRegistry";
 
 const METHOD_DID_CHANGED: &str = "changed";
 const METHOD_DID_NONCE: &str = "nonce";
@@ -724,6 +724,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -762,6 +763,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -797,6 +799,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -836,6 +839,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -872,6 +876,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -905,6 +910,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -302,6 +302,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -328,6 +329,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -0,0 +1,125 @@
+use log::warn;
+use log_derive::{logfn, logfn_inputs};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    client::LedgerClient,
+    contracts::{
+        anoncreds::{
+            credential_definition_registry, schema_registry,
+            types::anoncreds_events::{CredentialDefinitionCreated, SchemaCreated},
+        },
+        auth::{role_control, RoleEvents},
+        did::{did_ethr_registry, types::did_events::DidEvents},
+    },
+    error::VdrResult,
+    types::{ReceiptLog, TransactionReceipt},
+};
+
+/// Typed event emitted by one of the ledger contracts
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum LedgerEvent {
+    Did(DidEvents),
+    SchemaCreated(SchemaCreated),
+    CredentialDefinitionCreated(CredentialDefinitionCreated),
+    Role(RoleEvents),
+    /// Log emitted by a contract which events are not supported or log which can not be parsed
+    Unknown(ReceiptLog),
+}
+
+/// Parse logs of the transaction receipt into typed contract events.
+///     Contract emitted the log is determined by its address using contract configs the client is created with.
+///     Logs which can not be parsed (e.g. `Upgraded` event of the contract proxy) are returned as
+///     [LedgerEvent::Unknown]
+///
+/// # Params
+/// - `client`: [LedgerClient] - client connected to the network where transaction was executed
+/// - `receipt`: [TransactionReceipt] - receipt of the transaction
+///
+/// # Returns
+///   events: [Vec] - list of parsed events in the order they were emitted
+#[logfn(Info)]
+#[logfn_inputs(Debug)]
+pub fn parse_receipt_events(
+    client: &LedgerClient,
+    receipt: &TransactionReceipt,
+) -> VdrResult<Vec<LedgerEvent>> {
+    Ok(receipt
+        .logs
+        .iter()
+        .map(|log| {
+            let event_log = log.event_log(receipt.block_number);
+            let event = match client.contract_name(&log.address) {
+                Some(did_ethr_registry::CONTRACT_NAME) => {
+                    did_ethr_registry::parse_did_event_response(client, &event_log)
+                        .map(LedgerEvent::Did)
+                }
+                Some(schema_registry::CONTRACT_NAME) => {
+                    schema_registry::parse_schema_created_event_response(client, &event_log)
+                        .map(LedgerEvent::SchemaCreated)
+                }
+                Some(credential_definition_registry::CONTRACT_NAME) => {
+                    credential_definition_registry::parse_credential_definition_created_event_response(
+                        client, &event_log,
+                    )
+                    .map(LedgerEvent::CredentialDefinitionCreated)
+                }
+                Some(role_control::CONTRACT_NAME) => {
+                    role_control::parse_role_event_response(client, &event_log)
+                        .map(LedgerEvent::Role)
+                }
+                _ => Ok(LedgerEvent::Unknown(log.clone())),
+            };
+            event.unwrap_or_else(|err| {
+                warn!(
+                    "Error: {} during parsing log of contract {}. Log is returned as unknown event",
+                    err,
+                    log.address.as_ref()
+                );
+                LedgerEvent::Unknown(log.clone())
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::{
+        client::client::test::{mock_client, CONFIG},
+        types::Address,
+    };
+
+    fn receipt(address: &Address) -> TransactionReceipt {
+        TransactionReceipt {
+            block_number: 1,
+            success: true,
+            logs: vec![ReceiptLog {
+                address: address.clone(),
+                topics: vec![],
+                data: vec![],
+            }],
+            ..TransactionReceipt::default()
+        }
+    }
+
+    #[test]
+    fn parse_receipt_events_of_unknown_contract() {
+        let client = mock_client();
+        let receipt = receipt(&Address::from("0x0000000000000000000000000000000000000001"));
+
+        let events = parse_receipt_events(&client, &receipt).unwrap();
+
+        assert_eq!(vec![LedgerEvent::Unknown(receipt.logs[0].clone())], events);
+    }
+
+    #[test]
+    fn parse_receipt_events_of_known_contract_with_invalid_log() {
+        let client = mock_client();
+        let receipt = receipt(&CONFIG.contracts.role_control.address);
+
+        let events = parse_receipt_events(&client, &receipt).unwrap();
+
+        assert_eq!(vec![LedgerEvent::Unknown(receipt.logs[0].clone())], events);
+    }
+}
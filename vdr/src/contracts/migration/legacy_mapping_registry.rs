@@ -310,6 +310,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -340,6 +341,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -391,6 +393,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -424,6 +427,7 @@ pub mod test {
                 ],
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -2,6 +2,7 @@ pub mod anoncreds;
 pub mod auth;
 pub mod did;
 pub mod endorsing;
+pub mod events;
 pub mod migration;
 pub mod network;
 
@@ -10,5 +11,6 @@ pub use anoncreds::{
 };
 pub use auth::{role_control, Role};
 pub use did::*;
+pub use events::{parse_receipt_events, LedgerEvent};
 pub use migration::legacy_mapping_registry;
 pub use network::validator_control;
@@ -2,7 +2,10 @@ use log_derive::{logfn, logfn_inputs};
 
 use crate::{
     error::VdrResult,
-    types::{Transaction, TransactionBuilder, TransactionParser, TransactionType},
+    types::{
+        Block, EventQuery, EventQueryBuilder, Transaction, TransactionBuilder, TransactionParser,
+        TransactionType,
+    },
     Address, LedgerClient,
 };
 
@@ -12,6 +15,8 @@ const CONTRACT_NAME: &str = "ValidatorControl";
 const METHOD_ADD_VALIDATOR: &str = "addValidator";
 const METHOD_REMOVE_VALIDATOR: &str = "removeValidator";
 const METHOD_GET_VALIDATORS: &str = "getValidators";
+const EVENT_VALIDATOR_ADDED: &str = "ValidatorAdded";
+const EVENT_VALIDATOR_REMOVED: &str = "ValidatorRemoved";
 
 /// Build transaction to execute ValidatorControl.addValidator contract method to add a new Validator
 ///
@@ -103,6 +108,31 @@ pub fn parse_get_validators_result(
         .parse::<ValidatorAddresses>(client, bytes)
 }
 
+/// Build event query to obtain ValidatorAdded and ValidatorRemoved events from the ledger
+///
+/// # Params
+/// - `client`: [LedgerClient] - client connected to the network where contract will be executed
+/// - `from_block`: [Block] - start block
+/// - `to_block`: [Block] - finish block
+///
+/// # Returns
+///   query: [EventQuery] - prepared event query to send
+#[logfn(Info)]
+#[logfn_inputs(Debug)]
+pub fn build_get_validator_events_query(
+    client: &LedgerClient,
+    from_block: Option<&Block>,
+    to_block: Option<&Block>,
+) -> VdrResult<EventQuery> {
+    EventQueryBuilder::new()
+        .set_contract(CONTRACT_NAME)
+        .set_from_block(from_block.cloned())
+        .set_to_block(to_block.cloned())
+        .add_event(EVENT_VALIDATOR_ADDED)
+        .add_event(EVENT_VALIDATOR_REMOVED)
+        .build(client)
+}
+
 #[cfg(test)]
 pub mod test {
     use super::*;
@@ -152,6 +182,7 @@ pub mod test {
                 data: expected_data.into(),
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
 
             assert_eq!(expected_transaction, transaction);
@@ -182,6 +213,7 @@ pub mod test {
                 data: expected_data.into(),
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
 
             assert_eq!(expected_transaction, transaction);
@@ -206,12 +238,44 @@ pub mod test {
                 data: encoded_method.into(),
                 signature: None,
                 hash: None,
+                ..Transaction::default()
             };
 
             assert_eq!(expected_transaction, transaction);
         }
     }
 
+    mod build_get_validator_events_query {
+        use super::*;
+
+        #[test]
+        fn build_get_validator_events_query_test() {
+            let client = mock_client();
+            let contract = client.contract(CONTRACT_NAME).unwrap();
+            let expected_signatures = vec![
+                hex::encode(contract.event(EVENT_VALIDATOR_ADDED).unwrap().signature()),
+                hex::encode(contract.event(EVENT_VALIDATOR_REMOVED).unwrap().signature()),
+            ];
+
+            let query = build_get_validator_events_query(
+                &client,
+                Some(&Block::from(10)),
+                Some(&Block::from(20)),
+            )
+            .unwrap();
+
+            assert_eq!(
+                EventQuery {
+                    addresses: vec![CONFIG.contracts.validator_control.address.clone()],
+                    from_block: Some(Block::from(10)),
+                    to_block: Some(Block::from(20)),
+                    topics: [Some(expected_signatures), None, None, None],
+                },
+                query
+            );
+        }
+    }
+
     mod parse_get_validators_result {
         use std::vec;
 
@@ -4,9 +4,9 @@ use serde_json::json;
 
 use jsonrpc_core::types::error::{Error as RpcError, ErrorCode};
 #[cfg(not(feature = "wasm"))]
-use web3::{ethabi::Error as Web3EthabiError, Error as Web3Error};
+use web3::{error::TransportError, ethabi::Error as Web3EthabiError, Error as Web3Error};
 #[cfg(feature = "wasm")]
-use web3_wasm::{ethabi::Error as Web3EthabiError, Error as Web3Error};
+use web3_wasm::{error::TransportError, ethabi::Error as Web3EthabiError, Error as Web3Error};
 
 const RPC_SERVER_ERROR_RANGE: RangeInclusive<i64> = -32099..=-32000;
 
@@ -18,6 +18,9 @@ pub enum VdrError {
     #[error("Ledger Client: Node is unreachable")]
     ClientNodeUnreachable,
 
+    #[error("Ledger Client: Node responded with HTTP status {}", _0)]
+    ClientHttpStatus(u16),
+
     #[error("Ledger Client: Invalid transaction: {}", _0)]
     ClientInvalidTransaction(String),
 
@@ -36,6 +39,12 @@ pub enum VdrError {
     #[error("Ledger Client: Invalid state {}", _0)]
     ClientInvalidState(String),
 
+    #[error("Ledger Client: Timeout: {}", _0)]
+    ClientTimeout(String),
+
+    #[error("Ledger Client: Query limit exceeded: {}", _0)]
+    ClientQueryLimitExceeded(String),
+
     #[error("Contract: Invalid name: {}", _0)]
     ContractInvalidName(String),
 
@@ -60,6 +69,12 @@ pub enum VdrError {
     #[error("Signer: Unexpected error occurred: {}", _0)]
     SignerUnexpectedError(String),
 
+    #[error("Signer: Invalid keystore: {}", _0)]
+    SignerInvalidKeystore(String),
+
+    #[error("Signer: Invalid mnemonic: {}", _0)]
+    SignerInvalidMnemonic(String),
+
     #[error("Invalid data: {}", _0)]
     CommonInvalidData(String),
 
@@ -81,7 +96,8 @@ pub type VdrResult<T> = Result<T, VdrError>;
 impl From<Web3Error> for VdrError {
     fn from(value: Web3Error) -> Self {
         match value {
-            Web3Error::Unreachable => VdrError::ClientNodeUnreachable,
+            Web3Error::Transport(TransportError::Code(code)) => VdrError::ClientHttpStatus(code),
+            Web3Error::Unreachable | Web3Error::Transport(_) => VdrError::ClientNodeUnreachable,
             Web3Error::InvalidResponse(err) => VdrError::ClientInvalidResponse(err),
             Web3Error::Rpc(err) => err.into(),
             _ => VdrError::ClientUnexpectedError(value.to_string()),
@@ -168,4 +184,27 @@ pub mod test {
 
         assert_eq!(actual_vdr_error, expected_vdr_error);
     }
+
+    #[rstest]
+    #[case::unreachable(Web3Error::Unreachable, VdrError::ClientNodeUnreachable)]
+    #[case::transport_message(
+        Web3Error::Transport(TransportError::Message("connection refused".to_string())),
+        VdrError::ClientNodeUnreachable
+    )]
+    #[case::transport_code(
+        Web3Error::Transport(TransportError::Code(502)),
+        VdrError::ClientHttpStatus(502)
+    )]
+    #[case::rpc_error(
+        Web3Error::Rpc(RpcError { code: ErrorCode::ServerError(-32001), message: "Nonce too low".to_string(), data: None }),
+        VdrError::ClientUnexpectedError("{\"code\":-32001,\"message\":\"Nonce too low\"}".to_string()),
+    )]
+    fn convert_web3_error_to_vdr_error_test(
+        #[case] web3_error: Web3Error,
+        #[case] expected_vdr_error: VdrError,
+    ) {
+        let actual_vdr_error: VdrError = web3_error.into();
+
+        assert_eq!(actual_vdr_error, expected_vdr_error);
+    }
 }
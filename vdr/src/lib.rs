@@ -5,7 +5,6 @@ mod error;
 mod types;
 mod utils;
 
-#[cfg(feature = "basic_signer")]
 mod signer;
 
 #[cfg(feature = "migration")]
@@ -15,11 +14,12 @@ pub mod migration;
 #[cfg(test)]
 mod test;
 
-pub use client::{Client, Contract, LedgerClient};
+pub use client::{Client, Contract, FailoverClient, LedgerClient, LedgerClientBuilder, Web3Client};
 pub use contracts::{
     anoncreds::{
         credential_definition_registry, schema_registry,
         types::{
+            anoncreds_events::{CredentialDefinitionCreated, SchemaCreated},
             credential_definition::{
                 CredentialDefinition, CredentialDefinitionRecord, SignatureType,
             },
@@ -28,7 +28,7 @@ pub use contracts::{
             schema_id::SchemaId,
         },
     },
-    auth::{role_control, Role},
+    auth::{role_control, Role, RoleAssigned, RoleEvents, RoleRevoked},
     did::{
         did_ethr_registry, did_indy_registry, did_resolver,
         types::{
@@ -46,6 +46,7 @@ pub use contracts::{
         },
     },
     endorsing,
+    events::{parse_receipt_events, LedgerEvent},
     migration::{
         legacy_mapping_registry,
         types::{
@@ -59,6 +60,12 @@ pub use contracts::{
 pub use error::{VdrError, VdrResult};
 pub use types::*;
 
-pub use crate::client::QuorumConfig;
+pub use crate::client::{
+    BasicAuth, ConfirmationPolicy, ConnectionConfig, EventPagination, FeeStrategy, NonceManager,
+    QuorumConfig, QuorumPolicy, TlsConfig, TokenProvider,
+};
+#[cfg(not(feature = "wasm"))]
+pub use signer::RemoteSigner;
+pub use signer::Signer;
 #[cfg(feature = "basic_signer")]
-pub use signer::{BasicSigner, KeyPair};
+pub use signer::{BasicSigner, DirectorySigner, KeyPair, KeyStore};
@@ -1,5 +1,9 @@
-use crate::error::{VdrError, VdrResult};
+use crate::{
+    error::{VdrError, VdrResult},
+    signer::{hd_key, keystore::KeyStore, Signer},
+};
 
+use async_trait::async_trait;
 use log::warn;
 use log_derive::{logfn, logfn_inputs};
 use secp256k1::{All, Message, PublicKey, Secp256k1, SecretKey};
@@ -11,6 +15,7 @@ use std::{
 use crate::types::{Address, SignatureData};
 use std::str::FromStr;
 use web3::signing::keccak256;
+use zeroize::Zeroizing;
 
 pub struct KeyPair {
     pub public_key: PublicKey,
@@ -20,6 +25,8 @@ pub struct KeyPair {
 pub struct BasicSigner {
     secp: Secp256k1<All>,
     keys: HashMap<String, KeyPair>,
+    seed: Option<Zeroizing<[u8; 64]>>,
+    derivation_indexes: HashMap<String, u32>,
 }
 
 impl BasicSigner {
@@ -29,9 +36,103 @@ impl BasicSigner {
         Ok(BasicSigner {
             secp: Secp256k1::new(),
             keys: HashMap::new(),
+            seed: None,
+            derivation_indexes: HashMap::new(),
         })
     }
 
+    /// Generate a new random BIP-39 mnemonic (English word list)
+    ///
+    /// # Params
+    /// - `word_count` number of words in the mnemonic: 12, 15, 18, 21 or 24
+    ///
+    /// # Returns
+    /// mnemonic words separated with spaces
+    pub fn generate_mnemonic(word_count: usize) -> VdrResult<String> {
+        hd_key::generate_mnemonic(word_count)
+    }
+
+    /// Create a signer deriving account keys from the BIP-39 mnemonic
+    ///
+    /// Keys are not added until they are derived with [BasicSigner::derive_key].
+    ///
+    /// # Params
+    /// - `mnemonic` mnemonic words separated with spaces
+    /// - `passphrase` optional BIP-39 passphrase
+    ///
+    /// # Returns
+    /// signer restored from the mnemonic
+    pub fn from_mnemonic(mnemonic: &str, passphrase: Option<&str>) -> VdrResult<BasicSigner> {
+        let mut signer = BasicSigner::new()?;
+        signer.seed = Some(hd_key::mnemonic_to_seed(
+            mnemonic,
+            passphrase.unwrap_or_default(),
+        )?);
+        Ok(signer)
+    }
+
+    /// Derive the key of the account `m/44'/60'/0'/0/{index}` from the mnemonic and add it to the signer
+    ///
+    /// # Params
+    /// - `index` index of the account in the derivation path
+    ///
+    /// # Returns
+    /// account and uncompressed public key of the derived key
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub fn derive_key(&mut self, index: u32) -> VdrResult<(Address, Vec<u8>)> {
+        let (account, key_pair) = self.key_pair(self.derive_private_key(index)?);
+        let public_key_bytes = key_pair.public_key.serialize_uncompressed().to_vec();
+        self.keys.insert(account.to_string(), key_pair);
+        self.derivation_indexes.insert(account.to_string(), index);
+        Ok((account, public_key_bytes))
+    }
+
+    /// Find the derivation index of the account
+    ///
+    /// Accounts already derived by the signer are resolved without derivation. Otherwise, indexes are derived from `0`
+    /// until the account is found.
+    ///
+    /// # Params
+    /// - `account` account to look up
+    /// - `max_index` the last index to check
+    ///
+    /// # Returns
+    /// derivation index of the account or `None` if it is not derived from the mnemonic within `max_index`
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub fn find_derivation_index(
+        &self,
+        account: &Address,
+        max_index: u32,
+    ) -> VdrResult<Option<u32>> {
+        // derived accounts are lowercase, while the given one can be checksummed
+        let account = account.as_ref().to_lowercase();
+        if let Some(index) = self.derivation_indexes.get(&account) {
+            return Ok(Some(*index));
+        }
+        for index in 0..=max_index {
+            let (derived, _) = self.key_pair(self.derive_private_key(index)?);
+            if derived.as_ref().to_lowercase() == account {
+                return Ok(Some(index));
+            }
+        }
+        Ok(None)
+    }
+
+    fn derive_private_key(&self, index: u32) -> VdrResult<SecretKey> {
+        let seed = self.seed.as_ref().ok_or_else(|| {
+            let vdr_error = VdrError::SignerInvalidMnemonic(
+                "Signer is not created from a mnemonic".to_string(),
+            );
+
+            warn!("Error: {} during deriving key", vdr_error);
+
+            vdr_error
+        })?;
+        hd_key::derive_ethereum_key(&self.secp, seed.as_slice(), index)
+    }
+
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
     pub fn create_key(&mut self, private_key: Option<&str>) -> VdrResult<(Address, Vec<u8>)> {
@@ -41,6 +142,59 @@ impl BasicSigner {
         Ok((account, public_key_bytes))
     }
 
+    /// Import the key from the Ethereum keystore v3 file
+    ///
+    /// # Params
+    /// - `keystore` content of the keystore file (JSON)
+    /// - `password` password used to encrypt the key
+    ///
+    /// # Returns
+    /// account of the imported key
+    pub fn import_keystore(&mut self, keystore: &str, password: &str) -> VdrResult<Address> {
+        let keystore = KeyStore::from_json(keystore)?;
+        let private_key = keystore.decrypt(password)?;
+        let (account, key_pair) = self.key_pair(SecretKey::from_slice(&private_key)?);
+        if let Some(address) = keystore.address() {
+            if address != account {
+                let vdr_error = VdrError::SignerInvalidKeystore(format!(
+                    "Keystore address {} does not match the encrypted key",
+                    address.as_ref()
+                ));
+
+                warn!("Error: {} during importing keystore", vdr_error);
+
+                return Err(vdr_error);
+            }
+        }
+        self.keys.insert(account.to_string(), key_pair);
+        Ok(account)
+    }
+
+    /// Export the key of the account as Ethereum keystore v3 file encrypted with scrypt and AES-128-CTR
+    ///
+    /// # Params
+    /// - `account` account which key to export
+    /// - `password` password to encrypt the key
+    ///
+    /// # Returns
+    /// content of the keystore file (JSON)
+    pub fn export_keystore(&self, account: &str, password: &str) -> VdrResult<String> {
+        let key = self.key_for_account(account)?;
+        let private_key = Zeroizing::new(key.private_key.secret_bytes());
+        KeyStore::encrypt(&private_key[..], &Address::from(account), password)?.to_json()
+    }
+
+    /// Remove the key of the account from the signer
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub fn remove_key(&mut self, account: &str) -> VdrResult<()> {
+        self.derivation_indexes.remove(account);
+        self.keys
+            .remove(account)
+            .map(|_| ())
+            .ok_or_else(|| VdrError::SignerMissingKey(account.to_string()))
+    }
+
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
     fn key_for_account(&self, account: &str) -> VdrResult<&KeyPair> {
@@ -70,13 +224,17 @@ impl BasicSigner {
             Some(private_key) => SecretKey::from_str(private_key)?,
             None => SecretKey::new(&mut rand::thread_rng()),
         };
+        Ok(self.key_pair(private_key))
+    }
+
+    fn key_pair(&self, private_key: SecretKey) -> (Address, KeyPair) {
         let public_key = PublicKey::from_secret_key(&self.secp, &private_key);
         let address = Address::from(self.account_from_key(&public_key).as_str());
         let key_pair = KeyPair {
             public_key,
             private_key,
         };
-        Ok((address, key_pair))
+        (address, key_pair)
     }
 
     #[logfn(Trace)]
@@ -95,12 +253,36 @@ impl BasicSigner {
     }
 }
 
+#[cfg_attr(not(feature = "wasm"), async_trait)]
+#[cfg_attr(feature = "wasm", async_trait(?Send))]
+impl Signer for BasicSigner {
+    async fn accounts(&self) -> VdrResult<Vec<Address>> {
+        let mut accounts: Vec<Address> = self
+            .keys
+            .keys()
+            .map(|account| Address::from(account.as_str()))
+            .collect();
+        accounts.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+        Ok(accounts)
+    }
+
+    async fn sign_hash(&self, hash: &[u8], account: &Address) -> VdrResult<SignatureData> {
+        self.sign(hash, account.as_ref())
+    }
+}
+
 impl Debug for BasicSigner {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, r#"BasicSigner {{ }}"#)
     }
 }
 
+impl Drop for KeyPair {
+    fn drop(&mut self) {
+        self.private_key.non_secure_erase();
+    }
+}
+
 impl Debug for KeyPair {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, r#"KeyPair {{ }}"#)
@@ -110,7 +292,12 @@ impl Debug for KeyPair {
 #[cfg(test)]
 pub mod test {
     use super::*;
+    use crate::signer::{
+        hd_key::test::MNEMONIC,
+        keystore::test::{ETHSIGNER_ACCOUNT, ETHSIGNER_KEY_PATH, ETHSIGNER_PASSWORD},
+    };
     use once_cell::sync::Lazy;
+    use std::fs;
 
     pub static TRUSTEE_ACC: Lazy<Address> =
         Lazy::new(|| Address::from("0xf0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5"));
@@ -153,4 +340,141 @@ pub mod test {
         ];
         assert_eq!(expected, signature.signature);
     }
+
+    #[test]
+    fn import_ethsigner_keystore_test() {
+        let mut basic_signer = BasicSigner::new().unwrap();
+        let keystore = fs::read_to_string(ETHSIGNER_KEY_PATH).unwrap();
+
+        let account = basic_signer
+            .import_keystore(&keystore, ETHSIGNER_PASSWORD)
+            .unwrap();
+
+        assert_eq!(Address::from(ETHSIGNER_ACCOUNT), account);
+    }
+
+    #[test]
+    fn export_import_keystore_test() {
+        let basic_signer = basic_signer();
+        let keystore = basic_signer
+            .export_keystore(TRUSTEE_ACC.as_ref(), "password")
+            .unwrap();
+
+        let mut imported_signer = BasicSigner::new().unwrap();
+        let account = imported_signer
+            .import_keystore(&keystore, "password")
+            .unwrap();
+
+        assert_eq!(*TRUSTEE_ACC, account);
+    }
+
+    #[test]
+    fn import_keystore_address_mismatch_test() {
+        let keystore = fs::read_to_string(ETHSIGNER_KEY_PATH)
+            .unwrap()
+            .replace(&ETHSIGNER_ACCOUNT[2..], &TRUSTEE_ACC.as_ref()[2..]);
+
+        let err = BasicSigner::new()
+            .unwrap()
+            .import_keystore(&keystore, ETHSIGNER_PASSWORD)
+            .unwrap_err();
+
+        assert!(matches!(err, VdrError::SignerInvalidKeystore { .. }));
+    }
+
+    #[test]
+    fn derive_key_from_mnemonic_test() {
+        let mut basic_signer = BasicSigner::from_mnemonic(MNEMONIC, None).unwrap();
+
+        let (account, _) = basic_signer.derive_key(0).unwrap();
+
+        assert_eq!(
+            Address::from("0x9858effd232b4033e47d90003d41ec34ecaeda94"),
+            account
+        );
+        assert_eq!(
+            "1ab42cc412b618bdea3a599e3c9bae199ebf030895b039e9db1e30dafb12b727",
+            hex::encode(
+                basic_signer
+                    .key_for_account(account.as_ref())
+                    .unwrap()
+                    .private_key
+                    .secret_bytes()
+            )
+        );
+    }
+
+    #[test]
+    fn find_derivation_index_test() {
+        let mut basic_signer = BasicSigner::from_mnemonic(MNEMONIC, None).unwrap();
+        let account = Address::from("0xb6716976a3ebe8d39aceb04372f22ff8e6802d7a");
+
+        assert_eq!(
+            None,
+            basic_signer.find_derivation_index(&account, 1).unwrap()
+        );
+        assert_eq!(
+            Some(2),
+            basic_signer.find_derivation_index(&account, 5).unwrap()
+        );
+
+        basic_signer.derive_key(1).unwrap();
+        assert_eq!(
+            Some(1),
+            basic_signer
+                .find_derivation_index(
+                    &Address::from("0x6fac4d18c912343bf86fa7049364dd4e424ab9c0"),
+                    0
+                )
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn find_derivation_index_of_checksummed_address_test() {
+        let mut basic_signer = BasicSigner::from_mnemonic(MNEMONIC, None).unwrap();
+        let account = Address::from("0xB6716976A3ebe8D39aCEB04372f22Ff8e6802D7A");
+
+        assert_eq!(
+            Some(2),
+            basic_signer.find_derivation_index(&account, 5).unwrap()
+        );
+
+        basic_signer.derive_key(2).unwrap();
+        assert_eq!(
+            Some(2),
+            basic_signer.find_derivation_index(&account, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn derive_key_without_mnemonic_test() {
+        let err = basic_signer().derive_key(0).unwrap_err();
+
+        assert!(matches!(err, VdrError::SignerInvalidMnemonic { .. }));
+    }
+
+    #[async_std::test]
+    async fn basic_signer_accounts_test() {
+        let basic_signer = basic_signer();
+
+        let accounts = basic_signer.accounts().await.unwrap();
+
+        assert_eq!(vec![TRUSTEE_ACC.clone()], accounts);
+    }
+
+    #[async_std::test]
+    async fn basic_signer_sign_hash_missing_key_test() {
+        let basic_signer = basic_signer();
+
+        let err = basic_signer
+            .sign_hash(
+                &[0; 32],
+                &Address::from("0x0000000000000000000000000000000000000001"),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, VdrError::SignerMissingKey { .. }));
+    }
 }
@@ -0,0 +1,208 @@
+use crate::{
+    error::{VdrError, VdrResult},
+    signer::{keystore::KeyStore, BasicSigner, Signer},
+    types::{Address, SignatureData},
+};
+
+use async_trait::async_trait;
+use log::{trace, warn};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+};
+use zeroize::Zeroizing;
+
+/// Signer persisting keys in a directory of Ethereum keystore v3 files encrypted with the same password
+///
+/// All keystore files found in the directory are loaded on opening; other files are ignored. Keys created with the
+/// signer are written into `<account>.json` files, so accounts survive restarts. Decrypted keys and the password are
+/// zeroed in memory when the signer is dropped.
+pub struct DirectorySigner {
+    path: PathBuf,
+    password: Zeroizing<String>,
+    signer: BasicSigner,
+    files: HashMap<String, PathBuf>,
+}
+
+impl DirectorySigner {
+    /// Open the keystore directory and load all keys stored in it. The directory is created if it does not exist
+    ///
+    /// # Params
+    /// - `path` path to the directory
+    /// - `password` password of the keystore files
+    ///
+    /// # Returns
+    /// signer holding the loaded keys
+    pub fn open(path: &str, password: &str) -> VdrResult<DirectorySigner> {
+        let path = PathBuf::from(path);
+        fs::create_dir_all(&path).map_err(|err| io_error(&path, err))?;
+
+        let mut signer = BasicSigner::new()?;
+        let mut files = HashMap::new();
+        for entry in fs::read_dir(&path).map_err(|err| io_error(&path, err))? {
+            let file = entry.map_err(|err| io_error(&path, err))?.path();
+            if !file.is_file() {
+                continue;
+            }
+            let keystore = match fs::read_to_string(&file) {
+                Ok(content) if serde_json::from_str::<KeyStore>(&content).is_ok() => content,
+                _ => {
+                    trace!("Skipping {:?}: not a keystore file", file);
+                    continue;
+                }
+            };
+            let account = signer.import_keystore(&keystore, password)?;
+            files.insert(account.to_string(), file);
+        }
+
+        Ok(DirectorySigner {
+            path,
+            password: Zeroizing::new(password.to_string()),
+            signer,
+            files,
+        })
+    }
+
+    /// Create a new key (or add the given one) and store it into the directory
+    ///
+    /// # Params
+    /// - `private_key` hex encoded private key to add. A random key is generated if not set
+    ///
+    /// # Returns
+    /// account and uncompressed public key of the added key
+    pub fn create_key(&mut self, private_key: Option<&str>) -> VdrResult<(Address, Vec<u8>)> {
+        if private_key.is_some() {
+            let (account, _) = self.signer.create_account(private_key)?;
+            if self.files.contains_key(account.as_ref()) {
+                let vdr_error = VdrError::SignerUnexpectedError(format!(
+                    "Key of account {} is already stored",
+                    account.as_ref()
+                ));
+
+                warn!("Error: {} during creating key", vdr_error);
+
+                return Err(vdr_error);
+            }
+        }
+
+        let (account, public_key) = self.signer.create_key(private_key)?;
+        let file = self.path.join(format!(
+            "{}.json",
+            account.as_ref().trim_start_matches("0x")
+        ));
+        let stored = self
+            .signer
+            .export_keystore(account.as_ref(), &self.password)
+            .and_then(|keystore| write_file(&file, &keystore));
+        if let Err(err) = stored {
+            self.signer.remove_key(account.as_ref())?;
+            return Err(err);
+        }
+        self.files.insert(account.to_string(), file);
+        Ok((account, public_key))
+    }
+
+    /// Remove the key of the account from the signer and delete its file from the directory
+    pub fn remove_key(&mut self, account: &str) -> VdrResult<()> {
+        self.signer.remove_key(account)?;
+        if let Some(file) = self.files.remove(account) {
+            fs::remove_file(&file).map_err(|err| io_error(&file, err))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(feature = "wasm"), async_trait)]
+#[cfg_attr(feature = "wasm", async_trait(?Send))]
+impl Signer for DirectorySigner {
+    async fn accounts(&self) -> VdrResult<Vec<Address>> {
+        self.signer.accounts().await
+    }
+
+    async fn sign_hash(&self, hash: &[u8], account: &Address) -> VdrResult<SignatureData> {
+        self.signer.sign_hash(hash, account).await
+    }
+}
+
+fn write_file(file: &Path, content: &str) -> VdrResult<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    // keystore files must be readable by the owner only
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options
+        .open(file)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+        .map_err(|err| io_error(file, err))
+}
+
+fn io_error(path: &Path, err: std::io::Error) -> VdrError {
+    let vdr_error =
+        VdrError::SignerUnexpectedError(format!("Unable to access keystore {:?}: {}", path, err));
+
+    warn!("Error: {} during accessing keystore directory", vdr_error);
+
+    vdr_error
+}
+
+impl Debug for DirectorySigner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, r#"DirectorySigner {{ path: {:?} }}"#, self.path)
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::signer::{
+        basic_signer::test::{TRUSTEE_ACC, TRUSTEE_PRIVATE_KEY},
+        keystore::test::{ETHSIGNER_ACCOUNT, ETHSIGNER_PASSWORD},
+    };
+    use std::env;
+
+    fn temp_dir() -> PathBuf {
+        let mut path = env::temp_dir();
+        path.push(format!("indy-besu-vdr-keystore-{}", rand::random::<u64>()));
+        path
+    }
+
+    #[async_std::test]
+    async fn open_ethsigner_directory_test() {
+        let signer =
+            DirectorySigner::open("../network/config/ethsigner", ETHSIGNER_PASSWORD).unwrap();
+
+        let accounts = signer.accounts().await.unwrap();
+
+        assert_eq!(vec![Address::from(ETHSIGNER_ACCOUNT)], accounts);
+    }
+
+    #[async_std::test]
+    async fn keys_persist_across_restarts_test() {
+        let path = temp_dir();
+        let path_str = path.to_str().unwrap();
+
+        let mut signer = DirectorySigner::open(path_str, "password").unwrap();
+        let (account, _) = signer.create_key(Some(TRUSTEE_PRIVATE_KEY)).unwrap();
+        assert_eq!(*TRUSTEE_ACC, account);
+        drop(signer);
+
+        let mut signer = DirectorySigner::open(path_str, "password").unwrap();
+        assert_eq!(vec![account.clone()], signer.accounts().await.unwrap());
+
+        signer.remove_key(account.as_ref()).unwrap();
+        let signer = DirectorySigner::open(path_str, "password").unwrap();
+        assert!(signer.accounts().await.unwrap().is_empty());
+
+        fs::remove_dir_all(path).unwrap();
+    }
+
+    #[test]
+    fn open_with_wrong_password_test() {
+        let err = DirectorySigner::open("../network/config/ethsigner", "wrong").unwrap_err();
+
+        assert!(matches!(err, VdrError::SignerInvalidKeystore { .. }));
+    }
+}
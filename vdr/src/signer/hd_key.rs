@@ -0,0 +1,158 @@
+use crate::error::{VdrError, VdrResult};
+
+use bip39::Mnemonic;
+use hmac::{Hmac, Mac};
+use log::warn;
+use rand::RngCore;
+use secp256k1::{All, PublicKey, Scalar, Secp256k1, SecretKey};
+use sha2::Sha512;
+use std::iter::once;
+use zeroize::Zeroizing;
+
+/// Offset of hardened child indexes
+const HARDENED: u32 = 1 << 31;
+/// BIP-44 derivation path of Ethereum accounts without the address index: m/44'/60'/0'/0
+const ETHEREUM_PATH: [u32; 4] = [44 | HARDENED, 60 | HARDENED, HARDENED, 0];
+/// HMAC key used to derive the master key from the seed
+const MASTER_KEY_HMAC: &[u8] = b"Bitcoin seed";
+const MNEMONIC_WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+
+/// Generate a random BIP-39 mnemonic of English words
+pub(crate) fn generate_mnemonic(word_count: usize) -> VdrResult<String> {
+    if !MNEMONIC_WORD_COUNTS.contains(&word_count) {
+        return Err(invalid_mnemonic(&format!(
+            "Word count must be one of {:?}",
+            MNEMONIC_WORD_COUNTS
+        )));
+    }
+    // every 3 words encode 32 bits of entropy
+    let mut entropy = Zeroizing::new(vec![0u8; word_count * 4 / 3]);
+    rand::thread_rng().fill_bytes(&mut entropy);
+    let mnemonic =
+        Mnemonic::from_entropy(&entropy).map_err(|err| invalid_mnemonic(&err.to_string()))?;
+    Ok(mnemonic.to_string())
+}
+
+/// Convert BIP-39 mnemonic into the seed. The mnemonic and passphrase are NFKD normalized before use
+pub(crate) fn mnemonic_to_seed(mnemonic: &str, passphrase: &str) -> VdrResult<Zeroizing<[u8; 64]>> {
+    let mnemonic = Mnemonic::parse(mnemonic).map_err(|err| invalid_mnemonic(&err.to_string()))?;
+    Ok(Zeroizing::new(mnemonic.to_seed(passphrase)))
+}
+
+/// Derive the private key of the Ethereum account `m/44'/60'/0'/0/{index}` from the seed
+pub(crate) fn derive_ethereum_key(
+    secp: &Secp256k1<All>,
+    seed: &[u8],
+    index: u32,
+) -> VdrResult<SecretKey> {
+    if index >= HARDENED {
+        return Err(invalid_mnemonic(&format!(
+            "Account index must be less than {}",
+            HARDENED
+        )));
+    }
+    let path: Vec<u32> = ETHEREUM_PATH.into_iter().chain(once(index)).collect();
+    derive_key(secp, seed, &path)
+}
+
+/// Derive the private key along BIP-32 path. Hardened indexes include the `2^31` offset
+fn derive_key(secp: &Secp256k1<All>, seed: &[u8], path: &[u32]) -> VdrResult<SecretKey> {
+    let hash = hmac_sha512(MASTER_KEY_HMAC, seed);
+    let mut key = SecretKey::from_slice(&hash[..32])?;
+    let mut chain_code = Zeroizing::new(hash[32..].to_vec());
+
+    for index in path {
+        let mut data = Zeroizing::new(Vec::with_capacity(37));
+        if index & HARDENED != 0 {
+            data.push(0);
+            data.extend_from_slice(&key.secret_bytes());
+        } else {
+            data.extend_from_slice(&PublicKey::from_secret_key(secp, &key).serialize());
+        }
+        data.extend_from_slice(&index.to_be_bytes());
+
+        let hash = hmac_sha512(&chain_code, &data);
+        let mut tweak = [0u8; 32];
+        tweak.copy_from_slice(&hash[..32]);
+        let tweak = Scalar::from_be_bytes(tweak).map_err(|_| {
+            VdrError::SignerUnexpectedError(format!("Unable to derive child key {}", index))
+        })?;
+        key = key.add_tweak(&tweak)?;
+        chain_code = Zeroizing::new(hash[32..].to_vec());
+    }
+    Ok(key)
+}
+
+fn hmac_sha512(key: &[u8], data: &[u8]) -> Zeroizing<Vec<u8>> {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    Zeroizing::new(mac.finalize().into_bytes().to_vec())
+}
+
+fn invalid_mnemonic(msg: &str) -> VdrError {
+    let vdr_error = VdrError::SignerInvalidMnemonic(msg.to_string());
+
+    warn!("Error: {} during processing mnemonic", vdr_error);
+
+    vdr_error
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use rstest::rstest;
+
+    pub const MNEMONIC: &str =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[rstest]
+    #[case::master(vec![], "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35")]
+    #[case::hardened(vec![HARDENED], "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea")]
+    #[case::mixed(
+        vec![HARDENED, 1, 2 | HARDENED, 2, 1000000000],
+        "471b76e389e528d6de6d816857e012c5455051cad6660850e58372a6c3e6e7c8"
+    )]
+    fn derive_key_bip32_test_vector(#[case] path: Vec<u32>, #[case] expected: &str) {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+
+        let key = derive_key(&Secp256k1::new(), &seed, &path).unwrap();
+
+        assert_eq!(expected, hex::encode(key.secret_bytes()));
+    }
+
+    #[test]
+    fn mnemonic_to_seed_test() {
+        let seed = mnemonic_to_seed(MNEMONIC, "TREZOR").unwrap();
+
+        assert_eq!(
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+            hex::encode(seed.as_slice())
+        );
+    }
+
+    #[test]
+    fn mnemonic_to_seed_normalizes_passphrase_test() {
+        // precomposed and decomposed forms of "café"
+        let composed = mnemonic_to_seed(MNEMONIC, "caf\u{e9}").unwrap();
+        let decomposed = mnemonic_to_seed(MNEMONIC, "cafe\u{301}").unwrap();
+
+        assert_eq!(composed.as_slice(), decomposed.as_slice());
+    }
+
+    #[rstest]
+    #[case(12)]
+    #[case(24)]
+    fn generate_mnemonic_test(#[case] word_count: usize) {
+        let mnemonic = generate_mnemonic(word_count).unwrap();
+
+        assert_eq!(word_count, mnemonic.split_whitespace().count());
+        mnemonic_to_seed(&mnemonic, "").unwrap();
+    }
+
+    #[test]
+    fn invalid_mnemonic_test() {
+        let err = mnemonic_to_seed(&MNEMONIC.replace("about", "abandon"), "").unwrap_err();
+
+        assert!(matches!(err, VdrError::SignerInvalidMnemonic { .. }));
+    }
+}
@@ -0,0 +1,329 @@
+use crate::{
+    error::{VdrError, VdrResult},
+    types::Address,
+};
+
+use aes::Aes128;
+use ctr::{
+    cipher::{KeyIvInit, StreamCipher},
+    Ctr128BE,
+};
+use log::warn;
+use rand::RngCore;
+use serde_derive::{Deserialize, Serialize};
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
+use uuid::Uuid;
+use zeroize::Zeroizing;
+
+const VERSION: u8 = 3;
+const CIPHER: &str = "aes-128-ctr";
+const KDF_SCRYPT: &str = "scrypt";
+const KDF_PBKDF2: &str = "pbkdf2";
+const PBKDF2_PRF: &str = "hmac-sha256";
+/// Length of the derived key: the first half is the AES key, the second half is the MAC key
+const DKLEN: u32 = 32;
+/// scrypt cost parameters of the created keystore files (same as used by web3.js)
+const SCRYPT_LOG_N: u8 = 13;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+
+type Aes128Ctr = Ctr128BE<Aes128>;
+
+/// Ethereum Web3 Secret Storage (keystore v3) file holding a private key encrypted with a password
+///
+/// Keys are encrypted with AES-128-CTR using a key derived from the password with scrypt or PBKDF2-HMAC-SHA256.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyStore {
+    version: u8,
+    id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    address: Option<String>,
+    #[serde(alias = "Crypto")]
+    crypto: KeyStoreCrypto,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct KeyStoreCrypto {
+    cipher: String,
+    cipherparams: CipherParams,
+    ciphertext: String,
+    kdf: String,
+    kdfparams: KdfParams,
+    mac: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct CipherParams {
+    iv: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+enum KdfParams {
+    Scrypt {
+        dklen: u32,
+        n: u32,
+        p: u32,
+        r: u32,
+        salt: String,
+    },
+    Pbkdf2 {
+        c: u32,
+        dklen: u32,
+        prf: String,
+        salt: String,
+    },
+}
+
+impl KeyStore {
+    /// Encrypt the private key with the password
+    ///
+    /// # Params
+    /// - `private_key` 32 bytes secp256k1 private key
+    /// - `address` [Address] account of the private key
+    /// - `password` password to encrypt the key
+    ///
+    /// # Returns
+    /// keystore holding the encrypted key
+    pub fn encrypt(private_key: &[u8], address: &Address, password: &str) -> VdrResult<KeyStore> {
+        let mut rng = rand::thread_rng();
+        let mut salt = [0u8; 32];
+        rng.fill_bytes(&mut salt);
+        let mut iv = [0u8; 16];
+        rng.fill_bytes(&mut iv);
+
+        let kdfparams = KdfParams::Scrypt {
+            dklen: DKLEN,
+            n: 1 << SCRYPT_LOG_N,
+            p: SCRYPT_P,
+            r: SCRYPT_R,
+            salt: hex::encode(salt),
+        };
+        let derived_key = kdfparams.derive_key(KDF_SCRYPT, password)?;
+
+        let mut ciphertext = private_key.to_vec();
+        apply_cipher(&derived_key, &iv, &mut ciphertext)?;
+
+        Ok(KeyStore {
+            version: VERSION,
+            id: Uuid::new_v4().to_string(),
+            address: Some(address.as_ref().trim_start_matches("0x").to_lowercase()),
+            crypto: KeyStoreCrypto {
+                cipher: CIPHER.to_string(),
+                cipherparams: CipherParams {
+                    iv: hex::encode(iv),
+                },
+                mac: hex::encode(mac(&derived_key, &ciphertext)),
+                ciphertext: hex::encode(ciphertext),
+                kdf: KDF_SCRYPT.to_string(),
+                kdfparams,
+            },
+        })
+    }
+
+    /// Decrypt the private key with the password
+    ///
+    /// # Params
+    /// - `password` password used to encrypt the key
+    ///
+    /// # Returns
+    /// private key. The memory is zeroed when the key is dropped
+    pub fn decrypt(&self, password: &str) -> VdrResult<Zeroizing<Vec<u8>>> {
+        if self.version != VERSION {
+            return Err(invalid_keystore(&format!(
+                "Unsupported version {}",
+                self.version
+            )));
+        }
+        if self.crypto.cipher != CIPHER {
+            return Err(invalid_keystore(&format!(
+                "Unsupported cipher {}",
+                self.crypto.cipher
+            )));
+        }
+
+        let derived_key = self
+            .crypto
+            .kdfparams
+            .derive_key(&self.crypto.kdf, password)?;
+        let ciphertext = decode_hex("ciphertext", &self.crypto.ciphertext)?;
+        if mac(&derived_key, &ciphertext) != decode_hex("mac", &self.crypto.mac)? {
+            return Err(invalid_keystore(
+                "MAC mismatch: the password is wrong or the file is corrupted",
+            ));
+        }
+
+        let iv = decode_hex("iv", &self.crypto.cipherparams.iv)?;
+        let mut private_key = Zeroizing::new(ciphertext);
+        apply_cipher(&derived_key, &iv, &mut private_key)?;
+        Ok(private_key)
+    }
+
+    /// Account of the encrypted key, if it is set in the file
+    pub fn address(&self) -> Option<Address> {
+        self.address
+            .as_deref()
+            .map(|address| Address::from(address.to_lowercase().as_str()))
+    }
+
+    pub fn from_json(keystore: &str) -> VdrResult<KeyStore> {
+        serde_json::from_str(keystore).map_err(|err| invalid_keystore(&err.to_string()))
+    }
+
+    pub fn to_json(&self) -> VdrResult<String> {
+        serde_json::to_string(self).map_err(|err| invalid_keystore(&err.to_string()))
+    }
+}
+
+impl KdfParams {
+    fn derive_key(&self, kdf: &str, password: &str) -> VdrResult<Zeroizing<Vec<u8>>> {
+        match (kdf, self) {
+            (
+                KDF_SCRYPT,
+                KdfParams::Scrypt {
+                    dklen,
+                    n,
+                    p,
+                    r,
+                    salt,
+                },
+            ) => {
+                if !n.is_power_of_two() {
+                    return Err(invalid_keystore("scrypt `n` must be a power of two"));
+                }
+                let mut key = derived_key_buffer(*dklen)?;
+                let params = scrypt::Params::new(n.trailing_zeros() as u8, *r, *p, key.len())
+                    .map_err(|err| invalid_keystore(&err.to_string()))?;
+                scrypt::scrypt(
+                    password.as_bytes(),
+                    &decode_hex("salt", salt)?,
+                    &params,
+                    &mut key,
+                )
+                .map_err(|err| invalid_keystore(&err.to_string()))?;
+                Ok(key)
+            }
+            (
+                KDF_PBKDF2,
+                KdfParams::Pbkdf2 {
+                    c,
+                    dklen,
+                    prf,
+                    salt,
+                },
+            ) if prf == PBKDF2_PRF => {
+                let mut key = derived_key_buffer(*dklen)?;
+                pbkdf2::pbkdf2_hmac::<Sha256>(
+                    password.as_bytes(),
+                    &decode_hex("salt", salt)?,
+                    *c,
+                    &mut key,
+                );
+                Ok(key)
+            }
+            _ => Err(invalid_keystore(&format!(
+                "Unsupported key derivation function {}",
+                kdf
+            ))),
+        }
+    }
+}
+
+fn derived_key_buffer(dklen: u32) -> VdrResult<Zeroizing<Vec<u8>>> {
+    if dklen < DKLEN {
+        return Err(invalid_keystore(&format!(
+            "Derived key length must be at least {} bytes",
+            DKLEN
+        )));
+    }
+    Ok(Zeroizing::new(vec![0u8; dklen as usize]))
+}
+
+fn apply_cipher(derived_key: &[u8], iv: &[u8], data: &mut [u8]) -> VdrResult<()> {
+    let mut cipher = Aes128Ctr::new_from_slices(&derived_key[..16], iv)
+        .map_err(|_| invalid_keystore("Invalid cipher initialization vector"))?;
+    cipher.apply_keystream(data);
+    Ok(())
+}
+
+fn mac(derived_key: &[u8], ciphertext: &[u8]) -> Vec<u8> {
+    let mut hasher = Keccak256::new();
+    hasher.update(&derived_key[16..32]);
+    hasher.update(ciphertext);
+    hasher.finalize().to_vec()
+}
+
+fn decode_hex(name: &str, value: &str) -> VdrResult<Vec<u8>> {
+    hex::decode(value.trim_start_matches("0x"))
+        .map_err(|_| invalid_keystore(&format!("Invalid hex value of `{}`", name)))
+}
+
+fn invalid_keystore(msg: &str) -> VdrError {
+    let vdr_error = VdrError::SignerInvalidKeystore(msg.to_string());
+
+    warn!("Error: {} during processing keystore", vdr_error);
+
+    vdr_error
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use std::fs;
+
+    pub const ETHSIGNER_KEY_PATH: &str = "../network/config/ethsigner/key";
+    pub const ETHSIGNER_PASSWORD: &str = "Password1";
+    pub const ETHSIGNER_PRIVATE_KEY: &str =
+        "797bbe0373132e8c5483515b68ecbb6d3581b56f0205b653ad2b30a559e83891";
+    pub const ETHSIGNER_ACCOUNT: &str = "0x9b790656b9ec0db1936ed84b3bea605873558198";
+
+    const PBKDF2_KEYSTORE: &str = r#"{"version":3,"id":"3198bc9c-6672-5ab3-d995-4942343ae5b6","crypto":{"ciphertext":"5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46","cipherparams":{"iv":"6087dab2f9fdbbfaddc31a909735c1e6"},"cipher":"aes-128-ctr","kdf":"pbkdf2","kdfparams":{"c":262144,"dklen":32,"prf":"hmac-sha256","salt":"ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"},"mac":"517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"}}"#;
+    const PBKDF2_PRIVATE_KEY: &str =
+        "7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d";
+
+    fn ethsigner_keystore() -> KeyStore {
+        KeyStore::from_json(&fs::read_to_string(ETHSIGNER_KEY_PATH).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn decrypt_scrypt_keystore_test() {
+        let keystore = ethsigner_keystore();
+
+        let private_key = keystore.decrypt(ETHSIGNER_PASSWORD).unwrap();
+
+        assert_eq!(ETHSIGNER_PRIVATE_KEY, hex::encode(private_key.as_slice()));
+        assert_eq!(Some(Address::from(ETHSIGNER_ACCOUNT)), keystore.address());
+    }
+
+    #[test]
+    fn decrypt_pbkdf2_keystore_test() {
+        let keystore = KeyStore::from_json(PBKDF2_KEYSTORE).unwrap();
+
+        let private_key = keystore.decrypt("testpassword").unwrap();
+
+        assert_eq!(PBKDF2_PRIVATE_KEY, hex::encode(private_key.as_slice()));
+    }
+
+    #[test]
+    fn decrypt_wrong_password_test() {
+        let err = ethsigner_keystore().decrypt("wrong").unwrap_err();
+
+        assert!(matches!(err, VdrError::SignerInvalidKeystore { .. }));
+    }
+
+    #[test]
+    fn encrypt_decrypt_test() {
+        let private_key = hex::decode(ETHSIGNER_PRIVATE_KEY).unwrap();
+        let address = Address::from(ETHSIGNER_ACCOUNT);
+
+        let keystore = KeyStore::encrypt(&private_key, &address, "password").unwrap();
+        let keystore = KeyStore::from_json(&keystore.to_json().unwrap()).unwrap();
+
+        assert_eq!(Some(address), keystore.address());
+        assert_eq!(
+            private_key,
+            keystore.decrypt("password").unwrap().as_slice()
+        );
+    }
+}
@@ -1,3 +1,89 @@
+#[cfg(feature = "basic_signer")]
 pub mod basic_signer;
+#[cfg(feature = "basic_signer")]
+pub mod directory_signer;
+#[cfg(feature = "basic_signer")]
+mod hd_key;
+#[cfg(feature = "basic_signer")]
+pub mod keystore;
+#[cfg(not(feature = "wasm"))]
+pub mod remote_signer;
 
+#[cfg(feature = "basic_signer")]
 pub use basic_signer::{BasicSigner, KeyPair};
+#[cfg(feature = "basic_signer")]
+pub use directory_signer::DirectorySigner;
+#[cfg(feature = "basic_signer")]
+pub use keystore::KeyStore;
+#[cfg(not(feature = "wasm"))]
+pub use remote_signer::RemoteSigner;
+
+use crate::{
+    error::{VdrError, VdrResult},
+    types::{Address, SignatureData, Transaction, TransactionEndorsingData},
+};
+use async_trait::async_trait;
+use log::warn;
+use std::fmt::Debug;
+
+#[cfg(test)]
+use mockall::automock;
+
+/// Signer holding account keys and producing signatures of transactions and endorsing data
+///
+/// Implement the trait to plug custom key storage (hardware wallets, KMS, remote signing services) into
+/// [crate::LedgerClient::sign_and_submit].
+#[cfg_attr(test, automock)]
+#[cfg_attr(not(feature = "wasm"), async_trait)]
+#[cfg_attr(feature = "wasm", async_trait(?Send))]
+pub trait Signer: Sync + Send + Debug {
+    /// Get accounts which keys are managed by the signer
+    ///
+    /// # Returns
+    /// list of account addresses
+    async fn accounts(&self) -> VdrResult<Vec<Address>>;
+
+    /// Sign 32 bytes hash with the key of the account
+    ///
+    /// # Params
+    /// - `hash` 32 bytes hash to sign
+    /// - `account` [Address] account which key must be used for signing
+    ///
+    /// # Returns
+    /// recoverable ECDSA signature
+    async fn sign_hash(&self, hash: &[u8], account: &Address) -> VdrResult<SignatureData>;
+
+    /// Sign the transaction on behalf of its sender
+    ///
+    /// # Params
+    /// - `transaction` [Transaction] transaction to sign
+    ///
+    /// # Returns
+    /// signature to set into the transaction
+    async fn sign_transaction(&self, transaction: &Transaction) -> VdrResult<SignatureData> {
+        let from = transaction.from.as_ref().ok_or_else(|| {
+            let vdr_error =
+                VdrError::ClientInvalidTransaction("Transaction sender is not set".to_string());
+
+            warn!("Error: {} during signing transaction", vdr_error);
+
+            vdr_error
+        })?;
+        self.sign_hash(&transaction.get_signing_bytes()?, from)
+            .await
+    }
+
+    /// Sign the endorsing data on behalf of its author
+    ///
+    /// # Params
+    /// - `data` [TransactionEndorsingData] endorsing data to sign
+    ///
+    /// # Returns
+    /// signature to set into the endorsing data
+    async fn sign_endorsing_data(
+        &self,
+        data: &TransactionEndorsingData,
+    ) -> VdrResult<SignatureData> {
+        self.sign_hash(&data.get_signing_bytes()?, &data.from).await
+    }
+}
@@ -0,0 +1,339 @@
+use crate::{
+    client::{implementation::web3::transport::build_http, ConnectionConfig},
+    error::{VdrError, VdrResult},
+    signer::Signer,
+    types::{Address, SignatureData, Transaction},
+};
+
+use async_trait::async_trait;
+use log::warn;
+use log_derive::{logfn, logfn_inputs};
+use serde_json::{json, Value};
+use std::fmt::{Debug, Formatter};
+use web3::{transports::Http, Transport};
+
+/// Offset of `v` value in signatures returned by `eth_sign`
+const ETH_SIGN_V_OFFSET: u64 = 27;
+
+/// Signer delegating signing to a remote JSON-RPC signing service compatible with EthSigner / Web3Signer
+///
+/// Keys stay in the signing service: transactions are signed with `eth_signTransaction` and messages with `eth_sign`.
+///
+/// Note that `eth_sign` signs EIP-191 personal messages (`"\x19Ethereum Signed Message:\n" + len + message`), so
+/// the service cannot sign raw hashes. Endorsing data must be signed by its author with another signer.
+pub struct RemoteSigner {
+    signer_address: String,
+    transport: Http,
+}
+
+impl RemoteSigner {
+    /// Create signer connected to the signing service
+    ///
+    /// # Params
+    ///  - `signer_address`: [String] - HTTP RPC endpoint of the signing service
+    ///
+    /// # Returns
+    ///  signer: [RemoteSigner] - signer connected to the service
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn new(signer_address: &str) -> VdrResult<RemoteSigner> {
+        RemoteSigner::new_with_config(signer_address, &ConnectionConfig::default())
+    }
+
+    /// Create signer connected to the signing service over authenticated HTTP connection
+    ///
+    /// # Params
+    ///  - `signer_address`: [String] - HTTP RPC endpoint of the signing service
+    ///  - `config`: [ConnectionConfig] - headers, authentication and TLS settings of the connection
+    ///
+    /// # Returns
+    ///  signer: [RemoteSigner] - signer connected to the service
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn new_with_config(
+        signer_address: &str,
+        config: &ConnectionConfig,
+    ) -> VdrResult<RemoteSigner> {
+        if config.token_provider.is_some() {
+            return Err(VdrError::ClientInvalidState(
+                "Token provider is not supported by the remote signer".to_string(),
+            ));
+        }
+        Ok(RemoteSigner {
+            signer_address: signer_address.to_string(),
+            transport: build_http(signer_address, config, None)?,
+        })
+    }
+
+    /// Sign the message with the key of the account using `eth_sign`
+    ///
+    /// # Params
+    /// - `message` message to sign. The signing service prefixes it according to EIP-191 before hashing
+    /// - `account` [Address] account which key must be used for signing
+    ///
+    /// # Returns
+    /// recoverable ECDSA signature
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub async fn sign_message(
+        &self,
+        message: &[u8],
+        account: &Address,
+    ) -> VdrResult<SignatureData> {
+        let response = self
+            .request(
+                "eth_sign",
+                vec![
+                    json!(account.as_ref()),
+                    json!(format!("0x{}", hex::encode(message))),
+                ],
+            )
+            .await?;
+        let signature = decode_hex_response("eth_sign", &response)?;
+        if signature.len() != 65 {
+            return Err(invalid_response(
+                "eth_sign",
+                "signature must be 65 bytes long",
+            ));
+        }
+        let v = signature[64] as u64;
+        Ok(SignatureData {
+            recovery_id: v.checked_sub(ETH_SIGN_V_OFFSET).unwrap_or(v),
+            signature: signature[..64].to_vec(),
+        })
+    }
+
+    async fn request(&self, method: &str, params: Vec<Value>) -> VdrResult<Value> {
+        self.transport.execute(method, params).await.map_err(|err| {
+            let vdr_error = VdrError::SignerUnexpectedError(format!(
+                "Remote signer request `{}` failed: {}",
+                method,
+                VdrError::from(err)
+            ));
+
+            warn!("Error: {} during requesting remote signer", vdr_error);
+
+            vdr_error
+        })
+    }
+}
+
+#[cfg_attr(not(feature = "wasm"), async_trait)]
+#[cfg_attr(feature = "wasm", async_trait(?Send))]
+impl Signer for RemoteSigner {
+    async fn accounts(&self) -> VdrResult<Vec<Address>> {
+        let response = self.request("eth_accounts", vec![]).await?;
+        let accounts: Vec<String> = serde_json::from_value(response)
+            .map_err(|err| invalid_response("eth_accounts", &err.to_string()))?;
+        Ok(accounts
+            .iter()
+            .map(|account| Address::from(account.to_lowercase().as_str()))
+            .collect())
+    }
+
+    async fn sign_hash(&self, _hash: &[u8], _account: &Address) -> VdrResult<SignatureData> {
+        let vdr_error = VdrError::SignerUnexpectedError(
+            "Remote signer signs only transactions and EIP-191 messages, not raw hashes"
+                .to_string(),
+        );
+
+        warn!("Error: {} during signing hash", vdr_error);
+
+        Err(vdr_error)
+    }
+
+    async fn sign_transaction(&self, transaction: &Transaction) -> VdrResult<SignatureData> {
+        let response = self
+            .request(
+                "eth_signTransaction",
+                vec![transaction.to_signing_request()?],
+            )
+            .await?;
+        let encoded = decode_hex_response("eth_signTransaction", &response)?;
+        transaction.signature_from_encoded(&encoded)
+    }
+}
+
+fn decode_hex_response(method: &str, response: &Value) -> VdrResult<Vec<u8>> {
+    response
+        .as_str()
+        .and_then(|value| hex::decode(value.trim_start_matches("0x")).ok())
+        .ok_or_else(|| invalid_response(method, "hex string expected"))
+}
+
+fn invalid_response(method: &str, msg: &str) -> VdrError {
+    let vdr_error = VdrError::ClientInvalidResponse(format!(
+        "Remote signer returned invalid `{}` result: {}",
+        method, msg
+    ));
+
+    warn!(
+        "Error: {} during processing remote signer response",
+        vdr_error
+    );
+
+    vdr_error
+}
+
+impl Debug for RemoteSigner {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            r#"RemoteSigner {{ signer_address: {} }}"#,
+            self.signer_address
+        )
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::types::{TransactionEnvelope, TransactionType};
+    use rstest::rstest;
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    const ACCOUNT: &str = "0x9b790656b9ec0db1936ed84b3bea605873558198";
+
+    /// Start HTTP server standing in for the signing service: it answers a single JSON-RPC request with the result
+    /// and passes the received request to the returned channel
+    fn mock_signer(result: Value) -> (RemoteSigner, mpsc::Receiver<Value>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            let mut body = vec![0u8; content_length];
+            reader.read_exact(&mut body).unwrap();
+            let request: Value = serde_json::from_slice(&body).unwrap();
+
+            let response =
+                json!({"jsonrpc": "2.0", "id": request["id"], "result": result}).to_string();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                response.len(),
+                response
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+            sender.send(request).unwrap();
+        });
+        (RemoteSigner::new(&address).unwrap(), receiver)
+    }
+
+    fn transaction(envelope: TransactionEnvelope, nonce: u64) -> Transaction {
+        let mut transaction = Transaction::new(
+            TransactionType::Write,
+            Some(Address::from(ACCOUNT)),
+            Address::from("0x0000000000000000000000000000000000003333"),
+            1337,
+            vec![1, 2, 3],
+            Some(nonce),
+            None,
+        );
+        transaction.set_envelope(envelope);
+        transaction
+    }
+
+    fn signature() -> SignatureData {
+        SignatureData {
+            recovery_id: 1,
+            signature: [[1u8; 32], [2u8; 32]].concat(),
+        }
+    }
+
+    fn encoded_signed(transaction: &Transaction) -> Value {
+        let mut transaction = transaction.clone();
+        transaction.set_signature(signature());
+        json!(format!("0x{}", hex::encode(transaction.encode().unwrap())))
+    }
+
+    #[async_std::test]
+    async fn remote_signer_accounts_test() {
+        let (signer, _) = mock_signer(json!(["0x9B790656B9EC0DB1936ED84B3BEA605873558198"]));
+
+        let accounts = signer.accounts().await.unwrap();
+
+        assert_eq!(vec![Address::from(ACCOUNT)], accounts);
+    }
+
+    #[rstest]
+    #[case::legacy(TransactionEnvelope::Legacy, None)]
+    #[case::dynamic_fee(TransactionEnvelope::DynamicFee, Some("0x2"))]
+    #[async_std::test]
+    async fn remote_signer_sign_transaction_test(
+        #[case] envelope: TransactionEnvelope,
+        #[case] type_: Option<&str>,
+    ) {
+        let transaction = transaction(envelope, 1);
+        let (signer, requests) = mock_signer(encoded_signed(&transaction));
+
+        let signature = signer.sign_transaction(&transaction).await.unwrap();
+
+        assert_eq!(self::signature(), signature);
+        let request = requests.recv().unwrap();
+        assert_eq!("eth_signTransaction", request["method"]);
+        assert_eq!(ACCOUNT, request["params"][0]["from"]);
+        assert_eq!("0x1", request["params"][0]["nonce"]);
+        assert_eq!("0x539", request["params"][0]["chainId"]);
+        assert_eq!(type_, request["params"][0]["type"].as_str());
+    }
+
+    #[async_std::test]
+    async fn remote_signer_sign_different_transaction_test() {
+        let transaction = transaction(TransactionEnvelope::Legacy, 1);
+        let other = self::transaction(TransactionEnvelope::Legacy, 2);
+        let (signer, _) = mock_signer(encoded_signed(&other));
+
+        let err = signer.sign_transaction(&transaction).await.unwrap_err();
+
+        assert!(matches!(err, VdrError::ClientInvalidTransaction { .. }));
+    }
+
+    #[async_std::test]
+    async fn remote_signer_sign_message_test() {
+        let (signer, requests) = mock_signer(json!(format!(
+            "0x{}{}1c",
+            hex::encode([1u8; 32]),
+            hex::encode([2u8; 32])
+        )));
+
+        let signature = signer
+            .sign_message(b"message", &Address::from(ACCOUNT))
+            .await
+            .unwrap();
+
+        assert_eq!(self::signature(), signature);
+        let request = requests.recv().unwrap();
+        assert_eq!(json!([ACCOUNT, "0x6d657373616765"]), request["params"]);
+    }
+
+    #[async_std::test]
+    async fn remote_signer_sign_hash_not_supported_test() {
+        let signer = RemoteSigner::new("http://127.0.0.1:8545").unwrap();
+
+        let err = signer
+            .sign_hash(&[0; 32], &Address::from(ACCOUNT))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, VdrError::SignerUnexpectedError { .. }));
+    }
+}
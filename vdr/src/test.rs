@@ -8,7 +8,7 @@ use crate::{
         did::{did_indy_registry, types::did_doc::test::did_doc, DidRecord, DID, ETHR_DID_METHOD},
     },
     signer::basic_signer::{test::basic_signer, BasicSigner},
-    types::{Address, SignatureData, Transaction},
+    types::{Address, SignatureData, Transaction, TransactionReceipt},
     LedgerClient,
 };
 
@@ -18,15 +18,11 @@ mod helpers {
 
     pub async fn sign_and_submit_transaction(
         client: &LedgerClient,
-        mut transaction: Transaction,
+        transaction: Transaction,
         signer: &BasicSigner,
-    ) -> String {
-        let sign_bytes = transaction.get_signing_bytes().unwrap();
-        let from = transaction.from.as_ref().unwrap();
-        let signature = signer.sign(&sign_bytes, from.as_ref()).unwrap();
-        transaction.set_signature(signature);
-        let block_hash = client.submit_transaction(&transaction).await.unwrap();
-        client.get_receipt(&block_hash).await.unwrap()
+    ) -> TransactionReceipt {
+        let hash = client.sign_and_submit(&transaction, signer).await.unwrap();
+        client.get_receipt(&hash).await.unwrap()
     }
 
     pub fn sign_endorsing_data(
@@ -43,11 +39,7 @@ mod helpers {
         signer: &BasicSigner,
         mut data: TransactionEndorsingData,
     ) {
-        let signature = signer
-            .sign(&data.get_signing_bytes().unwrap(), data.from.as_ref())
-            .unwrap();
-
-        data.set_signature(signature);
+        client.sign_endorsing_data(&mut data, signer).await.unwrap();
 
         let transaction = endorsing::build_endorsement_transaction(client, &TRUSTEE_ACCOUNT, &data)
             .await
@@ -61,7 +53,7 @@ mod helpers {
         assignee_account: &Address,
         role_to_assign: &Role,
         signer: &BasicSigner,
-    ) -> String {
+    ) -> TransactionReceipt {
         let transaction = role_control::build_assign_role_transaction(
             client,
             &TRUSTEE_ACCOUNT,
@@ -177,6 +169,10 @@ mod did_indy {
 mod did_ethr {
     use super::*;
     use crate::{
+        client::{
+            client::test::{contracts, CONFIG, TEST_NETWORK},
+            implementation::web3::client::Web3Client,
+        },
         contracts::{
             did::{
                 did_ethr_registry,
@@ -191,8 +187,53 @@ mod did_ethr {
             ETHR_DID_METHOD,
         },
         did_ethr_registry::test::{public_key_2, public_key_3},
-        Address, LedgerClient, Validity,
+        Address, LedgerClient, LedgerClientBuilder, Validity,
     };
+    use futures::StreamExt;
+
+    const WS_NODE_ADDRESS: &str = "ws://127.0.0.1:8546";
+
+    async fn ws_client() -> LedgerClient {
+        LedgerClientBuilder::new()
+            .set_chain_id(CONFIG.chain_id)
+            .set_client(Box::new(Web3Client::new_ws(WS_NODE_ADDRESS).await.unwrap()))
+            .set_contract_configs(&contracts())
+            .set_network(TEST_NETWORK)
+            .build()
+            .unwrap()
+    }
+
+    #[async_std::test]
+    async fn demo_subscribe_did_events() {
+        let mut signer = basic_signer();
+        let client = ws_client().await;
+        let identity = super::helpers::create_trustee(&mut signer, &client).await;
+
+        let did = DID::build(ETHR_DID_METHOD, None, identity.as_ref());
+
+        // subscribe to DID events
+        let query = did_ethr_registry::build_get_did_events_query(&client, &did, None, None)
+            .await
+            .unwrap();
+        let mut events = client.subscribe_events(&query).await.unwrap();
+
+        // add service attribute to DID
+        let transaction = did_ethr_registry::build_did_set_attribute_transaction(
+            &client,
+            &identity,
+            &did,
+            &service(),
+            &validity(),
+        )
+        .await
+        .unwrap();
+        super::helpers::sign_and_submit_transaction(&client, transaction, &signer).await;
+
+        // receive pushed DID event
+        let event = events.next().await.unwrap().unwrap();
+        let event = did_ethr_registry::parse_did_event_response(&client, &event).unwrap();
+        let _attribute: DidDocAttribute = event.try_into().unwrap();
+    }
 
     async fn endorse_set_did_attribute(
         client: &LedgerClient,
@@ -587,14 +628,20 @@ mod credential_definition {
 
 mod role {
     use super::*;
-    use crate::role_control;
+    use crate::{
+        contracts::{
+            auth::{RoleAssigned, RoleEvents},
+            parse_receipt_events, LedgerEvent,
+        },
+        role_control,
+    };
 
     async fn revoke_role(
         client: &LedgerClient,
         revokee_account: &Address,
         role_to_revoke: &Role,
         signer: &BasicSigner,
-    ) -> String {
+    ) -> TransactionReceipt {
         let mut transaction = role_control::build_revoke_role_transaction(
             client,
             &TRUSTEE_ACCOUNT,
@@ -643,7 +690,18 @@ mod role {
         let client = client();
         let role_to_assign = Role::Endorser;
 
-        super::helpers::assign_role(&client, &assignee_account, &role_to_assign, &signer).await;
+        let receipt =
+            super::helpers::assign_role(&client, &assignee_account, &role_to_assign, &signer).await;
+
+        let events = parse_receipt_events(&client, &receipt).unwrap();
+        assert_eq!(
+            vec![LedgerEvent::Role(RoleEvents::RoleAssigned(RoleAssigned {
+                role: role_to_assign,
+                account: assignee_account.clone(),
+                sender: TRUSTEE_ACCOUNT.clone(),
+            }))],
+            events
+        );
 
         let assigned_role = build_and_submit_get_role_transaction(&client, &assignee_account).await;
         assert_eq!(role_to_assign, assigned_role);
@@ -680,7 +738,7 @@ mod validator {
         client: &LedgerClient,
         new_validator_address: &Address,
         signer: &BasicSigner,
-    ) -> String {
+    ) -> TransactionReceipt {
         let transaction = validator_control::build_add_validator_transaction(
             &client,
             &TRUSTEE_ACCOUNT,
@@ -695,7 +753,7 @@ mod validator {
         client: &LedgerClient,
         validator_address: &Address,
         signer: &BasicSigner,
-    ) -> String {
+    ) -> TransactionReceipt {
         // write
         let transaction = validator_control::build_remove_validator_transaction(
             &client,
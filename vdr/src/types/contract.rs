@@ -10,7 +10,7 @@ use log_derive::{logfn, logfn_inputs};
 use serde::{Deserialize, Serialize};
 
 /// Contract configuration
-#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ContractConfig {
     /// Address of deployed contract
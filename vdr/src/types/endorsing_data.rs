@@ -1,4 +1,5 @@
 use ethabi::Uint;
+use log::warn;
 use log_derive::{logfn, logfn_inputs};
 use serde::{Deserialize, Serialize};
 use sha3::Digest;
@@ -62,6 +63,53 @@ impl TransactionEndorsingData {
         self.signature = Some(signature_data)
     }
 
+    /// Recover the account which signed the endorsing data from its signature
+    ///
+    /// # Returns
+    /// account of the signer
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn recover_signer(&self) -> VdrResult<Address> {
+        let signature = self.signature.as_ref().ok_or_else(|| {
+            VdrError::ClientInvalidEndorsementData("Missing signature".to_string())
+        })?;
+        signature
+            .recover(&self.get_signing_bytes()?)
+            .map_err(|err| {
+                let vdr_error = VdrError::ClientInvalidEndorsementData(format!(
+                    "Unable to recover endorsing data signer: {}",
+                    err
+                ));
+
+                warn!(
+                    "Error: {} during recovering endorsing data signer",
+                    vdr_error
+                );
+
+                vdr_error
+            })
+    }
+
+    /// Check that the endorsing data is signed by its author, so the endorser can reject forged requests before
+    /// submitting the transaction
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn verify(&self) -> VdrResult<()> {
+        let signer = self.recover_signer()?;
+        if !signer.as_ref().eq_ignore_ascii_case(self.from.as_ref()) {
+            let vdr_error = VdrError::ClientInvalidEndorsementData(format!(
+                "Endorsing data is signed by {} instead of the author {}",
+                signer.as_ref(),
+                self.from.as_ref()
+            ));
+
+            warn!("Error: {} during verifying endorsing data", vdr_error);
+
+            return Err(vdr_error);
+        }
+        Ok(())
+    }
+
     /// Serialize transaction endorsement as JSON string
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
@@ -165,3 +213,59 @@ impl TransactionEndorsingDataBuilder {
         })
     }
 }
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::{
+        client::client::test::{CONFIG, TRUSTEE_ACCOUNT},
+        types::signature::test::{sign, OTHER_PRIVATE_KEY, TRUSTEE_PRIVATE_KEY},
+    };
+
+    fn endorsing_data(private_key: &str) -> TransactionEndorsingData {
+        let mut data = TransactionEndorsingData {
+            to: CONFIG.contracts.indy_did_registry.address.clone(),
+            from: TRUSTEE_ACCOUNT.clone(),
+            method: "createDid".to_string(),
+            endorsing_method: "createDidSigned".to_string(),
+            params: vec![ContractParam::String("document".to_string())],
+            ..TransactionEndorsingData::default()
+        };
+        data.set_signature(sign(&data.get_signing_bytes().unwrap(), private_key));
+        data
+    }
+
+    #[test]
+    fn verify_signed_endorsing_data() {
+        let data = endorsing_data(TRUSTEE_PRIVATE_KEY);
+
+        assert_eq!(*TRUSTEE_ACCOUNT, data.recover_signer().unwrap());
+        data.verify().unwrap();
+    }
+
+    #[test]
+    fn verify_endorsing_data_signed_by_other_account() {
+        let err = endorsing_data(OTHER_PRIVATE_KEY).verify().unwrap_err();
+
+        assert!(matches!(err, VdrError::ClientInvalidEndorsementData { .. }));
+    }
+
+    #[test]
+    fn verify_forged_endorsing_data() {
+        let mut data = endorsing_data(TRUSTEE_PRIVATE_KEY);
+        data.params = vec![ContractParam::String("forged".to_string())];
+
+        let err = data.verify().unwrap_err();
+
+        assert!(matches!(err, VdrError::ClientInvalidEndorsementData { .. }));
+    }
+
+    #[test]
+    fn recover_signer_of_unsigned_endorsing_data() {
+        let data = TransactionEndorsingData::default();
+
+        let err = data.recover_signer().unwrap_err();
+
+        assert!(matches!(err, VdrError::ClientInvalidEndorsementData { .. }));
+    }
+}
@@ -3,24 +3,86 @@ use crate::{
     Address, LedgerClient, VdrError, VdrResult,
 };
 use ethabi::{Hash, RawLog};
+use futures::Stream;
 use log::warn;
 use log_derive::{logfn, logfn_inputs};
 use serde_derive::{Deserialize, Serialize};
-use std::fmt::Debug;
+use sha3::{Digest, Keccak256};
+use std::{collections::BTreeMap, fmt::Debug, pin::Pin};
+
+/// Max number of event log topics: event signature and up to three indexed event parameters
+pub const EVENT_TOPICS_COUNT: usize = 4;
+
+/// Filter of the event log topic at some position:
+///     `None` matches any value, otherwise the topic must be equal to one of the listed hex values
+pub type TopicFilter = Option<Vec<String>>;
 
 /// Definition of query object to query logged events from the ledger
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", from = "EventQueryRepr")]
 pub struct EventQuery {
-    pub address: Address,
+    /// Addresses of contracts emitted events. Events of any of the listed contracts are matched
+    pub addresses: Vec<Address>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub from_block: Option<Block>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub to_block: Option<Block>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_signature: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub event_filter: Option<String>,
+    /// Topic filters by position (topic 0 is the event signature)
+    #[serde(default)]
+    pub topics: [TopicFilter; EVENT_TOPICS_COUNT],
+}
+
+impl EventQuery {
+    /// Create query matching all events emitted by the contract
+    ///
+    /// # Params
+    ///  - `address`: [Address] - address of the contract
+    ///
+    /// # Returns
+    ///  query: [EventQuery] - event query
+    pub fn new(address: &Address) -> EventQuery {
+        EventQuery {
+            addresses: vec![address.to_owned()],
+            from_block: None,
+            to_block: None,
+            topics: Default::default(),
+        }
+    }
+}
+
+/// Serialized event query. Besides the current shape, accepts the previous one with single contract `address`,
+/// `eventSignature` and `eventFilter` (the first indexed event parameter)
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EventQueryRepr {
+    #[serde(default)]
+    addresses: Vec<Address>,
+    from_block: Option<Block>,
+    to_block: Option<Block>,
+    #[serde(default)]
+    topics: [TopicFilter; EVENT_TOPICS_COUNT],
+    address: Option<Address>,
+    event_signature: Option<String>,
+    event_filter: Option<String>,
+}
+
+impl From<EventQueryRepr> for EventQuery {
+    fn from(repr: EventQueryRepr) -> Self {
+        let mut query = EventQuery {
+            addresses: repr.addresses,
+            from_block: repr.from_block,
+            to_block: repr.to_block,
+            topics: repr.topics,
+        };
+        query.addresses.extend(repr.address);
+        if let Some(event_signature) = repr.event_signature {
+            query.topics[0] = Some(vec![event_signature]);
+        }
+        if let Some(event_filter) = repr.event_filter {
+            query.topics[1] = Some(vec![event_filter]);
+        }
+        query
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -28,8 +90,8 @@ pub(crate) struct EventQueryBuilder {
     contract: String,
     from_block: Option<Block>,
     to_block: Option<Block>,
-    event_signature: Option<String>,
-    event_filter: Option<String>,
+    events: Vec<String>,
+    topics: BTreeMap<usize, Vec<String>>,
 }
 
 impl EventQueryBuilder {
@@ -60,18 +122,33 @@ impl EventQueryBuilder {
         self
     }
 
+    /// Add event (by name) to match. Event signature is resolved from the ABI of the query contract.
+    /// Events added one after another are matched with OR.
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
-    #[allow(unused)]
-    pub fn set_event_signature(mut self, event_signature: String) -> EventQueryBuilder {
-        self.event_signature = Some(event_signature);
+    pub fn add_event(mut self, event: &str) -> EventQueryBuilder {
+        self.events.push(event.to_string());
         self
     }
 
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
-    pub fn set_event_filer(mut self, event_filter: String) -> EventQueryBuilder {
-        self.event_filter = Some(event_filter);
+    #[allow(unused)]
+    pub fn set_event_signature(self, event_signature: String) -> EventQueryBuilder {
+        self.set_topic(0, vec![event_signature])
+    }
+
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub fn set_event_filer(self, event_filter: String) -> EventQueryBuilder {
+        self.set_topic(1, vec![event_filter])
+    }
+
+    /// Set values of the topic at the given position. Values are matched with OR.
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub fn set_topic(mut self, position: usize, values: Vec<String>) -> EventQueryBuilder {
+        self.topics.insert(position, values);
         self
     }
 
@@ -79,12 +156,40 @@ impl EventQueryBuilder {
     #[logfn_inputs(Trace)]
     pub fn build(self, client: &LedgerClient) -> VdrResult<EventQuery> {
         let contract = client.contract(&self.contract)?;
+
+        let mut topics: [TopicFilter; EVENT_TOPICS_COUNT] = Default::default();
+        for (position, values) in self.topics {
+            let topic = topics.get_mut(position).ok_or_else(|| {
+                let vdr_error = VdrError::CommonInvalidData(format!(
+                    "Invalid event topic position {}. Event log has at most {} topics",
+                    position, EVENT_TOPICS_COUNT
+                ));
+
+                warn!("Error: {} during building event query", vdr_error);
+
+                vdr_error
+            })?;
+            *topic = Some(values);
+        }
+
+        if !self.events.is_empty() {
+            let signatures = self
+                .events
+                .iter()
+                .map(|event| {
+                    contract
+                        .event(event)
+                        .map(|event| hex::encode(event.signature()))
+                })
+                .collect::<VdrResult<Vec<String>>>()?;
+            topics[0] = Some(signatures);
+        }
+
         let query = EventQuery {
-            address: contract.address().to_owned(),
+            addresses: vec![contract.address().to_owned()],
             from_block: self.from_block,
             to_block: self.to_block,
-            event_signature: self.event_signature,
-            event_filter: self.event_filter,
+            topics,
         };
         Ok(query)
     }
@@ -154,11 +259,24 @@ impl EventParser {
     }
 }
 
+/// Stream of log events pushed by the ledger for an event subscription
+pub type EventStream = Pin<Box<dyn Stream<Item = VdrResult<EventLog>> + Send>>;
+
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct EventLog {
     pub topics: Vec<Hash>,
     pub data: Vec<u8>,
     pub block: Block,
+    /// Hash of the block including the log
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_hash: Option<Hash>,
+    /// Index of the log in the block
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub log_index: Option<u64>,
+    /// Hash of the transaction emitted the log
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction_hash: Option<Hash>,
 }
 
 impl EventLog {
@@ -172,6 +290,91 @@ impl EventLog {
                 .collect(),
             data,
             block: Block::from(block),
+            block_hash: None,
+            log_index: None,
+            transaction_hash: None,
         }
     }
+
+    /// Get hash of the log identifying its content and position in the ledger
+    ///
+    /// # Returns
+    ///  hash: [Vec] - keccak256 hash of the block hash, log index, transaction hash, topics and data
+    pub fn hash(&self) -> Vec<u8> {
+        let mut hasher = Keccak256::new();
+        hasher.update(self.block.value().to_be_bytes());
+        if let Some(block_hash) = self.block_hash.as_ref() {
+            hasher.update(block_hash);
+        }
+        if let Some(log_index) = self.log_index {
+            hasher.update(log_index.to_be_bytes());
+        }
+        if let Some(transaction_hash) = self.transaction_hash.as_ref() {
+            hasher.update(transaction_hash);
+        }
+        for topic in self.topics.iter() {
+            hasher.update(topic);
+        }
+        hasher.update(&self.data);
+        hasher.finalize().to_vec()
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use serde_json::json;
+
+    const ADDRESS: &str = "0x0000000000000000000000000000000000003333";
+
+    #[test]
+    fn deserialize_event_query() {
+        let query: EventQuery = serde_json::from_value(json!({
+            "addresses": [ADDRESS],
+            "fromBlock": 10,
+            "topics": [["0x1"], null, ["0x2", "0x3"], null]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            EventQuery {
+                addresses: vec![Address::from(ADDRESS)],
+                from_block: Some(Block::from(10)),
+                to_block: None,
+                topics: [
+                    Some(vec!["0x1".to_string()]),
+                    None,
+                    Some(vec!["0x2".to_string(), "0x3".to_string()]),
+                    None
+                ],
+            },
+            query
+        );
+    }
+
+    #[test]
+    fn deserialize_event_query_of_previous_format() {
+        let query: EventQuery = serde_json::from_value(json!({
+            "address": ADDRESS,
+            "toBlock": 20,
+            "eventSignature": "0x1",
+            "eventFilter": "0x2"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            EventQuery {
+                addresses: vec![Address::from(ADDRESS)],
+                from_block: None,
+                to_block: Some(Block::from(20)),
+                topics: [
+                    Some(vec!["0x1".to_string()]),
+                    Some(vec!["0x2".to_string()]),
+                    None,
+                    None
+                ],
+            },
+            query
+        );
+    }
 }
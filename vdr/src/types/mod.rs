@@ -2,17 +2,24 @@ mod address;
 mod contract;
 mod endorsing_data;
 mod event_query;
+mod receipt;
 mod signature;
 mod status;
 pub(crate) mod transaction;
+mod verification;
 
 pub use address::Address;
 pub use contract::{ContractConfig, ContractParam, ContractSpec};
 pub use endorsing_data::TransactionEndorsingData;
-pub use event_query::{EventLog, EventQuery};
+pub use event_query::{EventLog, EventQuery, EventStream, TopicFilter, EVENT_TOPICS_COUNT};
+pub use receipt::{ReceiptLog, TransactionReceipt};
 pub use signature::SignatureData;
-pub use status::{PingStatus, Status};
-pub use transaction::{Block, BlockDetails, Nonce, Transaction, TransactionType};
+pub use status::{PingStatus, SimulationResult, Status, TransactionStatus};
+pub use transaction::{
+    AccessListItem, Block, BlockDetails, BlockTag, FeeHistory, Nonce, Transaction,
+    TransactionEnvelope, TransactionFees, TransactionType,
+};
+pub use verification::{VerificationMismatch, VerificationReport};
 
 pub(crate) use contract::{ContractEvent, ContractOutput, MethodStringParam, MethodUintBytesParam};
 pub(crate) use endorsing_data::TransactionEndorsingDataBuilder;
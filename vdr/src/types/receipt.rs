@@ -0,0 +1,82 @@
+use crate::types::{transaction::Block, Address, EventLog};
+use ethabi::Hash;
+use serde_derive::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+
+/// Receipt of the transaction included into a block
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionReceipt {
+    /// Hash of the transaction
+    pub transaction_hash: Vec<u8>,
+    /// Hash of the block including the transaction
+    pub block_hash: Vec<u8>,
+    /// Number of the block including the transaction
+    pub block_number: u64,
+    /// Whether the transaction was executed successfully
+    pub success: bool,
+    /// Amount of gas used by the transaction
+    pub gas_used: u64,
+    /// Logs emitted by the transaction
+    pub logs: Vec<ReceiptLog>,
+    /// Revert reason of the failed transaction.
+    /// Decoded using known contract errors when returned by [LedgerClient::get_receipt](crate::LedgerClient::get_receipt)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revert_reason: Option<String>,
+}
+
+/// Log emitted by the transaction
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ReceiptLog {
+    /// Address of the contract emitted the log
+    pub address: Address,
+    pub topics: Vec<Hash>,
+    pub data: Vec<u8>,
+}
+
+impl TransactionReceipt {
+    /// Get transaction logs as event logs which can be parsed with contract specific functions
+    /// (for example [did_ethr_registry::parse_did_event_response](crate::did_ethr_registry::parse_did_event_response))
+    pub fn event_logs(&self) -> Vec<EventLog> {
+        self.logs
+            .iter()
+            .map(|log| log.event_log(self.block_number))
+            .collect()
+    }
+
+    /// Hash of the transaction outcome: transaction hash, block, execution status, used gas and emitted logs
+    ///
+    /// Nodes which executed the transaction in the same block with the same result return receipts with equal hashes.
+    /// Revert reason is not included as its formatting is node specific.
+    pub fn hash(&self) -> Vec<u8> {
+        let mut hasher = Keccak256::new();
+        hasher.update(&self.transaction_hash);
+        hasher.update(&self.block_hash);
+        hasher.update(self.block_number.to_be_bytes());
+        hasher.update([self.success as u8]);
+        hasher.update(self.gas_used.to_be_bytes());
+        for log in self.logs.iter() {
+            hasher.update(log.address.as_ref().to_lowercase().as_bytes());
+            hasher.update([log.topics.len() as u8]);
+            for topic in log.topics.iter() {
+                hasher.update(topic.as_bytes());
+            }
+            hasher.update((log.data.len() as u64).to_be_bytes());
+            hasher.update(&log.data);
+        }
+        hasher.finalize().to_vec()
+    }
+}
+
+impl ReceiptLog {
+    pub(crate) fn event_log(&self, block_number: u64) -> EventLog {
+        EventLog {
+            topics: self.topics.clone(),
+            data: self.data.clone(),
+            block: Block::from(block_number),
+            block_hash: None,
+            log_index: None,
+            transaction_hash: None,
+        }
+    }
+}
@@ -1,6 +1,13 @@
-use crate::{types::ContractParam, VdrError};
+use crate::{
+    types::{Address, ContractParam},
+    VdrError, VdrResult,
+};
+use ethers_core::types::{RecoveryMessage, Signature as EthSignature, H256, U256};
 use serde_derive::{Deserialize, Serialize};
 
+/// Offset of `v` value in Ethereum signatures: `v = recovery_id + 27`
+const V_OFFSET: u64 = 27;
+
 /// Definition of recoverable ECDSA signature
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SignatureData {
@@ -22,6 +29,31 @@ impl SignatureData {
     pub(crate) fn s(&self) -> SignatureS {
         SignatureS(self.signature[32..].to_vec())
     }
+
+    /// Recover the account which key produced the signature of the hash (secp256k1 `ecrecover`)
+    pub(crate) fn recover(&self, hash: &[u8]) -> VdrResult<Address> {
+        if hash.len() != 32 {
+            return Err(VdrError::CommonInvalidData(
+                "Signed hash must be 32 bytes long".to_string(),
+            ));
+        }
+        if self.signature.len() != 64 || self.recovery_id > 1 {
+            return Err(VdrError::CommonInvalidData(
+                "Signature must be 64 bytes long and have recovery id 0 or 1".to_string(),
+            ));
+        }
+        let signature = EthSignature {
+            r: U256::from_big_endian(&self.signature[..32]),
+            s: U256::from_big_endian(&self.signature[32..]),
+            v: self.recovery_id + V_OFFSET,
+        };
+        let account = signature
+            .recover(RecoveryMessage::Hash(H256::from_slice(hash)))
+            .map_err(|err| {
+                VdrError::CommonInvalidData(format!("Unable to recover signer: {}", err))
+            })?;
+        Ok(Address::from(format!("{:?}", account).as_str()))
+    }
 }
 
 #[derive(Debug)]
@@ -31,7 +63,7 @@ impl TryFrom<&SignatureV> for ContractParam {
     type Error = VdrError;
 
     fn try_from(value: &SignatureV) -> Result<Self, Self::Error> {
-        Ok(ContractParam::Uint((value.0 + 27).into()))
+        Ok(ContractParam::Uint((value.0 + V_OFFSET).into()))
     }
 }
 
@@ -56,3 +88,47 @@ impl TryFrom<&SignatureS> for ContractParam {
         Ok(ContractParam::FixedBytes(value.0.to_vec()))
     }
 }
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use ethers_core::k256::ecdsa::SigningKey;
+
+    pub const TRUSTEE_PRIVATE_KEY: &str =
+        "8bbbb1b345af56b560a5b20bd4b0ed1cd8cc9958a16262bc75118453cb546df7";
+    pub const OTHER_PRIVATE_KEY: &str =
+        "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+    pub fn sign(hash: &[u8], private_key: &str) -> SignatureData {
+        let key = SigningKey::from_slice(&hex::decode(private_key).unwrap()).unwrap();
+        let (signature, recovery_id) = key.sign_prehash_recoverable(hash).unwrap();
+        SignatureData {
+            recovery_id: recovery_id.to_byte() as u64,
+            signature: signature.to_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn recover_test() {
+        let hash = [7u8; 32];
+
+        let account = sign(&hash, TRUSTEE_PRIVATE_KEY).recover(&hash).unwrap();
+
+        assert_eq!(
+            Address::from("0xf0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5"),
+            account
+        );
+    }
+
+    #[test]
+    fn recover_invalid_signature_test() {
+        let signature = SignatureData {
+            recovery_id: 2,
+            signature: vec![1; 64],
+        };
+
+        let err = signature.recover(&[7u8; 32]).unwrap_err();
+
+        assert!(matches!(err, VdrError::CommonInvalidData { .. }));
+    }
+}
@@ -35,3 +35,43 @@ impl PingStatus {
         }
     }
 }
+
+/// Status of the transaction sent to the ledger
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TransactionStatus {
+    /// Transaction is not included into a block yet
+    Pending,
+    /// Transaction is included into a block and executed successfully
+    Included {
+        block_number: u64,
+        confirmations: u64,
+    },
+    /// Transaction is included into a block but its execution failed
+    Reverted {
+        block_number: u64,
+        revert_reason: String,
+    },
+    /// Waiting for the transaction was cancelled by the caller
+    Cancelled,
+}
+
+/// Result of write transaction simulation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SimulationResult {
+    /// Transaction is going to be executed successfully
+    Success {
+        /// output data of the contract method. Can be parsed with the contract specific parse functions
+        output: Vec<u8>,
+    },
+    /// Transaction is going to be reverted
+    Reverted {
+        /// revert reason decoded using errors of the known contracts
+        revert_reason: String,
+    },
+}
+
+impl SimulationResult {
+    pub fn is_success(&self) -> bool {
+        matches!(self, SimulationResult::Success { .. })
+    }
+}
@@ -1,12 +1,15 @@
-use ethabi::Uint;
+use ethabi::{Hash, Uint};
 use ethereum::{
-    EnvelopedEncodable, LegacyTransaction, LegacyTransactionMessage, TransactionAction,
-    TransactionSignature as EthTransactionSignature,
+    AccessListItem as EthAccessListItem, EIP1559Transaction, EIP1559TransactionMessage,
+    EIP2930Transaction, EIP2930TransactionMessage, EnvelopedDecodable, EnvelopedEncodable,
+    LegacyTransaction, LegacyTransactionMessage, TransactionAction,
+    TransactionSignature as EthTransactionSignature, TransactionV2,
 };
 use ethereum_types::{H160, H256, U256};
 use log::warn;
 use log_derive::{logfn, logfn_inputs};
 use serde_derive::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use std::{fmt::Debug, str::FromStr};
 
 use crate::{
@@ -27,6 +30,101 @@ pub enum TransactionType {
     Write,
 }
 
+/// Envelope format used to sign and encode write transactions
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum TransactionEnvelope {
+    /// Legacy transaction with EIP-155 replay protection
+    #[default]
+    Legacy,
+    /// EIP-2930 transaction (type 1) containing an access list
+    AccessList,
+    /// EIP-1559 transaction (type 2) with dynamic fee. Requires network with London fork enabled
+    DynamicFee,
+}
+
+/// Gas and fee parameters of the transaction
+///
+/// Values which are not set fall back to the free gas network defaults: max available gas limit and zero fees.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionFees {
+    /// max amount of gas the transaction can use
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_limit: Option<u64>,
+    /// gas price in wei. Used by `Legacy` and `AccessList` transactions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_price: Option<u64>,
+    /// max total fee per gas in wei (base fee + priority fee). Used by `DynamicFee` transactions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_fee_per_gas: Option<u64>,
+    /// max priority fee per gas in wei paid to the block producer. Used by `DynamicFee` transactions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_priority_fee_per_gas: Option<u64>,
+}
+
+/// Fees paid in the latest blocks of the network
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeHistory {
+    /// base fee per gas of the requested blocks including the next (pending) block
+    pub base_fee_per_gas: Vec<u64>,
+    /// priority fee per gas paid in the requested blocks at the requested percentile
+    pub priority_fee_per_gas: Vec<u64>,
+}
+
+impl FeeHistory {
+    /// Get base fee per gas of the next block
+    pub fn next_base_fee(&self) -> u64 {
+        self.base_fee_per_gas.last().copied().unwrap_or_default()
+    }
+
+    /// Get max priority fee per gas paid in the requested blocks
+    pub fn max_priority_fee(&self) -> u64 {
+        self.priority_fee_per_gas
+            .iter()
+            .max()
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// Entry of EIP-2930 access list: contract address and storage keys the transaction is going to access
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessListItem {
+    pub address: Address,
+    pub storage_keys: Vec<Hash>,
+}
+
+impl AccessListItem {
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    pub fn new(address: Address, storage_keys: Vec<Vec<u8>>) -> VdrResult<AccessListItem> {
+        let storage_keys = storage_keys
+            .iter()
+            .map(|key| {
+                if key.len() != H256::len_bytes() {
+                    let vdr_error = VdrError::ClientInvalidTransaction(format!(
+                        "Invalid access list storage key {:?}: expected {} bytes",
+                        key,
+                        H256::len_bytes()
+                    ));
+
+                    warn!("Error: {} during creating access list item", vdr_error);
+
+                    return Err(vdr_error);
+                }
+                Ok(H256::from_slice(key))
+            })
+            .collect::<VdrResult<Vec<Hash>>>()?;
+
+        Ok(AccessListItem {
+            address,
+            storage_keys,
+        })
+    }
+}
+
 /// Definition of transaction object to send on the ledger
 #[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Transaction {
@@ -50,6 +148,18 @@ pub struct Transaction {
     pub signature: Option<SignatureData>,
     /// transaction hash
     pub hash: Option<Vec<u8>>,
+    /// envelope format used to sign and encode the transaction
+    #[serde(default)]
+    pub envelope: TransactionEnvelope,
+    /// gas and fee parameters
+    #[serde(default)]
+    pub fees: TransactionFees,
+    /// addresses and storage keys the transaction is going to access (ignored for `Legacy` envelope)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub access_list: Vec<AccessListItem>,
+    /// block at which read transaction is executed. The latest block is used if not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_tag: Option<BlockTag>,
 }
 
 impl Transaction {
@@ -73,23 +183,55 @@ impl Transaction {
             nonce,
             signature,
             hash: None,
+            envelope: TransactionEnvelope::default(),
+            fees: TransactionFees::default(),
+            access_list: Vec::new(),
+            block_tag: None,
         }
     }
 
     /// Get transaction bytes which are need to be signed by the sender before the submitting on the ledger
+    ///
+    /// The signing hash depends on the transaction envelope: EIP-155 hash for `Legacy` transactions and
+    /// hash of the type prefixed payload for `AccessList` (EIP-2930) and `DynamicFee` (EIP-1559) transactions
     #[logfn(Info)]
     #[logfn_inputs(Debug)]
     pub fn get_signing_bytes(&self) -> VdrResult<Vec<u8>> {
-        let eth_transaction: LegacyTransactionMessage = LegacyTransactionMessage {
-            nonce: self.get_nonce()?,
-            gas_price: *GAS_PRICE,
-            gas_limit: *GAS_LIMIT,
-            action: TransactionAction::Call(self.get_to()?),
-            value: Default::default(),
-            input: self.data.clone(),
-            chain_id: Some(self.chain_id),
+        let hash = match self.envelope {
+            TransactionEnvelope::Legacy => LegacyTransactionMessage {
+                nonce: self.get_nonce()?,
+                gas_price: self.get_gas_price(),
+                gas_limit: self.get_gas_limit(),
+                action: TransactionAction::Call(self.get_to()?),
+                value: Default::default(),
+                input: self.data.clone(),
+                chain_id: Some(self.chain_id),
+            }
+            .hash(),
+            TransactionEnvelope::AccessList => EIP2930TransactionMessage {
+                chain_id: self.chain_id,
+                nonce: self.get_nonce()?,
+                gas_price: self.get_gas_price(),
+                gas_limit: self.get_gas_limit(),
+                action: TransactionAction::Call(self.get_to()?),
+                value: Default::default(),
+                input: self.data.clone(),
+                access_list: self.get_access_list()?,
+            }
+            .hash(),
+            TransactionEnvelope::DynamicFee => EIP1559TransactionMessage {
+                chain_id: self.chain_id,
+                nonce: self.get_nonce()?,
+                max_priority_fee_per_gas: self.get_max_priority_fee_per_gas(),
+                max_fee_per_gas: self.get_max_fee_per_gas(),
+                gas_limit: self.get_gas_limit(),
+                action: TransactionAction::Call(self.get_to()?),
+                value: Default::default(),
+                input: self.data.clone(),
+                access_list: self.get_access_list()?,
+            }
+            .hash(),
         };
-        let hash = eth_transaction.hash();
         Ok(hash.as_bytes().to_vec())
     }
 
@@ -100,22 +242,220 @@ impl Transaction {
         self.signature = Some(signature_data)
     }
 
+    /// Recover the account which signed the transaction from its signature
+    ///
+    /// # Returns
+    /// account of the signer
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn recover_signer(&self) -> VdrResult<Address> {
+        let signature = self
+            .signature
+            .as_ref()
+            .ok_or_else(|| VdrError::ClientInvalidTransaction("Missing signature".to_string()))?;
+        signature
+            .recover(&self.get_signing_bytes()?)
+            .map_err(|err| {
+                let vdr_error = VdrError::ClientInvalidTransaction(format!(
+                    "Unable to recover transaction signer: {}",
+                    err
+                ));
+
+                warn!("Error: {} during recovering transaction signer", vdr_error);
+
+                vdr_error
+            })
+    }
+
+    /// Check that the transaction is signed by its sender
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn verify(&self) -> VdrResult<()> {
+        let from = self.from.as_ref().ok_or_else(|| {
+            VdrError::ClientInvalidTransaction("Transaction sender is not set".to_string())
+        })?;
+        let signer = self.recover_signer()?;
+        if !signer.as_ref().eq_ignore_ascii_case(from.as_ref()) {
+            let vdr_error = VdrError::ClientInvalidTransaction(format!(
+                "Transaction is signed by {} instead of the sender {}",
+                signer.as_ref(),
+                from.as_ref()
+            ));
+
+            warn!("Error: {} during verifying transaction", vdr_error);
+
+            return Err(vdr_error);
+        }
+        Ok(())
+    }
+
+    /// Set envelope format used to sign and encode the transaction
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn set_envelope(&mut self, envelope: TransactionEnvelope) {
+        self.envelope = envelope
+    }
+
+    /// Set gas and fee parameters of the transaction
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn set_fees(&mut self, fees: TransactionFees) {
+        self.fees = fees
+    }
+
+    /// Set block at which read transaction is executed to query historical state of the ledger
+    ///     (for example, the validator set or a role of an account at the given block)
+    #[logfn(Info)]
+    #[logfn_inputs(Debug)]
+    pub fn set_block_tag(&mut self, block_tag: BlockTag) {
+        self.block_tag = Some(block_tag)
+    }
+
     /// Encode transaction as bytes
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
     pub fn encode(&self) -> VdrResult<Vec<u8>> {
-        let transaction = LegacyTransaction {
-            nonce: self.get_nonce()?,
-            gas_price: *GAS_PRICE,
-            gas_limit: *GAS_LIMIT,
-            action: TransactionAction::Call(self.get_to()?),
-            value: Default::default(),
-            input: self.data.clone(),
-            signature: self.get_transaction_signature()?,
+        let transaction = match self.envelope {
+            TransactionEnvelope::Legacy => TransactionV2::Legacy(LegacyTransaction {
+                nonce: self.get_nonce()?,
+                gas_price: self.get_gas_price(),
+                gas_limit: self.get_gas_limit(),
+                action: TransactionAction::Call(self.get_to()?),
+                value: Default::default(),
+                input: self.data.clone(),
+                signature: self.get_transaction_signature()?,
+            }),
+            TransactionEnvelope::AccessList => {
+                let (odd_y_parity, r, s) = self.get_typed_transaction_signature()?;
+                TransactionV2::EIP2930(EIP2930Transaction {
+                    chain_id: self.chain_id,
+                    nonce: self.get_nonce()?,
+                    gas_price: self.get_gas_price(),
+                    gas_limit: self.get_gas_limit(),
+                    action: TransactionAction::Call(self.get_to()?),
+                    value: Default::default(),
+                    input: self.data.clone(),
+                    access_list: self.get_access_list()?,
+                    odd_y_parity,
+                    r,
+                    s,
+                })
+            }
+            TransactionEnvelope::DynamicFee => {
+                let (odd_y_parity, r, s) = self.get_typed_transaction_signature()?;
+                TransactionV2::EIP1559(EIP1559Transaction {
+                    chain_id: self.chain_id,
+                    nonce: self.get_nonce()?,
+                    max_priority_fee_per_gas: self.get_max_priority_fee_per_gas(),
+                    max_fee_per_gas: self.get_max_fee_per_gas(),
+                    gas_limit: self.get_gas_limit(),
+                    action: TransactionAction::Call(self.get_to()?),
+                    value: Default::default(),
+                    input: self.data.clone(),
+                    access_list: self.get_access_list()?,
+                    odd_y_parity,
+                    r,
+                    s,
+                })
+            }
         };
         Ok(transaction.encode().to_vec())
     }
 
+    /// Build `eth_signTransaction` request object asking a remote signer to sign the transaction as is
+    ///
+    /// All fields (nonce, gas and fees) are set explicitly, so the signer does not fill them on its own.
+    pub(crate) fn to_signing_request(&self) -> VdrResult<Value> {
+        let from = self.from.as_ref().ok_or_else(|| {
+            VdrError::ClientInvalidTransaction("Transaction sender is not set".to_string())
+        })?;
+        let mut request = json!({
+            "from": from.as_ref(),
+            "to": self.to.as_ref(),
+            "nonce": format!("{:#x}", self.get_nonce()?),
+            "gas": format!("{:#x}", self.get_gas_limit()),
+            "value": "0x0",
+            "data": format!("0x{}", hex::encode(&self.data)),
+            "chainId": format!("{:#x}", self.chain_id),
+        });
+        let access_list: Vec<Value> = self
+            .access_list
+            .iter()
+            .map(|item| {
+                json!({
+                    "address": item.address.as_ref(),
+                    "storageKeys": item.storage_keys,
+                })
+            })
+            .collect();
+        let fields = match self.envelope {
+            TransactionEnvelope::Legacy => json!({
+                "gasPrice": format!("{:#x}", self.get_gas_price()),
+            }),
+            TransactionEnvelope::AccessList => json!({
+                "type": "0x1",
+                "gasPrice": format!("{:#x}", self.get_gas_price()),
+                "accessList": access_list,
+            }),
+            TransactionEnvelope::DynamicFee => json!({
+                "type": "0x2",
+                "maxFeePerGas": format!("{:#x}", self.get_max_fee_per_gas()),
+                "maxPriorityFeePerGas": format!("{:#x}", self.get_max_priority_fee_per_gas()),
+                "accessList": access_list,
+            }),
+        };
+        if let (Some(request), Value::Object(fields)) = (request.as_object_mut(), fields) {
+            request.extend(fields);
+        }
+        Ok(request)
+    }
+
+    /// Extract the signature from the encoded signed transaction returned by a remote signer
+    ///
+    /// The signer must sign exactly this transaction: the transaction encoded with the extracted signature has to
+    /// match the returned bytes.
+    pub(crate) fn signature_from_encoded(&self, encoded: &[u8]) -> VdrResult<SignatureData> {
+        let signed = TransactionV2::decode(encoded).map_err(|err| {
+            VdrError::ClientInvalidTransaction(format!(
+                "Unable to decode signed transaction: {:?}",
+                err
+            ))
+        })?;
+        let (recovery_id, r, s) = match signed {
+            TransactionV2::Legacy(transaction) => (
+                transaction.signature.standard_v(),
+                *transaction.signature.r(),
+                *transaction.signature.s(),
+            ),
+            TransactionV2::EIP2930(transaction) => {
+                (transaction.odd_y_parity as u8, transaction.r, transaction.s)
+            }
+            TransactionV2::EIP1559(transaction) => {
+                (transaction.odd_y_parity as u8, transaction.r, transaction.s)
+            }
+        };
+        let signature = SignatureData {
+            recovery_id: recovery_id as u64,
+            signature: [r.as_bytes(), s.as_bytes()].concat(),
+        };
+
+        let mut transaction = self.clone();
+        transaction.set_signature(signature.clone());
+        if transaction.encode()? != encoded {
+            let vdr_error = VdrError::ClientInvalidTransaction(
+                "Signed transaction does not match the requested one".to_string(),
+            );
+
+            warn!(
+                "Error: {} during extracting transaction signature",
+                vdr_error
+            );
+
+            return Err(vdr_error);
+        }
+        Ok(signature)
+    }
+
     /// Serialize transaction as JSON string
     #[logfn(Trace)]
     #[logfn_inputs(Trace)]
@@ -179,6 +519,70 @@ impl Transaction {
         })?;
         Ok(signature)
     }
+
+    /// Get signature parts of typed (EIP-2930, EIP-1559) transaction: `v` is encoded as y-parity bit
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    fn get_typed_transaction_signature(&self) -> VdrResult<(bool, H256, H256)> {
+        let signature = self
+            .signature
+            .as_ref()
+            .ok_or_else(|| VdrError::ClientInvalidTransaction("Missing signature".to_string()))?;
+
+        if signature.signature.len() != 64 || signature.recovery_id > 1 {
+            return Err(VdrError::ClientInvalidTransaction(
+                "Unable to create transaction signature".to_string(),
+            ));
+        }
+
+        Ok((
+            signature.v().0 == 1,
+            H256::from_slice(&signature.r().0),
+            H256::from_slice(&signature.s().0),
+        ))
+    }
+
+    #[logfn(Trace)]
+    #[logfn_inputs(Trace)]
+    fn get_access_list(&self) -> VdrResult<Vec<EthAccessListItem>> {
+        self.access_list
+            .iter()
+            .map(|item| {
+                let address = H160::from_str(item.address.as_ref()).map_err(|_| {
+                    VdrError::ClientInvalidTransaction(format!(
+                        "Invalid access list address {:?}",
+                        item.address
+                    ))
+                })?;
+                Ok(EthAccessListItem {
+                    address,
+                    storage_keys: item.storage_keys.clone(),
+                })
+            })
+            .collect()
+    }
+
+    fn get_gas_limit(&self) -> U256 {
+        self.fees.gas_limit.map(U256::from).unwrap_or(*GAS_LIMIT)
+    }
+
+    fn get_gas_price(&self) -> U256 {
+        self.fees.gas_price.map(U256::from).unwrap_or(*GAS_PRICE)
+    }
+
+    fn get_max_fee_per_gas(&self) -> U256 {
+        self.fees
+            .max_fee_per_gas
+            .map(U256::from)
+            .unwrap_or(*GAS_PRICE)
+    }
+
+    fn get_max_priority_fee_per_gas(&self) -> U256 {
+        self.fees
+            .max_priority_fee_per_gas
+            .map(U256::from)
+            .unwrap_or(*GAS_PRICE)
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -266,28 +670,36 @@ impl TransactionBuilder {
             .function(&self.method)?
             .encode_input(&self.params)?;
 
-        let nonce = match self.type_ {
-            TransactionType::Write => {
-                let from = self.from.as_ref().ok_or_else(|| {
-                    VdrError::ClientInvalidTransaction(
-                        "Transaction `sender` is not set".to_string(),
-                    )
-                })?;
-                Some(client.get_transaction_count(from).await?)
-            }
-            TransactionType::Read => None,
+        if self.type_ == TransactionType::Write && self.from.is_none() {
+            return Err(VdrError::ClientInvalidTransaction(
+                "Transaction `sender` is not set".to_string(),
+            ));
+        }
+
+        let envelope = match self.type_ {
+            TransactionType::Write => client.transaction_envelope().clone(),
+            TransactionType::Read => TransactionEnvelope::default(),
         };
 
-        let transaction = Transaction {
+        let mut transaction = Transaction {
             type_: self.type_,
             from: self.from,
             to: contract.address().clone(),
             chain_id: client.chain_id(),
             data,
-            nonce,
+            nonce: None,
             signature: None,
             hash: None,
+            envelope,
+            fees: TransactionFees::default(),
+            access_list: Vec::new(),
+            block_tag: None,
         };
+        if let (TransactionType::Write, Some(from)) = (&transaction.type_, &transaction.from) {
+            // estimate fees first, so the nonce is not reserved for the transaction which fails to be built
+            transaction.fees = client.transaction_fees(&transaction).await?;
+            transaction.nonce = Some(client.reserve_nonce(from).await?);
+        }
         Ok(transaction)
     }
 }
@@ -361,6 +773,16 @@ impl TransactionParser {
     }
 }
 
+/// Block at which the state of the ledger is read
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BlockTag {
+    /// Block number
+    Number(u64),
+    /// Block hash
+    Hash(Vec<u8>),
+}
+
 /// Wrapper structure for transaction block number
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Block(u64);
@@ -462,6 +884,7 @@ pub mod test {
             data: vec![],
             signature: None,
             hash: None,
+            ..Transaction::default()
         }
     }
 
@@ -475,12 +898,15 @@ pub mod test {
             data: vec![],
             signature: None,
             hash: None,
+            ..Transaction::default()
         }
     }
 
     #[cfg(test)]
     pub mod txn_test {
         use super::*;
+        use crate::types::signature::test::{sign, OTHER_PRIVATE_KEY, TRUSTEE_PRIVATE_KEY};
+        use rstest::rstest;
 
         #[async_std::test]
         async fn get_to_invalid() {
@@ -523,6 +949,255 @@ pub mod test {
                 get_sig_err,  | VdrError::ClientInvalidTransaction { .. }
             ));
         }
+
+        fn signed_transaction(envelope: TransactionEnvelope) -> Transaction {
+            Transaction {
+                envelope,
+                signature: Some(SignatureData {
+                    recovery_id: 1,
+                    signature: vec![1; 64],
+                }),
+                ..write_transaction()
+            }
+        }
+
+        #[test]
+        fn signing_bytes_depend_on_envelope() {
+            let legacy = signed_transaction(TransactionEnvelope::Legacy)
+                .get_signing_bytes()
+                .unwrap();
+            let access_list = signed_transaction(TransactionEnvelope::AccessList)
+                .get_signing_bytes()
+                .unwrap();
+            let dynamic_fee = signed_transaction(TransactionEnvelope::DynamicFee)
+                .get_signing_bytes()
+                .unwrap();
+
+            assert_ne!(legacy, access_list);
+            assert_ne!(legacy, dynamic_fee);
+            assert_ne!(access_list, dynamic_fee);
+        }
+
+        #[test]
+        fn signing_bytes_include_fees() {
+            let transaction = signed_transaction(TransactionEnvelope::DynamicFee);
+            let mut transaction_with_fees = transaction.clone();
+            transaction_with_fees.set_fees(TransactionFees {
+                max_fee_per_gas: Some(2_000_000_000),
+                max_priority_fee_per_gas: Some(1_000_000_000),
+                ..TransactionFees::default()
+            });
+
+            assert_ne!(
+                transaction.get_signing_bytes().unwrap(),
+                transaction_with_fees.get_signing_bytes().unwrap()
+            );
+        }
+
+        #[test]
+        fn encode_typed_transactions() {
+            let legacy = signed_transaction(TransactionEnvelope::Legacy)
+                .encode()
+                .unwrap();
+            let access_list = signed_transaction(TransactionEnvelope::AccessList)
+                .encode()
+                .unwrap();
+            let dynamic_fee = signed_transaction(TransactionEnvelope::DynamicFee)
+                .encode()
+                .unwrap();
+
+            // legacy transaction is a RLP list, typed transactions are prefixed with the type byte
+            assert!(legacy[0] >= 0xc0);
+            assert_eq!(1, access_list[0]);
+            assert_eq!(2, dynamic_fee[0]);
+        }
+
+        /// Signed EIP-2930 transaction from Ethereum mainnet (test vector of ethers-rs)
+        const ACCESS_LIST_TRANSACTION: &str = "01f90126018223ff850a02ffee00830f4240940000000000a8fb09af944ab3baf7a9b3e1ab29d880b876200200001525000000000b69ffb300000000557b933a7c2c45672b610f8954a3deb39a51a8cae53ec727dbdeb9e2d5456c3be40cff031ab40a55724d5c9c618a2152e99a45649a3b8cf198321f46720b722f4ec38f99ba3bb1303258d2e816e6a95b25647e01bd0967c1b9599fa3521939871d1d0888f845d694724d5c9c618a2152e99a45649a3b8cf198321f46c0d694720b722f4ec38f99ba3bb1303258d2e816e6a95bc0d69425647e01bd0967c1b9599fa3521939871d1d0888c001a08323efae7b9993bd31a58da7924359d24b5504aa2b33194fcc5ae206e65d2e62a054ce201e3b4b5cd38eb17c56ee2f9111b2e164efcd57b3e70fa308a0a51f7014";
+        const ACCESS_LIST_SIGNING_HASH: &str =
+            "23f3f4e09e30b5de557870b1e811f8300a805ebe786fbbbf16e124e79565905b";
+        const ACCESS_LIST_SENDER: &str = "0xe9c790e8fde820ded558a4771b72eec916c04763";
+
+        /// Signed EIP-1559 transaction from Goerli (test vector of ethers-rs)
+        const DYNAMIC_FEE_TRANSACTION: &str = "02f86f05418459682f008459682f098301a0cf9411d7c2ab0d4aa26b7d8502f6a7ef6844908495c28084e5225381c001a01a8d7bef47f6155cbdf13d57107fc577fd52880fa2862b1a50d47641f8839419a03279bbf73fde76de83440d04b9d97f3809fec8617d3557ee40ac3e0edc391514";
+        const DYNAMIC_FEE_SIGNING_HASH: &str =
+            "390acafd379a4758f7090afb9e9ad43bd4cb2d603d87e07827d4c90fa1c0c682";
+        const DYNAMIC_FEE_SENDER: &str = "0xe66b278fa9fbb181522f6916ec2f6d66ab846e04";
+
+        fn vector_signature(r: &str, s: &str) -> Option<SignatureData> {
+            Some(SignatureData {
+                recovery_id: 1,
+                signature: [hex::decode(r).unwrap(), hex::decode(s).unwrap()].concat(),
+            })
+        }
+
+        fn access_list_transaction() -> Transaction {
+            Transaction {
+                type_: TransactionType::Write,
+                to: Address::from("0x0000000000a8fb09af944ab3baf7a9b3e1ab29d8"),
+                nonce: Some(9215),
+                chain_id: 1,
+                data: hex::decode("200200001525000000000b69ffb300000000557b933a7c2c45672b610f8954a3deb39a51a8cae53ec727dbdeb9e2d5456c3be40cff031ab40a55724d5c9c618a2152e99a45649a3b8cf198321f46720b722f4ec38f99ba3bb1303258d2e816e6a95b25647e01bd0967c1b9599fa3521939871d1d0888").unwrap(),
+                signature: vector_signature(
+                    "8323efae7b9993bd31a58da7924359d24b5504aa2b33194fcc5ae206e65d2e62",
+                    "54ce201e3b4b5cd38eb17c56ee2f9111b2e164efcd57b3e70fa308a0a51f7014",
+                ),
+                envelope: TransactionEnvelope::AccessList,
+                fees: TransactionFees {
+                    gas_limit: Some(1_000_000),
+                    gas_price: Some(43_000_000_000),
+                    ..TransactionFees::default()
+                },
+                access_list: [
+                    "0x724d5c9c618a2152e99a45649a3b8cf198321f46",
+                    "0x720b722f4ec38f99ba3bb1303258d2e816e6a95b",
+                    "0x25647e01bd0967c1b9599fa3521939871d1d0888",
+                ]
+                .iter()
+                .map(|address| AccessListItem::new(Address::from(*address), vec![]).unwrap())
+                .collect(),
+                ..Transaction::default()
+            }
+        }
+
+        fn dynamic_fee_transaction() -> Transaction {
+            Transaction {
+                type_: TransactionType::Write,
+                to: Address::from("0x11d7c2ab0d4aa26b7d8502f6a7ef6844908495c2"),
+                nonce: Some(65),
+                chain_id: 5,
+                data: hex::decode("e5225381").unwrap(),
+                signature: vector_signature(
+                    "1a8d7bef47f6155cbdf13d57107fc577fd52880fa2862b1a50d47641f8839419",
+                    "3279bbf73fde76de83440d04b9d97f3809fec8617d3557ee40ac3e0edc391514",
+                ),
+                envelope: TransactionEnvelope::DynamicFee,
+                fees: TransactionFees {
+                    gas_limit: Some(106_703),
+                    max_fee_per_gas: Some(1_500_000_009),
+                    max_priority_fee_per_gas: Some(1_500_000_000),
+                    ..TransactionFees::default()
+                },
+                ..Transaction::default()
+            }
+        }
+
+        #[rstest]
+        #[case::access_list(
+            access_list_transaction(),
+            ACCESS_LIST_TRANSACTION,
+            ACCESS_LIST_SIGNING_HASH,
+            ACCESS_LIST_SENDER
+        )]
+        #[case::dynamic_fee(
+            dynamic_fee_transaction(),
+            DYNAMIC_FEE_TRANSACTION,
+            DYNAMIC_FEE_SIGNING_HASH,
+            DYNAMIC_FEE_SENDER
+        )]
+        fn encode_typed_transaction_vectors(
+            #[case] transaction: Transaction,
+            #[case] expected_encoded: &str,
+            #[case] expected_signing_hash: &str,
+            #[case] expected_sender: &str,
+        ) {
+            let encoded = transaction.encode().unwrap();
+
+            assert_eq!(
+                expected_signing_hash,
+                hex::encode(transaction.get_signing_bytes().unwrap())
+            );
+            assert_eq!(expected_encoded, hex::encode(&encoded));
+            assert_eq!(
+                Address::from(expected_sender),
+                transaction.recover_signer().unwrap()
+            );
+            // typed transactions carry y-parity (0 or 1) as `v` instead of `27 + parity` / EIP-155 value
+            let odd_y_parity = match TransactionV2::decode(&encoded).unwrap() {
+                TransactionV2::EIP2930(transaction) => transaction.odd_y_parity,
+                TransactionV2::EIP1559(transaction) => transaction.odd_y_parity,
+                TransactionV2::Legacy(_) => panic!("Typed transaction is expected"),
+            };
+            assert!(odd_y_parity);
+        }
+
+        #[test]
+        fn access_list_item_with_invalid_storage_key() {
+            let err = AccessListItem::new(Address::from(ACCESS_LIST_SENDER), vec![vec![1; 31]])
+                .unwrap_err();
+
+            assert!(matches!(err, VdrError::ClientInvalidTransaction { .. }));
+        }
+
+        #[test]
+        fn encode_typed_transaction_with_invalid_recovery_id() {
+            let mut transaction = signed_transaction(TransactionEnvelope::DynamicFee);
+            transaction.set_signature(SignatureData {
+                recovery_id: 27,
+                signature: vec![1; 64],
+            });
+
+            let err = transaction.encode().unwrap_err();
+
+            assert!(matches!(err, VdrError::ClientInvalidTransaction { .. }));
+        }
+
+        #[test]
+        fn encode_transaction_with_invalid_access_list() {
+            let mut transaction = signed_transaction(TransactionEnvelope::AccessList);
+            transaction.access_list =
+                vec![
+                    AccessListItem::new(Address::from(INVALID_ADDRESS), vec![vec![1; 32]]).unwrap(),
+                ];
+
+            let err = transaction.encode().unwrap_err();
+
+            assert!(matches!(err, VdrError::ClientInvalidTransaction { .. }));
+        }
+
+        fn sign_transaction(envelope: TransactionEnvelope, private_key: &str) -> Transaction {
+            let mut transaction = write_transaction();
+            transaction.set_envelope(envelope);
+            transaction.set_signature(sign(&transaction.get_signing_bytes().unwrap(), private_key));
+            transaction
+        }
+
+        #[rstest]
+        #[case::legacy(TransactionEnvelope::Legacy)]
+        #[case::dynamic_fee(TransactionEnvelope::DynamicFee)]
+        async fn verify_signed_transaction(#[case] envelope: TransactionEnvelope) {
+            let transaction = sign_transaction(envelope, TRUSTEE_PRIVATE_KEY);
+
+            assert_eq!(*TRUSTEE_ACCOUNT, transaction.recover_signer().unwrap());
+            transaction.verify().unwrap();
+        }
+
+        #[async_std::test]
+        async fn verify_transaction_signed_by_other_account() {
+            let transaction = sign_transaction(TransactionEnvelope::Legacy, OTHER_PRIVATE_KEY);
+
+            let err = transaction.verify().unwrap_err();
+
+            assert!(matches!(err, VdrError::ClientInvalidTransaction { .. }));
+        }
+
+        #[async_std::test]
+        async fn verify_modified_transaction() {
+            let mut transaction =
+                sign_transaction(TransactionEnvelope::Legacy, TRUSTEE_PRIVATE_KEY);
+            transaction.data = vec![1];
+
+            let err = transaction.verify().unwrap_err();
+
+            assert!(matches!(err, VdrError::ClientInvalidTransaction { .. }));
+        }
+
+        #[async_std::test]
+        async fn recover_signer_of_unsigned_transaction() {
+            let err = write_transaction().recover_signer().unwrap_err();
+
+            assert!(matches!(err, VdrError::ClientInvalidTransaction { .. }));
+        }
     }
 
     #[cfg(test)]
@@ -605,6 +1280,112 @@ pub mod test {
         }
     }
 
+    #[cfg(test)]
+    pub mod txn_fees_test {
+        use super::*;
+        use crate::client::{
+            client::test::{contracts, TEST_NETWORK},
+            Client, FeeStrategy, LedgerClientBuilder, MockClient, NonceManager,
+        };
+        use std::ops::Deref;
+
+        fn client(fee_strategy: &FeeStrategy) -> LedgerClient {
+            let mut client = MockClient::new();
+            client
+                .expect_get_transaction_count()
+                .returning(|_| Ok(DEFAULT_NONCE));
+            client.expect_estimate_gas().returning(|_| Ok(100_000));
+            client.expect_get_gas_price().returning(|| Ok(1_000));
+            let client: Box<dyn Client> = Box::new(client);
+
+            LedgerClientBuilder::new()
+                .set_chain_id(CONFIG.chain_id)
+                .set_client(client)
+                .set_contract_configs(&contracts())
+                .set_network(TEST_NETWORK)
+                .set_fee_strategy(fee_strategy)
+                .build()
+                .unwrap()
+        }
+
+        async fn build_transaction(client: &LedgerClient, type_: TransactionType) -> Transaction {
+            TransactionBuilder::new()
+                .set_contract(VALIDATOR_CONTROL_NAME)
+                .set_method(ADD_VALIDATOR_METHOD)
+                .add_param(VALIDATOR_ADDRESS.deref())
+                .unwrap()
+                .set_type(type_)
+                .set_from(&TRUSTEE_ACCOUNT)
+                .build(client)
+                .await
+                .unwrap()
+        }
+
+        #[async_std::test]
+        async fn build_write_transaction_with_estimated_fees() {
+            let client = client(&FeeStrategy::Estimated {
+                multiplier: Some(1.5),
+            });
+
+            let transaction = build_transaction(&client, TransactionType::Write).await;
+
+            assert_eq!(
+                TransactionFees {
+                    gas_limit: Some(150_000),
+                    gas_price: Some(1_500),
+                    ..TransactionFees::default()
+                },
+                transaction.fees
+            );
+        }
+
+        #[async_std::test]
+        async fn fee_estimation_failure_does_not_reserve_nonce() {
+            let mut client = MockClient::new();
+            client
+                .expect_get_transaction_count()
+                .returning(|_| Ok(DEFAULT_NONCE));
+            client
+                .expect_estimate_gas()
+                .returning(|_| Err(VdrError::ClientNodeUnreachable));
+            let client: Box<dyn Client> = Box::new(client);
+            let client = LedgerClientBuilder::new()
+                .set_chain_id(CONFIG.chain_id)
+                .set_client(client)
+                .set_contract_configs(&contracts())
+                .set_fee_strategy(&FeeStrategy::estimated())
+                .set_nonce_manager(NonceManager::new())
+                .build()
+                .unwrap();
+
+            let err = TransactionBuilder::new()
+                .set_contract(VALIDATOR_CONTROL_NAME)
+                .set_method(ADD_VALIDATOR_METHOD)
+                .add_param(VALIDATOR_ADDRESS.deref())
+                .unwrap()
+                .set_type(TransactionType::Write)
+                .set_from(&TRUSTEE_ACCOUNT)
+                .build(&client)
+                .await
+                .unwrap_err();
+
+            assert_eq!(VdrError::ClientNodeUnreachable, err);
+            assert_eq!(
+                DEFAULT_NONCE,
+                client.reserve_nonce(&TRUSTEE_ACCOUNT).await.unwrap()
+            );
+        }
+
+        #[async_std::test]
+        async fn build_read_transaction_without_fees() {
+            let client = client(&FeeStrategy::estimated());
+
+            let transaction = build_transaction(&client, TransactionType::Read).await;
+
+            assert_eq!(TransactionFees::default(), transaction.fees);
+        }
+    }
+
     #[cfg(test)]
     pub mod txn_parser_test {
         use super::*;
@@ -0,0 +1,32 @@
+use crate::types::Address;
+use serde_derive::{Deserialize, Serialize};
+
+/// Result of checking the client configuration against the network
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VerificationReport {
+    /// chain id returned by the node
+    pub chain_id: u64,
+    /// differences between the client configuration and the network. Empty if the configuration is correct
+    pub mismatches: Vec<VerificationMismatch>,
+}
+
+/// Difference between the client configuration and the network
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum VerificationMismatch {
+    /// Node belongs to the network with another chain id
+    ChainId { expected: u64, actual: u64 },
+    /// There is no contract deployed at the configured address
+    ContractNotDeployed { contract: String, address: Address },
+    /// Deployed contract does not implement functions defined in its ABI
+    ContractFunctionsMissing {
+        contract: String,
+        address: Address,
+        functions: Vec<String>,
+    },
+}
+
+impl VerificationReport {
+    pub fn is_valid(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
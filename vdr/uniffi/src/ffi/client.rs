@@ -3,11 +3,40 @@ use crate::{
         error::VdrResult,
         event_query::{EventLog, EventQuery},
         transaction::Transaction,
-        types::{ContractConfig, PingStatus, QuorumConfig},
+        types::{
+            ConfirmationPolicy, ConnectionConfig, ContractConfig, EventPagination,
+            LedgerClientOptions, PingStatus, QuorumConfig, SimulationResult, VerificationReport,
+        },
     },
     VdrError,
 };
-use indy_besu_vdr::{ContractConfig as ContractConfig_, LedgerClient as LedgerClient_};
+use async_trait::async_trait;
+use indy_besu_vdr::{
+    ConnectionConfig as ConnectionConfig_, ContractConfig as ContractConfig_,
+    EventQuery as EventQuery_, LedgerClient as LedgerClient_,
+    LedgerClientBuilder as LedgerClientBuilder_, QuorumConfig as QuorumConfig_,
+    TokenProvider as TokenProvider_, VdrError as VdrError_, VdrResult as VdrResult_,
+};
+use serde_json::json;
+use std::sync::Arc;
+
+/// Provider of bearer tokens used to authenticate requests to the RPC node.
+/// Called before every request, so implementations should cache the token until it expires
+#[uniffi::export(callback_interface)]
+pub trait TokenProvider: Send + Sync {
+    fn token(&self) -> VdrResult<String>;
+}
+
+struct TokenProviderAdapter(Box<dyn TokenProvider>);
+
+#[async_trait]
+impl TokenProvider_ for TokenProviderAdapter {
+    async fn token(&self) -> VdrResult_<String> {
+        self.0
+            .token()
+            .map_err(|err| VdrError_::ClientUnexpectedError(err.to_string()))
+    }
+}
 
 #[derive(uniffi::Object)]
 pub struct LedgerClient {
@@ -39,29 +68,187 @@ impl LedgerClient {
         Ok(LedgerClient { client })
     }
 
+    #[uniffi::constructor]
+    pub fn new_with_connection(
+        chain_id: u64,
+        node_address: String,
+        contract_configs: Vec<ContractConfig>,
+        network: Option<String>,
+        quorum_config: Option<QuorumConfig>,
+        connection_config: ConnectionConfig,
+    ) -> VdrResult<LedgerClient> {
+        LedgerClient::build(
+            chain_id,
+            &node_address,
+            contract_configs,
+            network,
+            quorum_config.map(QuorumConfig::into),
+            Some(connection_config.into()),
+            LedgerClientOptions::default(),
+        )
+    }
+
+    #[uniffi::constructor]
+    pub fn new_with_options(
+        chain_id: u64,
+        node_address: String,
+        contract_configs: Vec<ContractConfig>,
+        network: Option<String>,
+        quorum_config: Option<QuorumConfig>,
+        connection_config: Option<ConnectionConfig>,
+        options: LedgerClientOptions,
+    ) -> VdrResult<LedgerClient> {
+        LedgerClient::build(
+            chain_id,
+            &node_address,
+            contract_configs,
+            network,
+            quorum_config.map(QuorumConfig::into),
+            connection_config.map(ConnectionConfig::into),
+            options,
+        )
+    }
+
+    #[uniffi::constructor]
+    pub fn new_with_token_provider(
+        chain_id: u64,
+        node_address: String,
+        contract_configs: Vec<ContractConfig>,
+        network: Option<String>,
+        quorum_config: Option<QuorumConfig>,
+        connection_config: ConnectionConfig,
+        token_provider: Box<dyn TokenProvider>,
+    ) -> VdrResult<LedgerClient> {
+        let token_provider: Arc<dyn TokenProvider_> =
+            Arc::new(TokenProviderAdapter(token_provider));
+        let connection_config = ConnectionConfig_ {
+            token_provider: Some(token_provider.clone()),
+            ..connection_config.into()
+        };
+        // quorum nodes are authenticated with the same token provider
+        let quorum_config = quorum_config.map(|quorum_config| {
+            let mut quorum_config: QuorumConfig_ = quorum_config.into();
+            quorum_config.connection = Some(ConnectionConfig_ {
+                token_provider: Some(token_provider),
+                ..quorum_config.connection.unwrap_or_default()
+            });
+            quorum_config
+        });
+        LedgerClient::build(
+            chain_id,
+            &node_address,
+            contract_configs,
+            network,
+            quorum_config,
+            Some(connection_config),
+            LedgerClientOptions::default(),
+        )
+    }
+
     pub async fn ping(&self) -> VdrResult<PingStatus> {
         let ping = self.client.ping().await?;
         Ok(ping.into())
     }
 
+    pub async fn verify(&self) -> VdrResult<VerificationReport> {
+        let report = self.client.verify().await?;
+        Ok(report.into())
+    }
+
     pub async fn submit_transaction(&self, transaction: &Transaction) -> VdrResult<Vec<u8>> {
         self.client
-            .submit_transaction(&transaction.into())
+            .submit_transaction(&transaction.try_into()?)
+            .await
+            .map_err(VdrError::from)
+    }
+
+    pub async fn submit_transaction_with_policy(
+        &self,
+        transaction: &Transaction,
+        policy: ConfirmationPolicy,
+    ) -> VdrResult<Vec<u8>> {
+        self.client
+            .submit_transaction_with_policy(&transaction.try_into()?, &policy.into())
             .await
             .map_err(VdrError::from)
     }
 
+    pub async fn simulate_transaction(
+        &self,
+        transaction: &Transaction,
+    ) -> VdrResult<SimulationResult> {
+        let result = self
+            .client
+            .simulate_transaction(&transaction.try_into()?)
+            .await?;
+        Ok(result.into())
+    }
+
     pub async fn query_events(&self, query: &EventQuery) -> VdrResult<Vec<EventLog>> {
         Ok(self
             .client
-            .query_events(&query.into())
+            .query_events(&EventQuery_::try_from(query)?)
             .await?
             .into_iter()
             .map(EventLog::from)
             .collect())
     }
 
+    pub async fn query_events_with_pagination(
+        &self,
+        query: &EventQuery,
+        pagination: EventPagination,
+    ) -> VdrResult<Vec<EventLog>> {
+        Ok(self
+            .client
+            .query_events_with_pagination(&EventQuery_::try_from(query)?, &pagination.into())
+            .await?
+            .into_iter()
+            .map(EventLog::from)
+            .collect())
+    }
+
+    /// Get the receipt of the executed transaction serialized as JSON string
     pub async fn get_receipt(&self, hash: Vec<u8>) -> VdrResult<String> {
-        self.client.get_receipt(&hash).await.map_err(VdrError::from)
+        let receipt = self.client.get_receipt(&hash).await?;
+        Ok(json!(receipt).to_string())
+    }
+}
+
+impl LedgerClient {
+    fn build(
+        chain_id: u64,
+        node_address: &str,
+        contract_configs: Vec<ContractConfig>,
+        network: Option<String>,
+        quorum_config: Option<QuorumConfig_>,
+        connection_config: Option<ConnectionConfig_>,
+        options: LedgerClientOptions,
+    ) -> VdrResult<LedgerClient> {
+        let contract_configs: Vec<ContractConfig_> = contract_configs
+            .into_iter()
+            .map(ContractConfig::into)
+            .collect();
+        let mut builder = LedgerClientBuilder_::new()
+            .set_chain_id(chain_id)
+            .set_rpc_node(node_address)
+            .set_contract_configs(&contract_configs);
+        if let Some(connection_config) = connection_config {
+            builder = builder.set_connection_config(&connection_config);
+        }
+        if let Some(confirmation_policy) = options.confirmation_policy {
+            builder = builder.set_confirmation_policy(&confirmation_policy.into());
+        }
+        if let Some(fee_strategy) = options.fee_strategy {
+            builder = builder.set_fee_strategy(&fee_strategy.into());
+        }
+        if let Some(network) = network {
+            builder = builder.set_network(&network);
+        }
+        if let Some(quorum_config) = quorum_config {
+            builder = builder.set_quorum_config(&quorum_config);
+        }
+        let client = builder.build()?;
+        Ok(LedgerClient { client })
     }
 }
@@ -49,6 +49,23 @@ pub fn transaction_endorsing_data_get_signing_bytes(
         .map_err(VdrError::from)
 }
 
+#[uniffi::export]
+pub fn transaction_endorsing_data_recover_signer(
+    data: &TransactionEndorsingData,
+) -> VdrResult<String> {
+    TransactionEndorsingData_::from(data)
+        .recover_signer()
+        .map(|account| account.to_string())
+        .map_err(VdrError::from)
+}
+
+#[uniffi::export]
+pub fn transaction_endorsing_data_verify(data: &TransactionEndorsingData) -> VdrResult<()> {
+    TransactionEndorsingData_::from(data)
+        .verify()
+        .map_err(VdrError::from)
+}
+
 #[uniffi::export]
 pub fn transaction_endorsing_data_to_string(data: &TransactionEndorsingData) -> VdrResult<String> {
     TransactionEndorsingData_::from(data)
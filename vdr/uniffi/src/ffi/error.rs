@@ -5,6 +5,9 @@ pub enum VdrError {
     #[error("Ledger Client: Node is unreachable")]
     ClientNodeUnreachable,
 
+    #[error("Ledger Client: Node responded with HTTP status {}", code)]
+    ClientHttpStatus { code: u16 },
+
     #[error("Ledger Client: Invalid transaction: {}", msg)]
     ClientInvalidTransaction { msg: String },
 
@@ -23,6 +26,12 @@ pub enum VdrError {
     #[error("Ledger Client: Invalid state {}", msg)]
     ClientInvalidState { msg: String },
 
+    #[error("Ledger Client: Timeout: {}", msg)]
+    ClientTimeout { msg: String },
+
+    #[error("Ledger Client: Query limit exceeded: {}", msg)]
+    ClientQueryLimitExceeded { msg: String },
+
     #[error("Contract: Invalid name: {}", msg)]
     ContractInvalidName { msg: String },
 
@@ -47,6 +56,12 @@ pub enum VdrError {
     #[error("Signer: Unexpected error occurred: {}", msg)]
     SignerUnexpectedError { msg: String },
 
+    #[error("Signer: Invalid keystore: {}", msg)]
+    SignerInvalidKeystore { msg: String },
+
+    #[error("Signer: Invalid mnemonic: {}", msg)]
+    SignerInvalidMnemonic { msg: String },
+
     #[error("Invalid data: {}", msg)]
     CommonInvalidData { msg: String },
 
@@ -56,6 +71,9 @@ pub enum VdrError {
     #[error("Could not get transaction: {}", msg)]
     GetTransactionError { msg: String },
 
+    #[error("Invalid DID document: {}", msg)]
+    InvalidDidDocument { msg: String },
+
     #[error("Invalid schema: {}", msg)]
     InvalidSchema { msg: String },
 
@@ -65,10 +83,17 @@ pub enum VdrError {
 
 pub type VdrResult<T> = Result<T, VdrError>;
 
+impl From<uniffi::UnexpectedUniFFICallbackError> for VdrError {
+    fn from(error: uniffi::UnexpectedUniFFICallbackError) -> Self {
+        VdrError::ClientUnexpectedError { msg: error.reason }
+    }
+}
+
 impl From<VdrError_> for VdrError {
     fn from(error: VdrError_) -> Self {
         match error {
             VdrError_::ClientNodeUnreachable => VdrError::ClientNodeUnreachable,
+            VdrError_::ClientHttpStatus(code) => VdrError::ClientHttpStatus { code },
             VdrError_::ClientInvalidTransaction(msg) => VdrError::ClientInvalidTransaction { msg },
             VdrError_::ClientInvalidEndorsementData(msg) => {
                 VdrError::ClientInvalidEndorsementData { msg }
@@ -79,6 +104,8 @@ impl From<VdrError_> for VdrError {
             }
             VdrError_::ClientUnexpectedError(msg) => VdrError::ClientUnexpectedError { msg },
             VdrError_::ClientInvalidState(msg) => VdrError::ClientInvalidState { msg },
+            VdrError_::ClientTimeout(msg) => VdrError::ClientTimeout { msg },
+            VdrError_::ClientQueryLimitExceeded(msg) => VdrError::ClientQueryLimitExceeded { msg },
             VdrError_::ContractInvalidName(msg) => VdrError::ContractInvalidName { msg },
             VdrError_::ContractInvalidSpec(msg) => VdrError::ContractInvalidSpec { msg },
             VdrError_::ContractInvalidInputData => VdrError::ContractInvalidInputData,
@@ -89,9 +116,12 @@ impl From<VdrError_> for VdrError {
             VdrError_::SignerInvalidMessage => VdrError::SignerInvalidMessage,
             VdrError_::SignerMissingKey(msg) => VdrError::SignerMissingKey { msg },
             VdrError_::SignerUnexpectedError(msg) => VdrError::SignerUnexpectedError { msg },
+            VdrError_::SignerInvalidKeystore(msg) => VdrError::SignerInvalidKeystore { msg },
+            VdrError_::SignerInvalidMnemonic(msg) => VdrError::SignerInvalidMnemonic { msg },
             VdrError_::CommonInvalidData(msg) => VdrError::CommonInvalidData { msg },
             VdrError_::QuorumNotReached(msg) => VdrError::QuorumNotReached { msg },
             VdrError_::GetTransactionError(msg) => VdrError::GetTransactionError { msg },
+            VdrError_::InvalidDidDocument(msg) => VdrError::InvalidDidDocument { msg },
             VdrError_::InvalidSchema(msg) => VdrError::InvalidSchema { msg },
             VdrError_::InvalidCredentialDefinition(msg) => {
                 VdrError::InvalidCredentialDefinition { msg }
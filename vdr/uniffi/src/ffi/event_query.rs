@@ -1,34 +1,84 @@
-use indy_besu_vdr::{Address, Block, EventLog as EventLog_, EventQuery as EventQuery_};
+use crate::ffi::error::VdrError;
+use indy_besu_vdr::{
+    Address, Block, EventLog as EventLog_, EventQuery as EventQuery_, EVENT_TOPICS_COUNT,
+};
 
+/// Event query. `addresses` and `topics` describe the query in full.
+/// Previous fields `address`, `event_signature` and `event_filter` (the first indexed event parameter) are still
+/// accepted and merged into them.
 #[derive(uniffi::Record)]
 pub struct EventQuery {
-    pub address: String,
+    #[uniffi(default = None)]
+    pub address: Option<String>,
+    #[uniffi(default = None)]
     pub from_block: Option<u64>,
+    #[uniffi(default = None)]
     pub to_block: Option<u64>,
+    #[uniffi(default = None)]
     pub event_signature: Option<String>,
+    #[uniffi(default = None)]
     pub event_filter: Option<String>,
+    #[uniffi(default = None)]
+    pub addresses: Option<Vec<String>>,
+    #[uniffi(default = None)]
+    pub topics: Option<Vec<Option<Vec<String>>>>,
 }
 
-impl From<&EventQuery> for EventQuery_ {
-    fn from(query: &EventQuery) -> Self {
-        EventQuery_ {
-            address: Address::from(query.address.as_ref()),
+impl TryFrom<&EventQuery> for EventQuery_ {
+    type Error = VdrError;
+
+    fn try_from(query: &EventQuery) -> Result<Self, Self::Error> {
+        let query_topics = query.topics.as_deref().unwrap_or_default();
+        if query_topics.len() > EVENT_TOPICS_COUNT {
+            return Err(VdrError::CommonInvalidData {
+                msg: format!(
+                    "Event query has {} topics. Event log has at most {} topics",
+                    query_topics.len(),
+                    EVENT_TOPICS_COUNT
+                ),
+            });
+        }
+        let mut topics: [Option<Vec<String>>; EVENT_TOPICS_COUNT] = Default::default();
+        for (topic, values) in topics.iter_mut().zip(query_topics.iter()) {
+            *topic = values.to_owned();
+        }
+        if let Some(event_signature) = &query.event_signature {
+            topics[0] = Some(vec![event_signature.to_owned()]);
+        }
+        if let Some(event_filter) = &query.event_filter {
+            topics[1] = Some(vec![event_filter.to_owned()]);
+        }
+        Ok(EventQuery_ {
+            addresses: query
+                .addresses
+                .iter()
+                .flatten()
+                .chain(query.address.iter())
+                .map(|address| Address::from(address.as_str()))
+                .collect(),
             from_block: query.from_block.map(Block::from),
             to_block: query.to_block.map(Block::from),
-            event_signature: query.event_signature.to_owned(),
-            event_filter: query.event_filter.to_owned(),
-        }
+            topics,
+        })
     }
 }
 
 impl From<EventQuery_> for EventQuery {
     fn from(query: EventQuery_) -> Self {
         EventQuery {
-            address: query.address.as_ref().to_string(),
+            address: None,
             from_block: query.from_block.map(|block| block.value()),
             to_block: query.to_block.map(|block| block.value()),
-            event_signature: query.event_signature,
-            event_filter: query.event_filter,
+            event_signature: None,
+            event_filter: None,
+            addresses: Some(
+                query
+                    .addresses
+                    .iter()
+                    .map(|address| address.as_ref().to_string())
+                    .collect(),
+            ),
+            topics: Some(query.topics.to_vec()),
         }
     }
 }
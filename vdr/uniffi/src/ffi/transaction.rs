@@ -1,8 +1,11 @@
 use crate::ffi::{
     error::{VdrError, VdrResult},
-    types::{SignatureData, TransactionType},
+    types::{
+        AccessListItem, BlockTag, SignatureData, TransactionEnvelope, TransactionFees,
+        TransactionType,
+    },
 };
-use indy_besu_vdr::{Address, Transaction as Transaction_};
+use indy_besu_vdr::{AccessListItem as AccessListItem_, Address, Transaction as Transaction_};
 
 #[derive(uniffi::Record)]
 pub struct Transaction {
@@ -14,6 +17,10 @@ pub struct Transaction {
     pub data: Vec<u8>,
     pub signature: Option<SignatureData>,
     pub hash: Option<Vec<u8>>,
+    pub envelope: TransactionEnvelope,
+    pub fees: TransactionFees,
+    pub access_list: Vec<AccessListItem>,
+    pub block_tag: Option<BlockTag>,
 }
 
 impl From<Transaction_> for Transaction {
@@ -27,13 +34,23 @@ impl From<Transaction_> for Transaction {
             data: transaction.data,
             signature: transaction.signature.as_ref().map(SignatureData::from),
             hash: transaction.hash,
+            envelope: TransactionEnvelope::from(&transaction.envelope),
+            fees: TransactionFees::from(&transaction.fees),
+            access_list: transaction
+                .access_list
+                .iter()
+                .map(AccessListItem::from)
+                .collect(),
+            block_tag: transaction.block_tag.as_ref().map(BlockTag::from),
         }
     }
 }
 
-impl From<&Transaction> for Transaction_ {
-    fn from(transaction: &Transaction) -> Self {
-        Transaction_ {
+impl TryFrom<&Transaction> for Transaction_ {
+    type Error = VdrError;
+
+    fn try_from(transaction: &Transaction) -> Result<Self, Self::Error> {
+        Ok(Transaction_ {
             type_: (&transaction.type_).into(),
             from: transaction.from.as_deref().map(Address::from),
             to: Address::from(transaction.to.as_ref()),
@@ -42,7 +59,18 @@ impl From<&Transaction> for Transaction_ {
             data: transaction.data.to_owned(),
             signature: transaction.signature.as_ref().map(|data| data.into()),
             hash: transaction.hash.to_owned(),
-        }
+            envelope: (&transaction.envelope).into(),
+            fees: (&transaction.fees).into(),
+            access_list: transaction
+                .access_list
+                .iter()
+                .map(AccessListItem_::try_from)
+                .collect::<VdrResult<_>>()?,
+            block_tag: transaction
+                .block_tag
+                .as_ref()
+                .map(|block_tag| block_tag.into()),
+        })
     }
 }
 
@@ -66,19 +94,45 @@ pub fn transaction_create(
         data,
         signature,
         hash,
+        envelope: TransactionEnvelope::Legacy,
+        fees: TransactionFees {
+            gas_limit: None,
+            gas_price: None,
+            max_fee_per_gas: None,
+            max_priority_fee_per_gas: None,
+        },
+        access_list: vec![],
+        block_tag: None,
     }
 }
 
 #[uniffi::export]
 pub fn transaction_get_signing_bytes(data: &Transaction) -> VdrResult<Vec<u8>> {
-    Transaction_::from(data)
+    Transaction_::try_from(data)?
         .get_signing_bytes()
         .map_err(VdrError::from)
 }
 
+#[uniffi::export]
+pub fn transaction_recover_signer(data: &Transaction) -> VdrResult<String> {
+    Transaction_::try_from(data)?
+        .recover_signer()
+        .map(|account| account.to_string())
+        .map_err(VdrError::from)
+}
+
+#[uniffi::export]
+pub fn transaction_verify(data: &Transaction) -> VdrResult<()> {
+    Transaction_::try_from(data)?
+        .verify()
+        .map_err(VdrError::from)
+}
+
 #[uniffi::export]
 pub fn transaction_to_string(data: &Transaction) -> VdrResult<String> {
-    Transaction_::from(data).to_string().map_err(VdrError::from)
+    Transaction_::try_from(data)?
+        .to_string()
+        .map_err(VdrError::from)
 }
 
 #[uniffi::export]
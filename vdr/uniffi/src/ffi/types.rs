@@ -1,8 +1,16 @@
-use crate::JsonValue;
+use std::collections::HashMap;
+
+use crate::{ffi::error::VdrError, JsonValue};
 use indy_besu_vdr::{
-    ContractConfig as ContractConfig_, ContractSpec as ContractSpec_, PingStatus as PingStatus_,
-    QuorumConfig as QuorumConfig_, SignatureData as SignatureData_, Status as Status_,
-    TransactionType as TransactionType_,
+    AccessListItem as AccessListItem_, Address, BasicAuth as BasicAuth_, BlockTag as BlockTag_,
+    ConfirmationPolicy as ConfirmationPolicy_, ConnectionConfig as ConnectionConfig_,
+    ContractConfig as ContractConfig_, ContractSpec as ContractSpec_,
+    EventPagination as EventPagination_, FeeStrategy as FeeStrategy_, PingStatus as PingStatus_,
+    QuorumConfig as QuorumConfig_, QuorumPolicy as QuorumPolicy_, SignatureData as SignatureData_,
+    SimulationResult as SimulationResult_, Status as Status_, TlsConfig as TlsConfig_,
+    TransactionEnvelope as TransactionEnvelope_, TransactionFees as TransactionFees_,
+    TransactionType as TransactionType_, VerificationMismatch as VerificationMismatch_,
+    VerificationReport as VerificationReport_,
 };
 
 #[derive(uniffi::Record)]
@@ -21,6 +29,35 @@ pub enum Status {
     },
 }
 
+#[derive(uniffi::Record)]
+pub struct VerificationReport {
+    pub chain_id: u64,
+    pub mismatches: Vec<VerificationMismatch>,
+}
+
+#[derive(uniffi::Enum)]
+pub enum VerificationMismatch {
+    ChainId {
+        expected: u64,
+        actual: u64,
+    },
+    ContractNotDeployed {
+        contract: String,
+        address: String,
+    },
+    ContractFunctionsMissing {
+        contract: String,
+        address: String,
+        functions: Vec<String>,
+    },
+}
+
+#[derive(uniffi::Enum)]
+pub enum SimulationResult {
+    Success { output: Vec<u8> },
+    Reverted { revert_reason: String },
+}
+
 #[derive(uniffi::Record)]
 pub struct ContractConfig {
     pub address: String,
@@ -40,6 +77,33 @@ pub enum TransactionType {
     Write,
 }
 
+#[derive(uniffi::Enum)]
+pub enum TransactionEnvelope {
+    Legacy,
+    AccessList,
+    DynamicFee,
+}
+
+#[derive(uniffi::Record)]
+pub struct TransactionFees {
+    pub gas_limit: Option<u64>,
+    pub gas_price: Option<u64>,
+    pub max_fee_per_gas: Option<u64>,
+    pub max_priority_fee_per_gas: Option<u64>,
+}
+
+#[derive(uniffi::Record)]
+pub struct AccessListItem {
+    pub address: String,
+    pub storage_keys: Vec<Vec<u8>>,
+}
+
+#[derive(uniffi::Enum)]
+pub enum BlockTag {
+    Number { number: u64 },
+    Hash { hash: Vec<u8> },
+}
+
 #[derive(uniffi::Record)]
 pub struct SignatureData {
     pub recovery_id: u64,
@@ -52,6 +116,86 @@ pub struct QuorumConfig {
     pub request_retries: Option<u8>,
     pub request_timeout: Option<u64>,
     pub retry_interval: Option<u64>,
+    pub read_policy: Option<QuorumPolicy>,
+    pub write_policy: Option<QuorumPolicy>,
+    pub weights: Option<HashMap<String, u64>>,
+    pub validator_nodes: Option<HashMap<String, String>>,
+    pub connection: Option<ConnectionConfig>,
+}
+
+#[derive(uniffi::Record)]
+pub struct ConnectionConfig {
+    pub headers: Option<HashMap<String, String>>,
+    pub bearer_token: Option<String>,
+    pub basic_auth: Option<BasicAuth>,
+    pub tls: Option<TlsConfig>,
+}
+
+#[derive(uniffi::Record)]
+pub struct BasicAuth {
+    pub username: String,
+    pub password: Option<String>,
+}
+
+#[derive(uniffi::Record)]
+pub struct TlsConfig {
+    pub ca_certificate: Option<String>,
+    pub client_certificate: Option<String>,
+    pub client_key: Option<String>,
+    pub accept_invalid_certificates: Option<bool>,
+}
+
+#[derive(uniffi::Enum)]
+pub enum QuorumPolicy {
+    Minimal,
+    Count { count: u64 },
+    Fraction { fraction: f64 },
+    Bft,
+    All,
+}
+
+impl Into<QuorumPolicy_> for QuorumPolicy {
+    fn into(self) -> QuorumPolicy_ {
+        match self {
+            QuorumPolicy::Minimal => QuorumPolicy_::Minimal,
+            QuorumPolicy::Count { count } => QuorumPolicy_::Count(count),
+            QuorumPolicy::Fraction { fraction } => QuorumPolicy_::Fraction(fraction),
+            QuorumPolicy::Bft => QuorumPolicy_::Bft,
+            QuorumPolicy::All => QuorumPolicy_::All,
+        }
+    }
+}
+
+#[derive(uniffi::Record)]
+pub struct ConfirmationPolicy {
+    pub confirmations: Option<u64>,
+    pub poll_interval: Option<u64>,
+    pub timeout: Option<u64>,
+    pub instant_finality: Option<bool>,
+}
+
+#[derive(uniffi::Enum)]
+pub enum FeeStrategy {
+    Free,
+    Fixed { fees: TransactionFees },
+    Estimated { multiplier: Option<f64> },
+}
+
+/// Optional settings of the ledger client
+#[derive(Default, uniffi::Record)]
+pub struct LedgerClientOptions {
+    /// Policy used to wait for write transactions submitted without explicit policy
+    #[uniffi(default = None)]
+    pub confirmation_policy: Option<ConfirmationPolicy>,
+    /// Strategy defining gas limit and fees of write transactions. Gas free network is assumed if not set
+    #[uniffi(default = None)]
+    pub fee_strategy: Option<FeeStrategy>,
+}
+
+#[derive(uniffi::Record)]
+pub struct EventPagination {
+    pub chunk_size: Option<u64>,
+    pub min_chunk_size: Option<u64>,
 }
 
 impl From<PingStatus_> for PingStatus {
@@ -77,6 +221,55 @@ impl From<Status_> for Status {
     }
 }
 
+impl From<VerificationReport_> for VerificationReport {
+    fn from(report: VerificationReport_) -> Self {
+        VerificationReport {
+            chain_id: report.chain_id,
+            mismatches: report
+                .mismatches
+                .into_iter()
+                .map(VerificationMismatch::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<VerificationMismatch_> for VerificationMismatch {
+    fn from(mismatch: VerificationMismatch_) -> Self {
+        match mismatch {
+            VerificationMismatch_::ChainId { expected, actual } => {
+                VerificationMismatch::ChainId { expected, actual }
+            }
+            VerificationMismatch_::ContractNotDeployed { contract, address } => {
+                VerificationMismatch::ContractNotDeployed {
+                    contract,
+                    address: address.to_string(),
+                }
+            }
+            VerificationMismatch_::ContractFunctionsMissing {
+                contract,
+                address,
+                functions,
+            } => VerificationMismatch::ContractFunctionsMissing {
+                contract,
+                address: address.to_string(),
+                functions,
+            },
+        }
+    }
+}
+
+impl From<SimulationResult_> for SimulationResult {
+    fn from(result: SimulationResult_) -> Self {
+        match result {
+            SimulationResult_::Success { output } => SimulationResult::Success { output },
+            SimulationResult_::Reverted { revert_reason } => {
+                SimulationResult::Reverted { revert_reason }
+            }
+        }
+    }
+}
+
 impl Into<ContractConfig_> for ContractConfig {
     fn into(self) -> ContractConfig_ {
         ContractConfig_ {
@@ -114,6 +307,87 @@ impl From<&TransactionType_> for TransactionType {
     }
 }
 
+impl Into<TransactionEnvelope_> for &TransactionEnvelope {
+    fn into(self) -> TransactionEnvelope_ {
+        match self {
+            TransactionEnvelope::Legacy => TransactionEnvelope_::Legacy,
+            TransactionEnvelope::AccessList => TransactionEnvelope_::AccessList,
+            TransactionEnvelope::DynamicFee => TransactionEnvelope_::DynamicFee,
+        }
+    }
+}
+
+impl From<&TransactionEnvelope_> for TransactionEnvelope {
+    fn from(envelope: &TransactionEnvelope_) -> TransactionEnvelope {
+        match envelope {
+            TransactionEnvelope_::Legacy => TransactionEnvelope::Legacy,
+            TransactionEnvelope_::AccessList => TransactionEnvelope::AccessList,
+            TransactionEnvelope_::DynamicFee => TransactionEnvelope::DynamicFee,
+        }
+    }
+}
+
+impl Into<TransactionFees_> for &TransactionFees {
+    fn into(self) -> TransactionFees_ {
+        TransactionFees_ {
+            gas_limit: self.gas_limit,
+            gas_price: self.gas_price,
+            max_fee_per_gas: self.max_fee_per_gas,
+            max_priority_fee_per_gas: self.max_priority_fee_per_gas,
+        }
+    }
+}
+
+impl From<&TransactionFees_> for TransactionFees {
+    fn from(fees: &TransactionFees_) -> TransactionFees {
+        TransactionFees {
+            gas_limit: fees.gas_limit,
+            gas_price: fees.gas_price,
+            max_fee_per_gas: fees.max_fee_per_gas,
+            max_priority_fee_per_gas: fees.max_priority_fee_per_gas,
+        }
+    }
+}
+
+impl TryFrom<&AccessListItem> for AccessListItem_ {
+    type Error = VdrError;
+
+    fn try_from(item: &AccessListItem) -> Result<Self, Self::Error> {
+        AccessListItem_::new(
+            Address::from(item.address.as_str()),
+            item.storage_keys.clone(),
+        )
+        .map_err(VdrError::from)
+    }
+}
+
+impl From<&AccessListItem_> for AccessListItem {
+    fn from(item: &AccessListItem_) -> AccessListItem {
+        AccessListItem {
+            address: item.address.to_string(),
+            storage_keys: item.storage_keys.iter().map(|key| key.0.to_vec()).collect(),
+        }
+    }
+}
+
+impl Into<BlockTag_> for &BlockTag {
+    fn into(self) -> BlockTag_ {
+        match self {
+            BlockTag::Number { number } => BlockTag_::Number(*number),
+            BlockTag::Hash { hash } => BlockTag_::Hash(hash.to_owned()),
+        }
+    }
+}
+
+impl From<&BlockTag_> for BlockTag {
+    fn from(block_tag: &BlockTag_) -> BlockTag {
+        match block_tag {
+            BlockTag_::Number(number) => BlockTag::Number { number: *number },
+            BlockTag_::Hash(hash) => BlockTag::Hash { hash: hash.clone() },
+        }
+    }
+}
+
 impl Into<SignatureData_> for &SignatureData {
     fn into(self) -> SignatureData_ {
         SignatureData_ {
@@ -139,6 +413,61 @@ impl Into<QuorumConfig_> for QuorumConfig {
             request_retries: self.request_retries,
             request_timeout: self.request_timeout,
             retry_interval: self.retry_interval,
+            read_policy: self.read_policy.map(QuorumPolicy::into),
+            write_policy: self.write_policy.map(QuorumPolicy::into),
+            weights: self.weights,
+            validator_nodes: self.validator_nodes,
+            connection: self.connection.map(ConnectionConfig::into),
+        }
+    }
+}
+
+impl Into<ConnectionConfig_> for ConnectionConfig {
+    fn into(self) -> ConnectionConfig_ {
+        ConnectionConfig_ {
+            headers: self.headers,
+            bearer_token: self.bearer_token,
+            basic_auth: self.basic_auth.map(|basic_auth| BasicAuth_ {
+                username: basic_auth.username,
+                password: basic_auth.password,
+            }),
+            tls: self.tls.map(|tls| TlsConfig_ {
+                ca_certificate: tls.ca_certificate,
+                client_certificate: tls.client_certificate,
+                client_key: tls.client_key,
+                accept_invalid_certificates: tls.accept_invalid_certificates,
+            }),
+            token_provider: None,
+        }
+    }
+}
+
+impl Into<EventPagination_> for EventPagination {
+    fn into(self) -> EventPagination_ {
+        EventPagination_ {
+            chunk_size: self.chunk_size,
+            min_chunk_size: self.min_chunk_size,
+        }
+    }
+}
+
+impl Into<ConfirmationPolicy_> for ConfirmationPolicy {
+    fn into(self) -> ConfirmationPolicy_ {
+        ConfirmationPolicy_ {
+            confirmations: self.confirmations,
+            poll_interval: self.poll_interval,
+            timeout: self.timeout,
+            instant_finality: self.instant_finality,
+        }
+    }
+}
+
+impl Into<FeeStrategy_> for FeeStrategy {
+    fn into(self) -> FeeStrategy_ {
+        match self {
+            FeeStrategy::Free => FeeStrategy_::Free,
+            FeeStrategy::Fixed { fees } => FeeStrategy_::Fixed((&fees).into()),
+            FeeStrategy::Estimated { multiplier } => FeeStrategy_::Estimated { multiplier },
         }
     }
 }
@@ -3,7 +3,10 @@ use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::future_to_promise;
 
-use indy_besu_vdr::{ContractConfig, LedgerClient, QuorumConfig};
+use indy_besu_vdr::{
+    ConfirmationPolicy, ConnectionConfig, ContractConfig, EventPagination, FeeStrategy,
+    LedgerClient, LedgerClientBuilder, QuorumConfig,
+};
 
 use crate::{
     error::{JsResult, Result},
@@ -40,6 +43,76 @@ impl LedgerClientWrapper {
         Ok(LedgerClientWrapper(Rc::new(client)))
     }
 
+    /// Create client connected with custom headers, bearer token or basic auth.
+    /// Token refresh is not supported: create a new client when `bearerToken` expires
+    #[wasm_bindgen(js_name = withConnection)]
+    pub fn with_connection(
+        chain_id: u32,
+        node_address: String,
+        contract_configs: JsValue,
+        network: Option<String>,
+        quorum_config: JsValue,
+        connection_config: JsValue,
+    ) -> Result<LedgerClientWrapper> {
+        LedgerClientWrapper::with_options(
+            chain_id,
+            node_address,
+            contract_configs,
+            network,
+            quorum_config,
+            connection_config,
+            JsValue::UNDEFINED,
+        )
+    }
+
+    /// Create client with optional settings.
+    /// `options` object can contain `confirmationPolicy` used to wait for write transactions submitted without
+    /// explicit policy and `feeStrategy` defining gas limit and fees of write transactions (e.g. `"Free"` or
+    /// `{ Estimated: { multiplier: 1.5 } }`)
+    #[wasm_bindgen(js_name = withOptions)]
+    pub fn with_options(
+        chain_id: u32,
+        node_address: String,
+        contract_configs: JsValue,
+        network: Option<String>,
+        quorum_config: JsValue,
+        connection_config: JsValue,
+        options: JsValue,
+    ) -> Result<LedgerClientWrapper> {
+        console_error_panic_hook::set_once();
+        let contract_configs: Vec<ContractConfig> =
+            serde_wasm_bindgen::from_value(contract_configs)?;
+        let quorum_config: Option<QuorumConfig> =
+            serde_wasm_bindgen::from_value(quorum_config).ok();
+        let connection_config: Option<ConnectionConfig> =
+            serde_wasm_bindgen::from_value(connection_config)?;
+        let confirmation_policy: Option<ConfirmationPolicy> =
+            serde_wasm_bindgen::from_value(get_option(&options, "confirmationPolicy")?)?;
+        let fee_strategy: Option<FeeStrategy> =
+            serde_wasm_bindgen::from_value(get_option(&options, "feeStrategy")?)?;
+        let mut builder = LedgerClientBuilder::new()
+            .set_chain_id(chain_id as u64)
+            .set_rpc_node(&node_address)
+            .set_contract_configs(&contract_configs);
+        if let Some(connection_config) = connection_config {
+            builder = builder.set_connection_config(&connection_config);
+        }
+        if let Some(confirmation_policy) = confirmation_policy {
+            builder = builder.set_confirmation_policy(&confirmation_policy);
+        }
+        if let Some(fee_strategy) = fee_strategy {
+            builder = builder.set_fee_strategy(&fee_strategy);
+        }
+        if let Some(network) = network {
+            builder = builder.set_network(&network);
+        }
+        if let Some(quorum_config) = quorum_config {
+            builder = builder.set_quorum_config(&quorum_config);
+        }
+        let client = builder.build().as_js()?;
+        Ok(LedgerClientWrapper(Rc::new(client)))
+    }
+
     pub async fn ping(&self) -> Promise {
         let client = self.0.clone();
         future_to_promise(async move {
@@ -49,6 +122,15 @@ impl LedgerClientWrapper {
         })
     }
 
+    pub async fn verify(&self) -> Promise {
+        let client = self.0.clone();
+        future_to_promise(async move {
+            let report = client.verify().await.as_js()?;
+            let result: JsValue = serde_wasm_bindgen::to_value(&report)?;
+            Ok(result)
+        })
+    }
+
     #[wasm_bindgen(js_name = submitTransaction)]
     pub async fn submit_transaction(&self, transaction: &TransactionWrapper) -> Promise {
         let client = self.0.clone();
@@ -61,6 +143,38 @@ impl LedgerClientWrapper {
         })
     }
 
+    #[wasm_bindgen(js_name = submitTransactionWithPolicy)]
+    pub async fn submit_transaction_with_policy(
+        &self,
+        transaction: &TransactionWrapper,
+        policy: JsValue,
+    ) -> Promise {
+        let client = self.0.clone();
+        let transaction = transaction.0.clone();
+        future_to_promise(async move {
+            let policy: ConfirmationPolicy = serde_wasm_bindgen::from_value(policy)?;
+            let transaction = transaction.borrow();
+            let response = client
+                .submit_transaction_with_policy(&transaction, &policy)
+                .await
+                .as_js()?;
+            let result: JsValue = serde_wasm_bindgen::to_value(&response)?;
+            Ok(result)
+        })
+    }
+
+    #[wasm_bindgen(js_name = simulateTransaction)]
+    pub async fn simulate_transaction(&self, transaction: &TransactionWrapper) -> Promise {
+        let client = self.0.clone();
+        let transaction = transaction.0.clone();
+        future_to_promise(async move {
+            let transaction = transaction.borrow();
+            let response = client.simulate_transaction(&transaction).await.as_js()?;
+            let result: JsValue = serde_wasm_bindgen::to_value(&response)?;
+            Ok(result)
+        })
+    }
+
     #[wasm_bindgen(js_name = queryEvents)]
     pub async fn query_events(&self, query: &EventQueryWrapper) -> Promise {
         let client = self.0.clone();
@@ -72,6 +186,25 @@ impl LedgerClientWrapper {
         })
     }
 
+    #[wasm_bindgen(js_name = queryEventsWithPagination)]
+    pub async fn query_events_with_pagination(
+        &self,
+        query: &EventQueryWrapper,
+        pagination: JsValue,
+    ) -> Promise {
+        let client = self.0.clone();
+        let query = query.0.clone();
+        future_to_promise(async move {
+            let pagination: EventPagination = serde_wasm_bindgen::from_value(pagination)?;
+            let response = client
+                .query_events_with_pagination(&query, &pagination)
+                .await
+                .as_js()?;
+            let result: JsValue = serde_wasm_bindgen::to_value(&response)?;
+            Ok(result)
+        })
+    }
+
     #[wasm_bindgen(js_name = getReceipt)]
     pub async fn get_receipt(&self, hash: Vec<u8>) -> Promise {
         let client = self.0.clone();
@@ -82,3 +215,11 @@ impl LedgerClientWrapper {
         })
     }
 }
+
+/// Get field of the options object. Returns `undefined` if options are not passed
+fn get_option(options: &JsValue, name: &str) -> Result<JsValue> {
+    if options.is_undefined() || options.is_null() {
+        return Ok(JsValue::UNDEFINED);
+    }
+    js_sys::Reflect::get(options, &JsValue::from_str(name))
+}
@@ -1,4 +1,7 @@
-use indy_besu_vdr::{SignatureData, Transaction, TransactionEndorsingData};
+use indy_besu_vdr::{
+    BlockTag, SignatureData, Transaction, TransactionEndorsingData, TransactionEnvelope,
+    TransactionFees,
+};
 use std::cell::RefCell;
 use wasm_bindgen::prelude::*;
 
@@ -25,6 +28,38 @@ impl TransactionWrapper {
         self.0.get_mut().set_signature(signature_data);
         Ok(())
     }
+
+    #[wasm_bindgen(js_name = recoverSigner)]
+    pub fn recover_signer(&self) -> Result<String> {
+        let account = self.0.borrow().recover_signer().as_js()?;
+        Ok(account.to_string())
+    }
+
+    pub fn verify(&self) -> Result<()> {
+        self.0.borrow().verify().as_js()?;
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = setEnvelope)]
+    pub fn set_envelope(&mut self, envelope: JsValue) -> Result<()> {
+        let envelope: TransactionEnvelope = serde_wasm_bindgen::from_value(envelope)?;
+        self.0.get_mut().set_envelope(envelope);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = setBlockTag)]
+    pub fn set_block_tag(&mut self, block_tag: JsValue) -> Result<()> {
+        let block_tag: BlockTag = serde_wasm_bindgen::from_value(block_tag)?;
+        self.0.get_mut().set_block_tag(block_tag);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = setFees)]
+    pub fn set_fees(&mut self, fees: JsValue) -> Result<()> {
+        let fees: TransactionFees = serde_wasm_bindgen::from_value(fees)?;
+        self.0.get_mut().set_fees(fees);
+        Ok(())
+    }
 }
 
 impl From<Transaction> for TransactionWrapper {
@@ -50,6 +85,17 @@ impl TransactionEndorsingDataWrapper {
         self.0.get_mut().set_signature(signature_data);
         Ok(())
     }
+
+    #[wasm_bindgen(js_name = recoverSigner)]
+    pub fn recover_signer(&self) -> Result<String> {
+        let account = self.0.borrow().recover_signer().as_js()?;
+        Ok(account.to_string())
+    }
+
+    pub fn verify(&self) -> Result<()> {
+        self.0.borrow().verify().as_js()?;
+        Ok(())
+    }
 }
 
 impl From<TransactionEndorsingData> for TransactionEndorsingDataWrapper {
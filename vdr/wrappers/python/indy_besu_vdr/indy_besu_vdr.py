@@ -615,24 +615,48 @@ def _uniffi_check_api_checksums(lib):
         raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     if lib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_endorsing_data_get_signing_bytes() != 44811:
         raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_endorsing_data_recover_signer() != 35958:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     if lib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_endorsing_data_to_string() != 63969:
         raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_endorsing_data_verify() != 48107:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     if lib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_from_string() != 62148:
         raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     if lib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_get_signing_bytes() != 41505:
         raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_recover_signer() != 39051:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     if lib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_to_string() != 21025:
         raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    if lib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_get_receipt() != 56453:
+    if lib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_verify() != 37828:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_get_receipt() != 54955:
         raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     if lib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_ping() != 64834:
         raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     if lib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_query_events() != 64611:
         raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_query_events_with_pagination() != 9802:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_simulate_transaction() != 39942:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     if lib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_submit_transaction() != 22126:
         raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_submit_transaction_with_policy() != 12701:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_verify() != 48967:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     if lib.uniffi_indy_besu_vdr_uniffi_checksum_constructor_ledgerclient_new() != 954:
         raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_indy_besu_vdr_uniffi_checksum_constructor_ledgerclient_new_with_connection() != 45656:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_indy_besu_vdr_uniffi_checksum_constructor_ledgerclient_new_with_options() != 15635:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_indy_besu_vdr_uniffi_checksum_constructor_ledgerclient_new_with_token_provider() != 8709:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    if lib.uniffi_indy_besu_vdr_uniffi_checksum_method_tokenprovider_token() != 26544:
+        raise InternalError("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
 
 # A ctypes library to expose the extern-C FFI definitions.
 # This is an implementation detail which will be called internally by the public API.
@@ -657,6 +681,38 @@ _UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_constructor_ledgerclient_new.argtypes
     ctypes.POINTER(_UniffiRustCallStatus),
 )
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_constructor_ledgerclient_new.restype = ctypes.c_void_p
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_constructor_ledgerclient_new_with_connection.argtypes = (
+    ctypes.c_uint64,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_constructor_ledgerclient_new_with_connection.restype = ctypes.c_void_p
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_constructor_ledgerclient_new_with_options.argtypes = (
+    ctypes.c_uint64,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_constructor_ledgerclient_new_with_options.restype = ctypes.c_void_p
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_constructor_ledgerclient_new_with_token_provider.argtypes = (
+    ctypes.c_uint64,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+    ctypes.c_uint64,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_constructor_ledgerclient_new_with_token_provider.restype = ctypes.c_void_p
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_method_ledgerclient_get_receipt.argtypes = (
     ctypes.c_void_p,
     _UniffiRustBuffer,
@@ -671,11 +727,36 @@ _UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_method_ledgerclient_query_events.argty
     _UniffiRustBuffer,
 )
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_method_ledgerclient_query_events.restype = ctypes.c_void_p
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_method_ledgerclient_query_events_with_pagination.argtypes = (
+    ctypes.c_void_p,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_method_ledgerclient_query_events_with_pagination.restype = ctypes.c_void_p
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_method_ledgerclient_simulate_transaction.argtypes = (
+    ctypes.c_void_p,
+    _UniffiRustBuffer,
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_method_ledgerclient_simulate_transaction.restype = ctypes.c_void_p
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_method_ledgerclient_submit_transaction.argtypes = (
     ctypes.c_void_p,
     _UniffiRustBuffer,
 )
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_method_ledgerclient_submit_transaction.restype = ctypes.c_void_p
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_method_ledgerclient_submit_transaction_with_policy.argtypes = (
+    ctypes.c_void_p,
+    _UniffiRustBuffer,
+    _UniffiRustBuffer,
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_method_ledgerclient_submit_transaction_with_policy.restype = ctypes.c_void_p
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_method_ledgerclient_verify.argtypes = (
+    ctypes.c_void_p,
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_method_ledgerclient_verify.restype = ctypes.c_void_p
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_init_callback_tokenprovider.argtypes = (
+    _UNIFFI_FOREIGN_CALLBACK_T,
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_init_callback_tokenprovider.restype = None
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_func_build_add_validator_transaction.argtypes = (
     ctypes.c_void_p,
     _UniffiRustBuffer,
@@ -1106,11 +1187,21 @@ _UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_func_transaction_endorsing_data_get_si
     ctypes.POINTER(_UniffiRustCallStatus),
 )
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_func_transaction_endorsing_data_get_signing_bytes.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_func_transaction_endorsing_data_recover_signer.argtypes = (
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_func_transaction_endorsing_data_recover_signer.restype = _UniffiRustBuffer
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_func_transaction_endorsing_data_to_string.argtypes = (
     _UniffiRustBuffer,
     ctypes.POINTER(_UniffiRustCallStatus),
 )
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_func_transaction_endorsing_data_to_string.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_func_transaction_endorsing_data_verify.argtypes = (
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_func_transaction_endorsing_data_verify.restype = None
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_func_transaction_from_string.argtypes = (
     _UniffiRustBuffer,
     ctypes.POINTER(_UniffiRustCallStatus),
@@ -1121,11 +1212,21 @@ _UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_func_transaction_get_signing_bytes.arg
     ctypes.POINTER(_UniffiRustCallStatus),
 )
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_func_transaction_get_signing_bytes.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_func_transaction_recover_signer.argtypes = (
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_func_transaction_recover_signer.restype = _UniffiRustBuffer
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_func_transaction_to_string.argtypes = (
     _UniffiRustBuffer,
     ctypes.POINTER(_UniffiRustCallStatus),
 )
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_func_transaction_to_string.restype = _UniffiRustBuffer
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_func_transaction_verify.argtypes = (
+    _UniffiRustBuffer,
+    ctypes.POINTER(_UniffiRustCallStatus),
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_func_transaction_verify.restype = None
 _UniffiLib.ffi_indy_besu_vdr_uniffi_rustbuffer_alloc.argtypes = (
     ctypes.c_int32,
     ctypes.POINTER(_UniffiRustCallStatus),
@@ -1601,18 +1702,30 @@ _UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_endorsing_data_
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_endorsing_data_get_signing_bytes.argtypes = (
 )
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_endorsing_data_get_signing_bytes.restype = ctypes.c_uint16
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_endorsing_data_recover_signer.argtypes = (
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_endorsing_data_recover_signer.restype = ctypes.c_uint16
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_endorsing_data_to_string.argtypes = (
 )
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_endorsing_data_to_string.restype = ctypes.c_uint16
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_endorsing_data_verify.argtypes = (
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_endorsing_data_verify.restype = ctypes.c_uint16
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_from_string.argtypes = (
 )
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_from_string.restype = ctypes.c_uint16
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_get_signing_bytes.argtypes = (
 )
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_get_signing_bytes.restype = ctypes.c_uint16
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_recover_signer.argtypes = (
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_recover_signer.restype = ctypes.c_uint16
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_to_string.argtypes = (
 )
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_to_string.restype = ctypes.c_uint16
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_verify.argtypes = (
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_func_transaction_verify.restype = ctypes.c_uint16
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_get_receipt.argtypes = (
 )
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_get_receipt.restype = ctypes.c_uint16
@@ -1622,12 +1735,36 @@ _UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_ping.restype
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_query_events.argtypes = (
 )
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_query_events.restype = ctypes.c_uint16
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_query_events_with_pagination.argtypes = (
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_query_events_with_pagination.restype = ctypes.c_uint16
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_simulate_transaction.argtypes = (
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_simulate_transaction.restype = ctypes.c_uint16
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_submit_transaction.argtypes = (
 )
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_submit_transaction.restype = ctypes.c_uint16
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_submit_transaction_with_policy.argtypes = (
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_submit_transaction_with_policy.restype = ctypes.c_uint16
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_verify.argtypes = (
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_method_ledgerclient_verify.restype = ctypes.c_uint16
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_constructor_ledgerclient_new.argtypes = (
 )
 _UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_constructor_ledgerclient_new.restype = ctypes.c_uint16
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_constructor_ledgerclient_new_with_connection.argtypes = (
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_constructor_ledgerclient_new_with_connection.restype = ctypes.c_uint16
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_constructor_ledgerclient_new_with_options.argtypes = (
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_constructor_ledgerclient_new_with_options.restype = ctypes.c_uint16
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_constructor_ledgerclient_new_with_token_provider.argtypes = (
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_constructor_ledgerclient_new_with_token_provider.restype = ctypes.c_uint16
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_method_tokenprovider_token.argtypes = (
+)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_checksum_method_tokenprovider_token.restype = ctypes.c_uint16
 _UniffiLib.ffi_indy_besu_vdr_uniffi_uniffi_contract_version.argtypes = (
 )
 _UniffiLib.ffi_indy_besu_vdr_uniffi_uniffi_contract_version.restype = ctypes.c_uint32
@@ -1690,6 +1827,19 @@ class _UniffiConverterUInt8(_UniffiConverterPrimitiveInt):
     def write(value, buf):
         buf.write_u8(value)
 
+class _UniffiConverterUInt16(_UniffiConverterPrimitiveInt):
+    CLASS_NAME = "u16"
+    VALUE_MIN = 0
+    VALUE_MAX = 2**16
+
+    @staticmethod
+    def read(buf):
+        return buf.read_u16()
+
+    @staticmethod
+    def write(value, buf):
+        buf.write_u16(value)
+
 class _UniffiConverterUInt64(_UniffiConverterPrimitiveInt):
     CLASS_NAME = "u64"
     VALUE_MIN = 0
@@ -1703,6 +1853,15 @@ class _UniffiConverterUInt64(_UniffiConverterPrimitiveInt):
     def write(value, buf):
         buf.write_u64(value)
 
+class _UniffiConverterDouble(_UniffiConverterPrimitiveFloat):
+    @staticmethod
+    def read(buf):
+        return buf.read_double()
+
+    @staticmethod
+    def write(value, buf):
+        buf.write_double(value)
+
 class _UniffiConverterBool:
     @classmethod
     def check_lower(cls, value):
@@ -1780,13 +1939,25 @@ class _UniffiConverterBytes(_UniffiConverterRustBuffer):
 
 class LedgerClientProtocol(typing.Protocol):
     def get_receipt(self, hash: "bytes"):
+        """
+        Get the receipt of the executed transaction serialized as JSON string
+        """
+
         raise NotImplementedError
     def ping(self, ):
         raise NotImplementedError
     def query_events(self, query: "EventQuery"):
         raise NotImplementedError
+    def query_events_with_pagination(self, query: "EventQuery",pagination: "EventPagination"):
+        raise NotImplementedError
+    def simulate_transaction(self, transaction: "Transaction"):
+        raise NotImplementedError
     def submit_transaction(self, transaction: "Transaction"):
         raise NotImplementedError
+    def submit_transaction_with_policy(self, transaction: "Transaction",policy: "ConfirmationPolicy"):
+        raise NotImplementedError
+    def verify(self, ):
+        raise NotImplementedError
 
 class LedgerClient:
 
@@ -1827,8 +1998,93 @@ class LedgerClient:
         inst._pointer = pointer
         return inst
 
+    @classmethod
+    def new_with_connection(cls, chain_id: "int",node_address: "str",contract_configs: "typing.List[ContractConfig]",network: "typing.Optional[str]",quorum_config: "typing.Optional[QuorumConfig]",connection_config: "ConnectionConfig"):
+        _UniffiConverterUInt64.check_lower(chain_id)
+        
+        _UniffiConverterString.check_lower(node_address)
+        
+        _UniffiConverterSequenceTypeContractConfig.check_lower(contract_configs)
+        
+        _UniffiConverterOptionalString.check_lower(network)
+        
+        _UniffiConverterOptionalTypeQuorumConfig.check_lower(quorum_config)
+        
+        _UniffiConverterTypeConnectionConfig.check_lower(connection_config)
+        
+        # Call the (fallible) function before creating any half-baked object instances.
+        pointer = _rust_call_with_error(_UniffiConverterTypeVdrError,_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_constructor_ledgerclient_new_with_connection,
+        _UniffiConverterUInt64.lower(chain_id),
+        _UniffiConverterString.lower(node_address),
+        _UniffiConverterSequenceTypeContractConfig.lower(contract_configs),
+        _UniffiConverterOptionalString.lower(network),
+        _UniffiConverterOptionalTypeQuorumConfig.lower(quorum_config),
+        _UniffiConverterTypeConnectionConfig.lower(connection_config))
+        return cls._make_instance_(pointer)
+
+
+    @classmethod
+    def new_with_options(cls, chain_id: "int",node_address: "str",contract_configs: "typing.List[ContractConfig]",network: "typing.Optional[str]",quorum_config: "typing.Optional[QuorumConfig]",connection_config: "typing.Optional[ConnectionConfig]",options: "LedgerClientOptions"):
+        _UniffiConverterUInt64.check_lower(chain_id)
+        
+        _UniffiConverterString.check_lower(node_address)
+        
+        _UniffiConverterSequenceTypeContractConfig.check_lower(contract_configs)
+        
+        _UniffiConverterOptionalString.check_lower(network)
+        
+        _UniffiConverterOptionalTypeQuorumConfig.check_lower(quorum_config)
+        
+        _UniffiConverterOptionalTypeConnectionConfig.check_lower(connection_config)
+        
+        _UniffiConverterTypeLedgerClientOptions.check_lower(options)
+        
+        # Call the (fallible) function before creating any half-baked object instances.
+        pointer = _rust_call_with_error(_UniffiConverterTypeVdrError,_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_constructor_ledgerclient_new_with_options,
+        _UniffiConverterUInt64.lower(chain_id),
+        _UniffiConverterString.lower(node_address),
+        _UniffiConverterSequenceTypeContractConfig.lower(contract_configs),
+        _UniffiConverterOptionalString.lower(network),
+        _UniffiConverterOptionalTypeQuorumConfig.lower(quorum_config),
+        _UniffiConverterOptionalTypeConnectionConfig.lower(connection_config),
+        _UniffiConverterTypeLedgerClientOptions.lower(options))
+        return cls._make_instance_(pointer)
+
+
+    @classmethod
+    def new_with_token_provider(cls, chain_id: "int",node_address: "str",contract_configs: "typing.List[ContractConfig]",network: "typing.Optional[str]",quorum_config: "typing.Optional[QuorumConfig]",connection_config: "ConnectionConfig",token_provider: "TokenProvider"):
+        _UniffiConverterUInt64.check_lower(chain_id)
+        
+        _UniffiConverterString.check_lower(node_address)
+        
+        _UniffiConverterSequenceTypeContractConfig.check_lower(contract_configs)
+        
+        _UniffiConverterOptionalString.check_lower(network)
+        
+        _UniffiConverterOptionalTypeQuorumConfig.check_lower(quorum_config)
+        
+        _UniffiConverterTypeConnectionConfig.check_lower(connection_config)
+        
+        _UniffiConverterCallbackInterfaceTokenProvider.check_lower(token_provider)
+        
+        # Call the (fallible) function before creating any half-baked object instances.
+        pointer = _rust_call_with_error(_UniffiConverterTypeVdrError,_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_constructor_ledgerclient_new_with_token_provider,
+        _UniffiConverterUInt64.lower(chain_id),
+        _UniffiConverterString.lower(node_address),
+        _UniffiConverterSequenceTypeContractConfig.lower(contract_configs),
+        _UniffiConverterOptionalString.lower(network),
+        _UniffiConverterOptionalTypeQuorumConfig.lower(quorum_config),
+        _UniffiConverterTypeConnectionConfig.lower(connection_config),
+        _UniffiConverterCallbackInterfaceTokenProvider.lower(token_provider))
+        return cls._make_instance_(pointer)
+
+
 
     def get_receipt(self, hash: "bytes"):
+        """
+        Get the receipt of the executed transaction serialized as JSON string
+        """
+
         _UniffiConverterBytes.check_lower(hash)
         
         return _uniffi_rust_call_async(
@@ -1888,6 +2144,51 @@ class LedgerClient:
 
 
 
+    def query_events_with_pagination(self, query: "EventQuery",pagination: "EventPagination"):
+        _UniffiConverterTypeEventQuery.check_lower(query)
+        
+        _UniffiConverterTypeEventPagination.check_lower(pagination)
+        
+        return _uniffi_rust_call_async(
+            _UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_method_ledgerclient_query_events_with_pagination(
+                self._uniffi_clone_pointer(), 
+        _UniffiConverterTypeEventQuery.lower(query),
+        _UniffiConverterTypeEventPagination.lower(pagination)
+            ),
+            _UniffiLib.ffi_indy_besu_vdr_uniffi_rust_future_poll_rust_buffer,
+            _UniffiLib.ffi_indy_besu_vdr_uniffi_rust_future_complete_rust_buffer,
+            _UniffiLib.ffi_indy_besu_vdr_uniffi_rust_future_free_rust_buffer,
+            # lift function
+            _UniffiConverterSequenceTypeEventLog.lift,
+            # Error FFI converter
+            _UniffiConverterTypeVdrError,
+        )
+
+
+
+
+
+    def simulate_transaction(self, transaction: "Transaction"):
+        _UniffiConverterTypeTransaction.check_lower(transaction)
+        
+        return _uniffi_rust_call_async(
+            _UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_method_ledgerclient_simulate_transaction(
+                self._uniffi_clone_pointer(), 
+        _UniffiConverterTypeTransaction.lower(transaction)
+            ),
+            _UniffiLib.ffi_indy_besu_vdr_uniffi_rust_future_poll_rust_buffer,
+            _UniffiLib.ffi_indy_besu_vdr_uniffi_rust_future_complete_rust_buffer,
+            _UniffiLib.ffi_indy_besu_vdr_uniffi_rust_future_free_rust_buffer,
+            # lift function
+            _UniffiConverterTypeSimulationResult.lift,
+            # Error FFI converter
+            _UniffiConverterTypeVdrError,
+        )
+
+
+
+
+
     def submit_transaction(self, transaction: "Transaction"):
         _UniffiConverterTypeTransaction.check_lower(transaction)
         
@@ -1908,6 +2209,48 @@ class LedgerClient:
 
 
 
+
+    def submit_transaction_with_policy(self, transaction: "Transaction",policy: "ConfirmationPolicy"):
+        _UniffiConverterTypeTransaction.check_lower(transaction)
+        
+        _UniffiConverterTypeConfirmationPolicy.check_lower(policy)
+        
+        return _uniffi_rust_call_async(
+            _UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_method_ledgerclient_submit_transaction_with_policy(
+                self._uniffi_clone_pointer(), 
+        _UniffiConverterTypeTransaction.lower(transaction),
+        _UniffiConverterTypeConfirmationPolicy.lower(policy)
+            ),
+            _UniffiLib.ffi_indy_besu_vdr_uniffi_rust_future_poll_rust_buffer,
+            _UniffiLib.ffi_indy_besu_vdr_uniffi_rust_future_complete_rust_buffer,
+            _UniffiLib.ffi_indy_besu_vdr_uniffi_rust_future_free_rust_buffer,
+            # lift function
+            _UniffiConverterBytes.lift,
+            # Error FFI converter
+            _UniffiConverterTypeVdrError,
+        )
+
+
+
+
+
+    def verify(self, ):
+        return _uniffi_rust_call_async(
+            _UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_method_ledgerclient_verify(
+                self._uniffi_clone_pointer(), 
+            ),
+            _UniffiLib.ffi_indy_besu_vdr_uniffi_rust_future_poll_rust_buffer,
+            _UniffiLib.ffi_indy_besu_vdr_uniffi_rust_future_complete_rust_buffer,
+            _UniffiLib.ffi_indy_besu_vdr_uniffi_rust_future_free_rust_buffer,
+            # lift function
+            _UniffiConverterTypeVerificationReport.lift,
+            # Error FFI converter
+            _UniffiConverterTypeVdrError,
+        )
+
+
+
+
 class _UniffiConverterTypeLedgerClient:
 
     @staticmethod
@@ -1937,72 +2280,248 @@ class _UniffiConverterTypeLedgerClient:
         buf.write_u64(cls.lower(value))
 
 
-class ContractConfig:
+class AccessListItem:
     address: "str"
-    spec_path: "typing.Optional[str]"
-    spec: "typing.Optional[ContractSpec]"
+    storage_keys: "typing.List[bytes]"
     @typing.no_type_check
-    def __init__(self, address: "str", spec_path: "typing.Optional[str]", spec: "typing.Optional[ContractSpec]"):
+    def __init__(self, address: "str", storage_keys: "typing.List[bytes]"):
         self.address = address
-        self.spec_path = spec_path
-        self.spec = spec
+        self.storage_keys = storage_keys
 
     def __str__(self):
-        return "ContractConfig(address={}, spec_path={}, spec={})".format(self.address, self.spec_path, self.spec)
+        return "AccessListItem(address={}, storage_keys={})".format(self.address, self.storage_keys)
 
     def __eq__(self, other):
         if self.address != other.address:
             return False
-        if self.spec_path != other.spec_path:
-            return False
-        if self.spec != other.spec:
+        if self.storage_keys != other.storage_keys:
             return False
         return True
 
-class _UniffiConverterTypeContractConfig(_UniffiConverterRustBuffer):
+class _UniffiConverterTypeAccessListItem(_UniffiConverterRustBuffer):
     @staticmethod
     def read(buf):
-        return ContractConfig(
+        return AccessListItem(
             address=_UniffiConverterString.read(buf),
-            spec_path=_UniffiConverterOptionalString.read(buf),
-            spec=_UniffiConverterOptionalTypeContractSpec.read(buf),
+            storage_keys=_UniffiConverterSequenceBytes.read(buf),
         )
 
     @staticmethod
     def check_lower(value):
         _UniffiConverterString.check_lower(value.address)
-        _UniffiConverterOptionalString.check_lower(value.spec_path)
-        _UniffiConverterOptionalTypeContractSpec.check_lower(value.spec)
+        _UniffiConverterSequenceBytes.check_lower(value.storage_keys)
 
     @staticmethod
     def write(value, buf):
         _UniffiConverterString.write(value.address, buf)
-        _UniffiConverterOptionalString.write(value.spec_path, buf)
-        _UniffiConverterOptionalTypeContractSpec.write(value.spec, buf)
+        _UniffiConverterSequenceBytes.write(value.storage_keys, buf)
 
 
-class ContractSpec:
-    name: "str"
-    abi: "JsonValue"
+class BasicAuth:
+    username: "str"
+    password: "typing.Optional[str]"
     @typing.no_type_check
-    def __init__(self, name: "str", abi: "JsonValue"):
-        self.name = name
-        self.abi = abi
+    def __init__(self, username: "str", password: "typing.Optional[str]"):
+        self.username = username
+        self.password = password
 
     def __str__(self):
-        return "ContractSpec(name={}, abi={})".format(self.name, self.abi)
+        return "BasicAuth(username={}, password={})".format(self.username, self.password)
 
     def __eq__(self, other):
-        if self.name != other.name:
+        if self.username != other.username:
             return False
-        if self.abi != other.abi:
+        if self.password != other.password:
             return False
         return True
 
-class _UniffiConverterTypeContractSpec(_UniffiConverterRustBuffer):
+class _UniffiConverterTypeBasicAuth(_UniffiConverterRustBuffer):
     @staticmethod
     def read(buf):
-        return ContractSpec(
+        return BasicAuth(
+            username=_UniffiConverterString.read(buf),
+            password=_UniffiConverterOptionalString.read(buf),
+        )
+
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterString.check_lower(value.username)
+        _UniffiConverterOptionalString.check_lower(value.password)
+
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterString.write(value.username, buf)
+        _UniffiConverterOptionalString.write(value.password, buf)
+
+
+class ConfirmationPolicy:
+    confirmations: "typing.Optional[int]"
+    poll_interval: "typing.Optional[int]"
+    timeout: "typing.Optional[int]"
+    instant_finality: "typing.Optional[bool]"
+    @typing.no_type_check
+    def __init__(self, confirmations: "typing.Optional[int]", poll_interval: "typing.Optional[int]", timeout: "typing.Optional[int]", instant_finality: "typing.Optional[bool]"):
+        self.confirmations = confirmations
+        self.poll_interval = poll_interval
+        self.timeout = timeout
+        self.instant_finality = instant_finality
+
+    def __str__(self):
+        return "ConfirmationPolicy(confirmations={}, poll_interval={}, timeout={}, instant_finality={})".format(self.confirmations, self.poll_interval, self.timeout, self.instant_finality)
+
+    def __eq__(self, other):
+        if self.confirmations != other.confirmations:
+            return False
+        if self.poll_interval != other.poll_interval:
+            return False
+        if self.timeout != other.timeout:
+            return False
+        if self.instant_finality != other.instant_finality:
+            return False
+        return True
+
+class _UniffiConverterTypeConfirmationPolicy(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return ConfirmationPolicy(
+            confirmations=_UniffiConverterOptionalUInt64.read(buf),
+            poll_interval=_UniffiConverterOptionalUInt64.read(buf),
+            timeout=_UniffiConverterOptionalUInt64.read(buf),
+            instant_finality=_UniffiConverterOptionalBool.read(buf),
+        )
+
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterOptionalUInt64.check_lower(value.confirmations)
+        _UniffiConverterOptionalUInt64.check_lower(value.poll_interval)
+        _UniffiConverterOptionalUInt64.check_lower(value.timeout)
+        _UniffiConverterOptionalBool.check_lower(value.instant_finality)
+
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterOptionalUInt64.write(value.confirmations, buf)
+        _UniffiConverterOptionalUInt64.write(value.poll_interval, buf)
+        _UniffiConverterOptionalUInt64.write(value.timeout, buf)
+        _UniffiConverterOptionalBool.write(value.instant_finality, buf)
+
+
+class ConnectionConfig:
+    headers: "typing.Optional[dict]"
+    bearer_token: "typing.Optional[str]"
+    basic_auth: "typing.Optional[BasicAuth]"
+    tls: "typing.Optional[TlsConfig]"
+    @typing.no_type_check
+    def __init__(self, headers: "typing.Optional[dict]", bearer_token: "typing.Optional[str]", basic_auth: "typing.Optional[BasicAuth]", tls: "typing.Optional[TlsConfig]"):
+        self.headers = headers
+        self.bearer_token = bearer_token
+        self.basic_auth = basic_auth
+        self.tls = tls
+
+    def __str__(self):
+        return "ConnectionConfig(headers={}, bearer_token={}, basic_auth={}, tls={})".format(self.headers, self.bearer_token, self.basic_auth, self.tls)
+
+    def __eq__(self, other):
+        if self.headers != other.headers:
+            return False
+        if self.bearer_token != other.bearer_token:
+            return False
+        if self.basic_auth != other.basic_auth:
+            return False
+        if self.tls != other.tls:
+            return False
+        return True
+
+class _UniffiConverterTypeConnectionConfig(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return ConnectionConfig(
+            headers=_UniffiConverterOptionalMapStringString.read(buf),
+            bearer_token=_UniffiConverterOptionalString.read(buf),
+            basic_auth=_UniffiConverterOptionalTypeBasicAuth.read(buf),
+            tls=_UniffiConverterOptionalTypeTlsConfig.read(buf),
+        )
+
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterOptionalMapStringString.check_lower(value.headers)
+        _UniffiConverterOptionalString.check_lower(value.bearer_token)
+        _UniffiConverterOptionalTypeBasicAuth.check_lower(value.basic_auth)
+        _UniffiConverterOptionalTypeTlsConfig.check_lower(value.tls)
+
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterOptionalMapStringString.write(value.headers, buf)
+        _UniffiConverterOptionalString.write(value.bearer_token, buf)
+        _UniffiConverterOptionalTypeBasicAuth.write(value.basic_auth, buf)
+        _UniffiConverterOptionalTypeTlsConfig.write(value.tls, buf)
+
+
+class ContractConfig:
+    address: "str"
+    spec_path: "typing.Optional[str]"
+    spec: "typing.Optional[ContractSpec]"
+    @typing.no_type_check
+    def __init__(self, address: "str", spec_path: "typing.Optional[str]", spec: "typing.Optional[ContractSpec]"):
+        self.address = address
+        self.spec_path = spec_path
+        self.spec = spec
+
+    def __str__(self):
+        return "ContractConfig(address={}, spec_path={}, spec={})".format(self.address, self.spec_path, self.spec)
+
+    def __eq__(self, other):
+        if self.address != other.address:
+            return False
+        if self.spec_path != other.spec_path:
+            return False
+        if self.spec != other.spec:
+            return False
+        return True
+
+class _UniffiConverterTypeContractConfig(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return ContractConfig(
+            address=_UniffiConverterString.read(buf),
+            spec_path=_UniffiConverterOptionalString.read(buf),
+            spec=_UniffiConverterOptionalTypeContractSpec.read(buf),
+        )
+
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterString.check_lower(value.address)
+        _UniffiConverterOptionalString.check_lower(value.spec_path)
+        _UniffiConverterOptionalTypeContractSpec.check_lower(value.spec)
+
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterString.write(value.address, buf)
+        _UniffiConverterOptionalString.write(value.spec_path, buf)
+        _UniffiConverterOptionalTypeContractSpec.write(value.spec, buf)
+
+
+class ContractSpec:
+    name: "str"
+    abi: "JsonValue"
+    @typing.no_type_check
+    def __init__(self, name: "str", abi: "JsonValue"):
+        self.name = name
+        self.abi = abi
+
+    def __str__(self):
+        return "ContractSpec(name={}, abi={})".format(self.name, self.abi)
+
+    def __eq__(self, other):
+        if self.name != other.name:
+            return False
+        if self.abi != other.abi:
+            return False
+        return True
+
+class _UniffiConverterTypeContractSpec(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return ContractSpec(
             name=_UniffiConverterString.read(buf),
             abi=_UniffiConverterTypeJsonValue.read(buf),
         )
@@ -2317,22 +2836,90 @@ class _UniffiConverterTypeEventLog(_UniffiConverterRustBuffer):
         _UniffiConverterUInt64.write(value.block, buf)
 
 
+class EventPagination:
+    chunk_size: "typing.Optional[int]"
+    min_chunk_size: "typing.Optional[int]"
+    @typing.no_type_check
+    def __init__(self, chunk_size: "typing.Optional[int]", min_chunk_size: "typing.Optional[int]"):
+        self.chunk_size = chunk_size
+        self.min_chunk_size = min_chunk_size
+
+    def __str__(self):
+        return "EventPagination(chunk_size={}, min_chunk_size={})".format(self.chunk_size, self.min_chunk_size)
+
+    def __eq__(self, other):
+        if self.chunk_size != other.chunk_size:
+            return False
+        if self.min_chunk_size != other.min_chunk_size:
+            return False
+        return True
+
+class _UniffiConverterTypeEventPagination(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return EventPagination(
+            chunk_size=_UniffiConverterOptionalUInt64.read(buf),
+            min_chunk_size=_UniffiConverterOptionalUInt64.read(buf),
+        )
+
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterOptionalUInt64.check_lower(value.chunk_size)
+        _UniffiConverterOptionalUInt64.check_lower(value.min_chunk_size)
+
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterOptionalUInt64.write(value.chunk_size, buf)
+        _UniffiConverterOptionalUInt64.write(value.min_chunk_size, buf)
+
+
 class EventQuery:
-    address: "str"
+    """
+    Event query. `addresses` and `topics` describe the query in full.
+    Previous fields `address`, `event_signature` and `event_filter` (the first indexed event parameter) are still
+    accepted and merged into them.
+    """
+
+    address: "typing.Optional[str]"
     from_block: "typing.Optional[int]"
     to_block: "typing.Optional[int]"
     event_signature: "typing.Optional[str]"
     event_filter: "typing.Optional[str]"
+    addresses: "typing.Optional[typing.List[str]]"
+    topics: "typing.Optional[typing.List[typing.Optional[typing.List[str]]]]"
     @typing.no_type_check
-    def __init__(self, address: "str", from_block: "typing.Optional[int]", to_block: "typing.Optional[int]", event_signature: "typing.Optional[str]", event_filter: "typing.Optional[str]"):
-        self.address = address
-        self.from_block = from_block
-        self.to_block = to_block
-        self.event_signature = event_signature
-        self.event_filter = event_filter
+    def __init__(self, address: "typing.Optional[str]" = _DEFAULT, from_block: "typing.Optional[int]" = _DEFAULT, to_block: "typing.Optional[int]" = _DEFAULT, event_signature: "typing.Optional[str]" = _DEFAULT, event_filter: "typing.Optional[str]" = _DEFAULT, addresses: "typing.Optional[typing.List[str]]" = _DEFAULT, topics: "typing.Optional[typing.List[typing.Optional[typing.List[str]]]]" = _DEFAULT):
+        if address is _DEFAULT:
+            self.address = None
+        else:
+            self.address = address
+        if from_block is _DEFAULT:
+            self.from_block = None
+        else:
+            self.from_block = from_block
+        if to_block is _DEFAULT:
+            self.to_block = None
+        else:
+            self.to_block = to_block
+        if event_signature is _DEFAULT:
+            self.event_signature = None
+        else:
+            self.event_signature = event_signature
+        if event_filter is _DEFAULT:
+            self.event_filter = None
+        else:
+            self.event_filter = event_filter
+        if addresses is _DEFAULT:
+            self.addresses = None
+        else:
+            self.addresses = addresses
+        if topics is _DEFAULT:
+            self.topics = None
+        else:
+            self.topics = topics
 
     def __str__(self):
-        return "EventQuery(address={}, from_block={}, to_block={}, event_signature={}, event_filter={})".format(self.address, self.from_block, self.to_block, self.event_signature, self.event_filter)
+        return "EventQuery(address={}, from_block={}, to_block={}, event_signature={}, event_filter={}, addresses={}, topics={})".format(self.address, self.from_block, self.to_block, self.event_signature, self.event_filter, self.addresses, self.topics)
 
     def __eq__(self, other):
         if self.address != other.address:
@@ -2345,34 +2932,99 @@ class EventQuery:
             return False
         if self.event_filter != other.event_filter:
             return False
+        if self.addresses != other.addresses:
+            return False
+        if self.topics != other.topics:
+            return False
         return True
 
 class _UniffiConverterTypeEventQuery(_UniffiConverterRustBuffer):
     @staticmethod
     def read(buf):
         return EventQuery(
-            address=_UniffiConverterString.read(buf),
+            address=_UniffiConverterOptionalString.read(buf),
             from_block=_UniffiConverterOptionalUInt64.read(buf),
             to_block=_UniffiConverterOptionalUInt64.read(buf),
             event_signature=_UniffiConverterOptionalString.read(buf),
             event_filter=_UniffiConverterOptionalString.read(buf),
+            addresses=_UniffiConverterOptionalSequenceString.read(buf),
+            topics=_UniffiConverterOptionalSequenceOptionalSequenceString.read(buf),
         )
 
     @staticmethod
     def check_lower(value):
-        _UniffiConverterString.check_lower(value.address)
+        _UniffiConverterOptionalString.check_lower(value.address)
         _UniffiConverterOptionalUInt64.check_lower(value.from_block)
         _UniffiConverterOptionalUInt64.check_lower(value.to_block)
         _UniffiConverterOptionalString.check_lower(value.event_signature)
         _UniffiConverterOptionalString.check_lower(value.event_filter)
+        _UniffiConverterOptionalSequenceString.check_lower(value.addresses)
+        _UniffiConverterOptionalSequenceOptionalSequenceString.check_lower(value.topics)
 
     @staticmethod
     def write(value, buf):
-        _UniffiConverterString.write(value.address, buf)
+        _UniffiConverterOptionalString.write(value.address, buf)
         _UniffiConverterOptionalUInt64.write(value.from_block, buf)
         _UniffiConverterOptionalUInt64.write(value.to_block, buf)
         _UniffiConverterOptionalString.write(value.event_signature, buf)
         _UniffiConverterOptionalString.write(value.event_filter, buf)
+        _UniffiConverterOptionalSequenceString.write(value.addresses, buf)
+        _UniffiConverterOptionalSequenceOptionalSequenceString.write(value.topics, buf)
+
+
+class LedgerClientOptions:
+    """
+    Optional settings of the ledger client
+    """
+
+    confirmation_policy: "typing.Optional[ConfirmationPolicy]"
+    """
+    Policy used to wait for write transactions submitted without explicit policy
+    """
+
+    fee_strategy: "typing.Optional[FeeStrategy]"
+    """
+    Strategy defining gas limit and fees of write transactions. Gas free network is assumed if not set
+    """
+
+    @typing.no_type_check
+    def __init__(self, confirmation_policy: "typing.Optional[ConfirmationPolicy]" = _DEFAULT, fee_strategy: "typing.Optional[FeeStrategy]" = _DEFAULT):
+        if confirmation_policy is _DEFAULT:
+            self.confirmation_policy = None
+        else:
+            self.confirmation_policy = confirmation_policy
+        if fee_strategy is _DEFAULT:
+            self.fee_strategy = None
+        else:
+            self.fee_strategy = fee_strategy
+
+    def __str__(self):
+        return "LedgerClientOptions(confirmation_policy={}, fee_strategy={})".format(self.confirmation_policy, self.fee_strategy)
+
+    def __eq__(self, other):
+        if self.confirmation_policy != other.confirmation_policy:
+            return False
+        if self.fee_strategy != other.fee_strategy:
+            return False
+        return True
+
+class _UniffiConverterTypeLedgerClientOptions(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return LedgerClientOptions(
+            confirmation_policy=_UniffiConverterOptionalTypeConfirmationPolicy.read(buf),
+            fee_strategy=_UniffiConverterOptionalTypeFeeStrategy.read(buf),
+        )
+
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterOptionalTypeConfirmationPolicy.check_lower(value.confirmation_policy)
+        _UniffiConverterOptionalTypeFeeStrategy.check_lower(value.fee_strategy)
+
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterOptionalTypeConfirmationPolicy.write(value.confirmation_policy, buf)
+        _UniffiConverterOptionalTypeFeeStrategy.write(value.fee_strategy, buf)
 
 
 class PingStatus:
@@ -2410,15 +3062,25 @@ class QuorumConfig:
     request_retries: "typing.Optional[int]"
     request_timeout: "typing.Optional[int]"
     retry_interval: "typing.Optional[int]"
+    read_policy: "typing.Optional[QuorumPolicy]"
+    write_policy: "typing.Optional[QuorumPolicy]"
+    weights: "typing.Optional[dict]"
+    validator_nodes: "typing.Optional[dict]"
+    connection: "typing.Optional[ConnectionConfig]"
     @typing.no_type_check
-    def __init__(self, nodes: "typing.List[str]", request_retries: "typing.Optional[int]", request_timeout: "typing.Optional[int]", retry_interval: "typing.Optional[int]"):
+    def __init__(self, nodes: "typing.List[str]", request_retries: "typing.Optional[int]", request_timeout: "typing.Optional[int]", retry_interval: "typing.Optional[int]", read_policy: "typing.Optional[QuorumPolicy]", write_policy: "typing.Optional[QuorumPolicy]", weights: "typing.Optional[dict]", validator_nodes: "typing.Optional[dict]", connection: "typing.Optional[ConnectionConfig]"):
         self.nodes = nodes
         self.request_retries = request_retries
         self.request_timeout = request_timeout
         self.retry_interval = retry_interval
+        self.read_policy = read_policy
+        self.write_policy = write_policy
+        self.weights = weights
+        self.validator_nodes = validator_nodes
+        self.connection = connection
 
     def __str__(self):
-        return "QuorumConfig(nodes={}, request_retries={}, request_timeout={}, retry_interval={})".format(self.nodes, self.request_retries, self.request_timeout, self.retry_interval)
+        return "QuorumConfig(nodes={}, request_retries={}, request_timeout={}, retry_interval={}, read_policy={}, write_policy={}, weights={}, validator_nodes={}, connection={})".format(self.nodes, self.request_retries, self.request_timeout, self.retry_interval, self.read_policy, self.write_policy, self.weights, self.validator_nodes, self.connection)
 
     def __eq__(self, other):
         if self.nodes != other.nodes:
@@ -2429,6 +3091,16 @@ class QuorumConfig:
             return False
         if self.retry_interval != other.retry_interval:
             return False
+        if self.read_policy != other.read_policy:
+            return False
+        if self.write_policy != other.write_policy:
+            return False
+        if self.weights != other.weights:
+            return False
+        if self.validator_nodes != other.validator_nodes:
+            return False
+        if self.connection != other.connection:
+            return False
         return True
 
 class _UniffiConverterTypeQuorumConfig(_UniffiConverterRustBuffer):
@@ -2439,6 +3111,11 @@ class _UniffiConverterTypeQuorumConfig(_UniffiConverterRustBuffer):
             request_retries=_UniffiConverterOptionalUInt8.read(buf),
             request_timeout=_UniffiConverterOptionalUInt64.read(buf),
             retry_interval=_UniffiConverterOptionalUInt64.read(buf),
+            read_policy=_UniffiConverterOptionalTypeQuorumPolicy.read(buf),
+            write_policy=_UniffiConverterOptionalTypeQuorumPolicy.read(buf),
+            weights=_UniffiConverterOptionalMapStringUInt64.read(buf),
+            validator_nodes=_UniffiConverterOptionalMapStringString.read(buf),
+            connection=_UniffiConverterOptionalTypeConnectionConfig.read(buf),
         )
 
     @staticmethod
@@ -2447,6 +3124,11 @@ class _UniffiConverterTypeQuorumConfig(_UniffiConverterRustBuffer):
         _UniffiConverterOptionalUInt8.check_lower(value.request_retries)
         _UniffiConverterOptionalUInt64.check_lower(value.request_timeout)
         _UniffiConverterOptionalUInt64.check_lower(value.retry_interval)
+        _UniffiConverterOptionalTypeQuorumPolicy.check_lower(value.read_policy)
+        _UniffiConverterOptionalTypeQuorumPolicy.check_lower(value.write_policy)
+        _UniffiConverterOptionalMapStringUInt64.check_lower(value.weights)
+        _UniffiConverterOptionalMapStringString.check_lower(value.validator_nodes)
+        _UniffiConverterOptionalTypeConnectionConfig.check_lower(value.connection)
 
     @staticmethod
     def write(value, buf):
@@ -2454,6 +3136,11 @@ class _UniffiConverterTypeQuorumConfig(_UniffiConverterRustBuffer):
         _UniffiConverterOptionalUInt8.write(value.request_retries, buf)
         _UniffiConverterOptionalUInt64.write(value.request_timeout, buf)
         _UniffiConverterOptionalUInt64.write(value.retry_interval, buf)
+        _UniffiConverterOptionalTypeQuorumPolicy.write(value.read_policy, buf)
+        _UniffiConverterOptionalTypeQuorumPolicy.write(value.write_policy, buf)
+        _UniffiConverterOptionalMapStringUInt64.write(value.weights, buf)
+        _UniffiConverterOptionalMapStringString.write(value.validator_nodes, buf)
+        _UniffiConverterOptionalTypeConnectionConfig.write(value.connection, buf)
 
 
 class Schema:
@@ -2544,6 +3231,57 @@ class _UniffiConverterTypeSignatureData(_UniffiConverterRustBuffer):
         _UniffiConverterBytes.write(value.signature, buf)
 
 
+class TlsConfig:
+    ca_certificate: "typing.Optional[str]"
+    client_certificate: "typing.Optional[str]"
+    client_key: "typing.Optional[str]"
+    accept_invalid_certificates: "typing.Optional[bool]"
+    @typing.no_type_check
+    def __init__(self, ca_certificate: "typing.Optional[str]", client_certificate: "typing.Optional[str]", client_key: "typing.Optional[str]", accept_invalid_certificates: "typing.Optional[bool]"):
+        self.ca_certificate = ca_certificate
+        self.client_certificate = client_certificate
+        self.client_key = client_key
+        self.accept_invalid_certificates = accept_invalid_certificates
+
+    def __str__(self):
+        return "TlsConfig(ca_certificate={}, client_certificate={}, client_key={}, accept_invalid_certificates={})".format(self.ca_certificate, self.client_certificate, self.client_key, self.accept_invalid_certificates)
+
+    def __eq__(self, other):
+        if self.ca_certificate != other.ca_certificate:
+            return False
+        if self.client_certificate != other.client_certificate:
+            return False
+        if self.client_key != other.client_key:
+            return False
+        if self.accept_invalid_certificates != other.accept_invalid_certificates:
+            return False
+        return True
+
+class _UniffiConverterTypeTlsConfig(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return TlsConfig(
+            ca_certificate=_UniffiConverterOptionalString.read(buf),
+            client_certificate=_UniffiConverterOptionalString.read(buf),
+            client_key=_UniffiConverterOptionalString.read(buf),
+            accept_invalid_certificates=_UniffiConverterOptionalBool.read(buf),
+        )
+
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterOptionalString.check_lower(value.ca_certificate)
+        _UniffiConverterOptionalString.check_lower(value.client_certificate)
+        _UniffiConverterOptionalString.check_lower(value.client_key)
+        _UniffiConverterOptionalBool.check_lower(value.accept_invalid_certificates)
+
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterOptionalString.write(value.ca_certificate, buf)
+        _UniffiConverterOptionalString.write(value.client_certificate, buf)
+        _UniffiConverterOptionalString.write(value.client_key, buf)
+        _UniffiConverterOptionalBool.write(value.accept_invalid_certificates, buf)
+
+
 class Transaction:
     type: "TransactionType"
     _from: "typing.Optional[str]"
@@ -2553,8 +3291,12 @@ class Transaction:
     data: "bytes"
     signature: "typing.Optional[SignatureData]"
     hash: "typing.Optional[bytes]"
+    envelope: "TransactionEnvelope"
+    fees: "TransactionFees"
+    access_list: "typing.List[AccessListItem]"
+    block_tag: "typing.Optional[BlockTag]"
     @typing.no_type_check
-    def __init__(self, type: "TransactionType", _from: "typing.Optional[str]", to: "str", nonce: "typing.Optional[int]", chain_id: "int", data: "bytes", signature: "typing.Optional[SignatureData]", hash: "typing.Optional[bytes]"):
+    def __init__(self, type: "TransactionType", _from: "typing.Optional[str]", to: "str", nonce: "typing.Optional[int]", chain_id: "int", data: "bytes", signature: "typing.Optional[SignatureData]", hash: "typing.Optional[bytes]", envelope: "TransactionEnvelope", fees: "TransactionFees", access_list: "typing.List[AccessListItem]", block_tag: "typing.Optional[BlockTag]"):
         self.type = type
         self._from = _from
         self.to = to
@@ -2563,9 +3305,13 @@ class Transaction:
         self.data = data
         self.signature = signature
         self.hash = hash
+        self.envelope = envelope
+        self.fees = fees
+        self.access_list = access_list
+        self.block_tag = block_tag
 
     def __str__(self):
-        return "Transaction(type={}, _from={}, to={}, nonce={}, chain_id={}, data={}, signature={}, hash={})".format(self.type, self._from, self.to, self.nonce, self.chain_id, self.data, self.signature, self.hash)
+        return "Transaction(type={}, _from={}, to={}, nonce={}, chain_id={}, data={}, signature={}, hash={}, envelope={}, fees={}, access_list={}, block_tag={})".format(self.type, self._from, self.to, self.nonce, self.chain_id, self.data, self.signature, self.hash, self.envelope, self.fees, self.access_list, self.block_tag)
 
     def __eq__(self, other):
         if self.type != other.type:
@@ -2584,6 +3330,14 @@ class Transaction:
             return False
         if self.hash != other.hash:
             return False
+        if self.envelope != other.envelope:
+            return False
+        if self.fees != other.fees:
+            return False
+        if self.access_list != other.access_list:
+            return False
+        if self.block_tag != other.block_tag:
+            return False
         return True
 
 class _UniffiConverterTypeTransaction(_UniffiConverterRustBuffer):
@@ -2598,6 +3352,10 @@ class _UniffiConverterTypeTransaction(_UniffiConverterRustBuffer):
             data=_UniffiConverterBytes.read(buf),
             signature=_UniffiConverterOptionalTypeSignatureData.read(buf),
             hash=_UniffiConverterOptionalBytes.read(buf),
+            envelope=_UniffiConverterTypeTransactionEnvelope.read(buf),
+            fees=_UniffiConverterTypeTransactionFees.read(buf),
+            access_list=_UniffiConverterSequenceTypeAccessListItem.read(buf),
+            block_tag=_UniffiConverterOptionalTypeBlockTag.read(buf),
         )
 
     @staticmethod
@@ -2610,6 +3368,10 @@ class _UniffiConverterTypeTransaction(_UniffiConverterRustBuffer):
         _UniffiConverterBytes.check_lower(value.data)
         _UniffiConverterOptionalTypeSignatureData.check_lower(value.signature)
         _UniffiConverterOptionalBytes.check_lower(value.hash)
+        _UniffiConverterTypeTransactionEnvelope.check_lower(value.envelope)
+        _UniffiConverterTypeTransactionFees.check_lower(value.fees)
+        _UniffiConverterSequenceTypeAccessListItem.check_lower(value.access_list)
+        _UniffiConverterOptionalTypeBlockTag.check_lower(value.block_tag)
 
     @staticmethod
     def write(value, buf):
@@ -2621,6 +3383,10 @@ class _UniffiConverterTypeTransaction(_UniffiConverterRustBuffer):
         _UniffiConverterBytes.write(value.data, buf)
         _UniffiConverterOptionalTypeSignatureData.write(value.signature, buf)
         _UniffiConverterOptionalBytes.write(value.hash, buf)
+        _UniffiConverterTypeTransactionEnvelope.write(value.envelope, buf)
+        _UniffiConverterTypeTransactionFees.write(value.fees, buf)
+        _UniffiConverterSequenceTypeAccessListItem.write(value.access_list, buf)
+        _UniffiConverterOptionalTypeBlockTag.write(value.block_tag, buf)
 
 
 class TransactionEndorsingData:
@@ -2702,33 +3468,219 @@ class _UniffiConverterTypeTransactionEndorsingData(_UniffiConverterRustBuffer):
         _UniffiConverterOptionalTypeSignatureData.write(value.signature, buf)
 
 
+class TransactionFees:
+    gas_limit: "typing.Optional[int]"
+    gas_price: "typing.Optional[int]"
+    max_fee_per_gas: "typing.Optional[int]"
+    max_priority_fee_per_gas: "typing.Optional[int]"
+    @typing.no_type_check
+    def __init__(self, gas_limit: "typing.Optional[int]", gas_price: "typing.Optional[int]", max_fee_per_gas: "typing.Optional[int]", max_priority_fee_per_gas: "typing.Optional[int]"):
+        self.gas_limit = gas_limit
+        self.gas_price = gas_price
+        self.max_fee_per_gas = max_fee_per_gas
+        self.max_priority_fee_per_gas = max_priority_fee_per_gas
 
+    def __str__(self):
+        return "TransactionFees(gas_limit={}, gas_price={}, max_fee_per_gas={}, max_priority_fee_per_gas={})".format(self.gas_limit, self.gas_price, self.max_fee_per_gas, self.max_priority_fee_per_gas)
 
+    def __eq__(self, other):
+        if self.gas_limit != other.gas_limit:
+            return False
+        if self.gas_price != other.gas_price:
+            return False
+        if self.max_fee_per_gas != other.max_fee_per_gas:
+            return False
+        if self.max_priority_fee_per_gas != other.max_priority_fee_per_gas:
+            return False
+        return True
 
-class DidEvents:
-    def __init__(self):
-        raise RuntimeError("DidEvents cannot be instantiated directly")
+class _UniffiConverterTypeTransactionFees(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return TransactionFees(
+            gas_limit=_UniffiConverterOptionalUInt64.read(buf),
+            gas_price=_UniffiConverterOptionalUInt64.read(buf),
+            max_fee_per_gas=_UniffiConverterOptionalUInt64.read(buf),
+            max_priority_fee_per_gas=_UniffiConverterOptionalUInt64.read(buf),
+        )
 
-    # Each enum variant is a nested class of the enum itself.
-    class ATTRIBUTE_CHANGED_EVENT:
-        event: "DidAttributeChanged"
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterOptionalUInt64.check_lower(value.gas_limit)
+        _UniffiConverterOptionalUInt64.check_lower(value.gas_price)
+        _UniffiConverterOptionalUInt64.check_lower(value.max_fee_per_gas)
+        _UniffiConverterOptionalUInt64.check_lower(value.max_priority_fee_per_gas)
 
-        @typing.no_type_check
-        def __init__(self,event: "DidAttributeChanged"):
-            
-            self.event = event
-            
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterOptionalUInt64.write(value.gas_limit, buf)
+        _UniffiConverterOptionalUInt64.write(value.gas_price, buf)
+        _UniffiConverterOptionalUInt64.write(value.max_fee_per_gas, buf)
+        _UniffiConverterOptionalUInt64.write(value.max_priority_fee_per_gas, buf)
 
-        def __str__(self):
-            return "DidEvents.ATTRIBUTE_CHANGED_EVENT(event={})".format(self.event)
 
-        def __eq__(self, other):
-            if not other.is_attribute_changed_event():
-                return False
-            if self.event != other.event:
-                return False
-            return True
-    class DELEGATE_CHANGED:
+class VerificationReport:
+    chain_id: "int"
+    mismatches: "typing.List[VerificationMismatch]"
+    @typing.no_type_check
+    def __init__(self, chain_id: "int", mismatches: "typing.List[VerificationMismatch]"):
+        self.chain_id = chain_id
+        self.mismatches = mismatches
+
+    def __str__(self):
+        return "VerificationReport(chain_id={}, mismatches={})".format(self.chain_id, self.mismatches)
+
+    def __eq__(self, other):
+        if self.chain_id != other.chain_id:
+            return False
+        if self.mismatches != other.mismatches:
+            return False
+        return True
+
+class _UniffiConverterTypeVerificationReport(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        return VerificationReport(
+            chain_id=_UniffiConverterUInt64.read(buf),
+            mismatches=_UniffiConverterSequenceTypeVerificationMismatch.read(buf),
+        )
+
+    @staticmethod
+    def check_lower(value):
+        _UniffiConverterUInt64.check_lower(value.chain_id)
+        _UniffiConverterSequenceTypeVerificationMismatch.check_lower(value.mismatches)
+
+    @staticmethod
+    def write(value, buf):
+        _UniffiConverterUInt64.write(value.chain_id, buf)
+        _UniffiConverterSequenceTypeVerificationMismatch.write(value.mismatches, buf)
+
+
+
+
+
+class BlockTag:
+    def __init__(self):
+        raise RuntimeError("BlockTag cannot be instantiated directly")
+
+    # Each enum variant is a nested class of the enum itself.
+    class NUMBER:
+        number: "int"
+
+        @typing.no_type_check
+        def __init__(self,number: "int"):
+            
+            self.number = number
+            
+
+        def __str__(self):
+            return "BlockTag.NUMBER(number={})".format(self.number)
+
+        def __eq__(self, other):
+            if not other.is_number():
+                return False
+            if self.number != other.number:
+                return False
+            return True
+    class HASH:
+        hash: "bytes"
+
+        @typing.no_type_check
+        def __init__(self,hash: "bytes"):
+            
+            self.hash = hash
+            
+
+        def __str__(self):
+            return "BlockTag.HASH(hash={})".format(self.hash)
+
+        def __eq__(self, other):
+            if not other.is_hash():
+                return False
+            if self.hash != other.hash:
+                return False
+            return True
+    
+
+    # For each variant, we have an `is_NAME` method for easily checking
+    # whether an instance is that variant.
+    def is_number(self) -> bool:
+        return isinstance(self, BlockTag.NUMBER)
+    def is_hash(self) -> bool:
+        return isinstance(self, BlockTag.HASH)
+    
+
+# Now, a little trick - we make each nested variant class be a subclass of the main
+# enum class, so that method calls and instance checks etc will work intuitively.
+# We might be able to do this a little more neatly with a metaclass, but this'll do.
+BlockTag.NUMBER = type("BlockTag.NUMBER", (BlockTag.NUMBER, BlockTag,), {})  # type: ignore
+BlockTag.HASH = type("BlockTag.HASH", (BlockTag.HASH, BlockTag,), {})  # type: ignore
+
+
+
+
+class _UniffiConverterTypeBlockTag(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        variant = buf.read_i32()
+        if variant == 1:
+            return BlockTag.NUMBER(
+                _UniffiConverterUInt64.read(buf),
+            )
+        if variant == 2:
+            return BlockTag.HASH(
+                _UniffiConverterBytes.read(buf),
+            )
+        raise InternalError("Raw enum value doesn't match any cases")
+
+    @staticmethod
+    def check_lower(value):
+        if value.is_number():
+            _UniffiConverterUInt64.check_lower(value.number)
+            return
+        if value.is_hash():
+            _UniffiConverterBytes.check_lower(value.hash)
+            return
+
+    @staticmethod
+    def write(value, buf):
+        if value.is_number():
+            buf.write_i32(1)
+            _UniffiConverterUInt64.write(value.number, buf)
+        if value.is_hash():
+            buf.write_i32(2)
+            _UniffiConverterBytes.write(value.hash, buf)
+
+
+
+
+
+
+
+class DidEvents:
+    def __init__(self):
+        raise RuntimeError("DidEvents cannot be instantiated directly")
+
+    # Each enum variant is a nested class of the enum itself.
+    class ATTRIBUTE_CHANGED_EVENT:
+        event: "DidAttributeChanged"
+
+        @typing.no_type_check
+        def __init__(self,event: "DidAttributeChanged"):
+            
+            self.event = event
+            
+
+        def __str__(self):
+            return "DidEvents.ATTRIBUTE_CHANGED_EVENT(event={})".format(self.event)
+
+        def __eq__(self, other):
+            if not other.is_attribute_changed_event():
+                return False
+            if self.event != other.event:
+                return False
+            return True
+    class DELEGATE_CHANGED:
         event: "DidDelegateChanged"
 
         @typing.no_type_check
@@ -2834,104 +3786,122 @@ class _UniffiConverterTypeDidEvents(_UniffiConverterRustBuffer):
 
 
 
-class Status:
+class FeeStrategy:
     def __init__(self):
-        raise RuntimeError("Status cannot be instantiated directly")
+        raise RuntimeError("FeeStrategy cannot be instantiated directly")
 
     # Each enum variant is a nested class of the enum itself.
-    class OK:
-        block_number: "int"
-        block_timestamp: "int"
+    class FREE:
 
         @typing.no_type_check
-        def __init__(self,block_number: "int", block_timestamp: "int"):
+        def __init__(self,):
             
-            self.block_number = block_number
-            self.block_timestamp = block_timestamp
+            pass
             
 
         def __str__(self):
-            return "Status.OK(block_number={}, block_timestamp={})".format(self.block_number, self.block_timestamp)
+            return "FeeStrategy.FREE()".format()
 
         def __eq__(self, other):
-            if not other.is_ok():
+            if not other.is_free():
                 return False
-            if self.block_number != other.block_number:
+            return True
+    class FIXED:
+        fees: "TransactionFees"
+
+        @typing.no_type_check
+        def __init__(self,fees: "TransactionFees"):
+            
+            self.fees = fees
+            
+
+        def __str__(self):
+            return "FeeStrategy.FIXED(fees={})".format(self.fees)
+
+        def __eq__(self, other):
+            if not other.is_fixed():
                 return False
-            if self.block_timestamp != other.block_timestamp:
+            if self.fees != other.fees:
                 return False
             return True
-    class ERR:
-        msg: "str"
+    class ESTIMATED:
+        multiplier: "typing.Optional[float]"
 
         @typing.no_type_check
-        def __init__(self,msg: "str"):
+        def __init__(self,multiplier: "typing.Optional[float]"):
             
-            self.msg = msg
+            self.multiplier = multiplier
             
 
         def __str__(self):
-            return "Status.ERR(msg={})".format(self.msg)
+            return "FeeStrategy.ESTIMATED(multiplier={})".format(self.multiplier)
 
         def __eq__(self, other):
-            if not other.is_err():
+            if not other.is_estimated():
                 return False
-            if self.msg != other.msg:
+            if self.multiplier != other.multiplier:
                 return False
             return True
     
 
     # For each variant, we have an `is_NAME` method for easily checking
     # whether an instance is that variant.
-    def is_ok(self) -> bool:
-        return isinstance(self, Status.OK)
-    def is_err(self) -> bool:
-        return isinstance(self, Status.ERR)
+    def is_free(self) -> bool:
+        return isinstance(self, FeeStrategy.FREE)
+    def is_fixed(self) -> bool:
+        return isinstance(self, FeeStrategy.FIXED)
+    def is_estimated(self) -> bool:
+        return isinstance(self, FeeStrategy.ESTIMATED)
     
 
 # Now, a little trick - we make each nested variant class be a subclass of the main
 # enum class, so that method calls and instance checks etc will work intuitively.
 # We might be able to do this a little more neatly with a metaclass, but this'll do.
-Status.OK = type("Status.OK", (Status.OK, Status,), {})  # type: ignore
-Status.ERR = type("Status.ERR", (Status.ERR, Status,), {})  # type: ignore
+FeeStrategy.FREE = type("FeeStrategy.FREE", (FeeStrategy.FREE, FeeStrategy,), {})  # type: ignore
+FeeStrategy.FIXED = type("FeeStrategy.FIXED", (FeeStrategy.FIXED, FeeStrategy,), {})  # type: ignore
+FeeStrategy.ESTIMATED = type("FeeStrategy.ESTIMATED", (FeeStrategy.ESTIMATED, FeeStrategy,), {})  # type: ignore
 
 
 
 
-class _UniffiConverterTypeStatus(_UniffiConverterRustBuffer):
+class _UniffiConverterTypeFeeStrategy(_UniffiConverterRustBuffer):
     @staticmethod
     def read(buf):
         variant = buf.read_i32()
         if variant == 1:
-            return Status.OK(
-                _UniffiConverterUInt64.read(buf),
-                _UniffiConverterUInt64.read(buf),
+            return FeeStrategy.FREE(
             )
         if variant == 2:
-            return Status.ERR(
-                _UniffiConverterString.read(buf),
+            return FeeStrategy.FIXED(
+                _UniffiConverterTypeTransactionFees.read(buf),
+            )
+        if variant == 3:
+            return FeeStrategy.ESTIMATED(
+                _UniffiConverterOptionalDouble.read(buf),
             )
         raise InternalError("Raw enum value doesn't match any cases")
 
     @staticmethod
     def check_lower(value):
-        if value.is_ok():
-            _UniffiConverterUInt64.check_lower(value.block_number)
-            _UniffiConverterUInt64.check_lower(value.block_timestamp)
+        if value.is_free():
             return
-        if value.is_err():
-            _UniffiConverterString.check_lower(value.msg)
+        if value.is_fixed():
+            _UniffiConverterTypeTransactionFees.check_lower(value.fees)
+            return
+        if value.is_estimated():
+            _UniffiConverterOptionalDouble.check_lower(value.multiplier)
             return
 
     @staticmethod
     def write(value, buf):
-        if value.is_ok():
+        if value.is_free():
             buf.write_i32(1)
-            _UniffiConverterUInt64.write(value.block_number, buf)
-            _UniffiConverterUInt64.write(value.block_timestamp, buf)
-        if value.is_err():
+        if value.is_fixed():
             buf.write_i32(2)
-            _UniffiConverterString.write(value.msg, buf)
+            _UniffiConverterTypeTransactionFees.write(value.fees, buf)
+        if value.is_estimated():
+            buf.write_i32(3)
+            _UniffiConverterOptionalDouble.write(value.multiplier, buf)
 
 
 
@@ -2939,28 +3909,449 @@ class _UniffiConverterTypeStatus(_UniffiConverterRustBuffer):
 
 
 
-class TransactionType(enum.Enum):
-    READ = 0
+class QuorumPolicy:
+    def __init__(self):
+        raise RuntimeError("QuorumPolicy cannot be instantiated directly")
+
+    # Each enum variant is a nested class of the enum itself.
+    class MINIMAL:
+
+        @typing.no_type_check
+        def __init__(self,):
+            
+            pass
+            
+
+        def __str__(self):
+            return "QuorumPolicy.MINIMAL()".format()
+
+        def __eq__(self, other):
+            if not other.is_minimal():
+                return False
+            return True
+    class COUNT:
+        count: "int"
+
+        @typing.no_type_check
+        def __init__(self,count: "int"):
+            
+            self.count = count
+            
+
+        def __str__(self):
+            return "QuorumPolicy.COUNT(count={})".format(self.count)
+
+        def __eq__(self, other):
+            if not other.is_count():
+                return False
+            if self.count != other.count:
+                return False
+            return True
+    class FRACTION:
+        fraction: "float"
+
+        @typing.no_type_check
+        def __init__(self,fraction: "float"):
+            
+            self.fraction = fraction
+            
+
+        def __str__(self):
+            return "QuorumPolicy.FRACTION(fraction={})".format(self.fraction)
+
+        def __eq__(self, other):
+            if not other.is_fraction():
+                return False
+            if self.fraction != other.fraction:
+                return False
+            return True
+    class BFT:
+
+        @typing.no_type_check
+        def __init__(self,):
+            
+            pass
+            
+
+        def __str__(self):
+            return "QuorumPolicy.BFT()".format()
+
+        def __eq__(self, other):
+            if not other.is_bft():
+                return False
+            return True
+    class ALL:
+
+        @typing.no_type_check
+        def __init__(self,):
+            
+            pass
+            
+
+        def __str__(self):
+            return "QuorumPolicy.ALL()".format()
+
+        def __eq__(self, other):
+            if not other.is_all():
+                return False
+            return True
     
-    WRITE = 1
+
+    # For each variant, we have an `is_NAME` method for easily checking
+    # whether an instance is that variant.
+    def is_minimal(self) -> bool:
+        return isinstance(self, QuorumPolicy.MINIMAL)
+    def is_count(self) -> bool:
+        return isinstance(self, QuorumPolicy.COUNT)
+    def is_fraction(self) -> bool:
+        return isinstance(self, QuorumPolicy.FRACTION)
+    def is_bft(self) -> bool:
+        return isinstance(self, QuorumPolicy.BFT)
+    def is_all(self) -> bool:
+        return isinstance(self, QuorumPolicy.ALL)
     
 
+# Now, a little trick - we make each nested variant class be a subclass of the main
+# enum class, so that method calls and instance checks etc will work intuitively.
+# We might be able to do this a little more neatly with a metaclass, but this'll do.
+QuorumPolicy.MINIMAL = type("QuorumPolicy.MINIMAL", (QuorumPolicy.MINIMAL, QuorumPolicy,), {})  # type: ignore
+QuorumPolicy.COUNT = type("QuorumPolicy.COUNT", (QuorumPolicy.COUNT, QuorumPolicy,), {})  # type: ignore
+QuorumPolicy.FRACTION = type("QuorumPolicy.FRACTION", (QuorumPolicy.FRACTION, QuorumPolicy,), {})  # type: ignore
+QuorumPolicy.BFT = type("QuorumPolicy.BFT", (QuorumPolicy.BFT, QuorumPolicy,), {})  # type: ignore
+QuorumPolicy.ALL = type("QuorumPolicy.ALL", (QuorumPolicy.ALL, QuorumPolicy,), {})  # type: ignore
 
-class _UniffiConverterTypeTransactionType(_UniffiConverterRustBuffer):
+
+
+
+class _UniffiConverterTypeQuorumPolicy(_UniffiConverterRustBuffer):
     @staticmethod
     def read(buf):
         variant = buf.read_i32()
         if variant == 1:
-            return TransactionType.READ
+            return QuorumPolicy.MINIMAL(
+            )
         if variant == 2:
-            return TransactionType.WRITE
+            return QuorumPolicy.COUNT(
+                _UniffiConverterUInt64.read(buf),
+            )
+        if variant == 3:
+            return QuorumPolicy.FRACTION(
+                _UniffiConverterDouble.read(buf),
+            )
+        if variant == 4:
+            return QuorumPolicy.BFT(
+            )
+        if variant == 5:
+            return QuorumPolicy.ALL(
+            )
         raise InternalError("Raw enum value doesn't match any cases")
 
     @staticmethod
     def check_lower(value):
-        if value == TransactionType.READ:
+        if value.is_minimal():
             return
-        if value == TransactionType.WRITE:
+        if value.is_count():
+            _UniffiConverterUInt64.check_lower(value.count)
+            return
+        if value.is_fraction():
+            _UniffiConverterDouble.check_lower(value.fraction)
+            return
+        if value.is_bft():
+            return
+        if value.is_all():
+            return
+
+    @staticmethod
+    def write(value, buf):
+        if value.is_minimal():
+            buf.write_i32(1)
+        if value.is_count():
+            buf.write_i32(2)
+            _UniffiConverterUInt64.write(value.count, buf)
+        if value.is_fraction():
+            buf.write_i32(3)
+            _UniffiConverterDouble.write(value.fraction, buf)
+        if value.is_bft():
+            buf.write_i32(4)
+        if value.is_all():
+            buf.write_i32(5)
+
+
+
+
+
+
+
+class SimulationResult:
+    def __init__(self):
+        raise RuntimeError("SimulationResult cannot be instantiated directly")
+
+    # Each enum variant is a nested class of the enum itself.
+    class SUCCESS:
+        output: "bytes"
+
+        @typing.no_type_check
+        def __init__(self,output: "bytes"):
+            
+            self.output = output
+            
+
+        def __str__(self):
+            return "SimulationResult.SUCCESS(output={})".format(self.output)
+
+        def __eq__(self, other):
+            if not other.is_success():
+                return False
+            if self.output != other.output:
+                return False
+            return True
+    class REVERTED:
+        revert_reason: "str"
+
+        @typing.no_type_check
+        def __init__(self,revert_reason: "str"):
+            
+            self.revert_reason = revert_reason
+            
+
+        def __str__(self):
+            return "SimulationResult.REVERTED(revert_reason={})".format(self.revert_reason)
+
+        def __eq__(self, other):
+            if not other.is_reverted():
+                return False
+            if self.revert_reason != other.revert_reason:
+                return False
+            return True
+    
+
+    # For each variant, we have an `is_NAME` method for easily checking
+    # whether an instance is that variant.
+    def is_success(self) -> bool:
+        return isinstance(self, SimulationResult.SUCCESS)
+    def is_reverted(self) -> bool:
+        return isinstance(self, SimulationResult.REVERTED)
+    
+
+# Now, a little trick - we make each nested variant class be a subclass of the main
+# enum class, so that method calls and instance checks etc will work intuitively.
+# We might be able to do this a little more neatly with a metaclass, but this'll do.
+SimulationResult.SUCCESS = type("SimulationResult.SUCCESS", (SimulationResult.SUCCESS, SimulationResult,), {})  # type: ignore
+SimulationResult.REVERTED = type("SimulationResult.REVERTED", (SimulationResult.REVERTED, SimulationResult,), {})  # type: ignore
+
+
+
+
+class _UniffiConverterTypeSimulationResult(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        variant = buf.read_i32()
+        if variant == 1:
+            return SimulationResult.SUCCESS(
+                _UniffiConverterBytes.read(buf),
+            )
+        if variant == 2:
+            return SimulationResult.REVERTED(
+                _UniffiConverterString.read(buf),
+            )
+        raise InternalError("Raw enum value doesn't match any cases")
+
+    @staticmethod
+    def check_lower(value):
+        if value.is_success():
+            _UniffiConverterBytes.check_lower(value.output)
+            return
+        if value.is_reverted():
+            _UniffiConverterString.check_lower(value.revert_reason)
+            return
+
+    @staticmethod
+    def write(value, buf):
+        if value.is_success():
+            buf.write_i32(1)
+            _UniffiConverterBytes.write(value.output, buf)
+        if value.is_reverted():
+            buf.write_i32(2)
+            _UniffiConverterString.write(value.revert_reason, buf)
+
+
+
+
+
+
+
+class Status:
+    def __init__(self):
+        raise RuntimeError("Status cannot be instantiated directly")
+
+    # Each enum variant is a nested class of the enum itself.
+    class OK:
+        block_number: "int"
+        block_timestamp: "int"
+
+        @typing.no_type_check
+        def __init__(self,block_number: "int", block_timestamp: "int"):
+            
+            self.block_number = block_number
+            self.block_timestamp = block_timestamp
+            
+
+        def __str__(self):
+            return "Status.OK(block_number={}, block_timestamp={})".format(self.block_number, self.block_timestamp)
+
+        def __eq__(self, other):
+            if not other.is_ok():
+                return False
+            if self.block_number != other.block_number:
+                return False
+            if self.block_timestamp != other.block_timestamp:
+                return False
+            return True
+    class ERR:
+        msg: "str"
+
+        @typing.no_type_check
+        def __init__(self,msg: "str"):
+            
+            self.msg = msg
+            
+
+        def __str__(self):
+            return "Status.ERR(msg={})".format(self.msg)
+
+        def __eq__(self, other):
+            if not other.is_err():
+                return False
+            if self.msg != other.msg:
+                return False
+            return True
+    
+
+    # For each variant, we have an `is_NAME` method for easily checking
+    # whether an instance is that variant.
+    def is_ok(self) -> bool:
+        return isinstance(self, Status.OK)
+    def is_err(self) -> bool:
+        return isinstance(self, Status.ERR)
+    
+
+# Now, a little trick - we make each nested variant class be a subclass of the main
+# enum class, so that method calls and instance checks etc will work intuitively.
+# We might be able to do this a little more neatly with a metaclass, but this'll do.
+Status.OK = type("Status.OK", (Status.OK, Status,), {})  # type: ignore
+Status.ERR = type("Status.ERR", (Status.ERR, Status,), {})  # type: ignore
+
+
+
+
+class _UniffiConverterTypeStatus(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        variant = buf.read_i32()
+        if variant == 1:
+            return Status.OK(
+                _UniffiConverterUInt64.read(buf),
+                _UniffiConverterUInt64.read(buf),
+            )
+        if variant == 2:
+            return Status.ERR(
+                _UniffiConverterString.read(buf),
+            )
+        raise InternalError("Raw enum value doesn't match any cases")
+
+    @staticmethod
+    def check_lower(value):
+        if value.is_ok():
+            _UniffiConverterUInt64.check_lower(value.block_number)
+            _UniffiConverterUInt64.check_lower(value.block_timestamp)
+            return
+        if value.is_err():
+            _UniffiConverterString.check_lower(value.msg)
+            return
+
+    @staticmethod
+    def write(value, buf):
+        if value.is_ok():
+            buf.write_i32(1)
+            _UniffiConverterUInt64.write(value.block_number, buf)
+            _UniffiConverterUInt64.write(value.block_timestamp, buf)
+        if value.is_err():
+            buf.write_i32(2)
+            _UniffiConverterString.write(value.msg, buf)
+
+
+
+
+
+
+
+class TransactionEnvelope(enum.Enum):
+    LEGACY = 0
+    
+    ACCESS_LIST = 1
+    
+    DYNAMIC_FEE = 2
+    
+
+
+class _UniffiConverterTypeTransactionEnvelope(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        variant = buf.read_i32()
+        if variant == 1:
+            return TransactionEnvelope.LEGACY
+        if variant == 2:
+            return TransactionEnvelope.ACCESS_LIST
+        if variant == 3:
+            return TransactionEnvelope.DYNAMIC_FEE
+        raise InternalError("Raw enum value doesn't match any cases")
+
+    @staticmethod
+    def check_lower(value):
+        if value == TransactionEnvelope.LEGACY:
+            return
+        if value == TransactionEnvelope.ACCESS_LIST:
+            return
+        if value == TransactionEnvelope.DYNAMIC_FEE:
+            return
+
+    @staticmethod
+    def write(value, buf):
+        if value == TransactionEnvelope.LEGACY:
+            buf.write_i32(1)
+        if value == TransactionEnvelope.ACCESS_LIST:
+            buf.write_i32(2)
+        if value == TransactionEnvelope.DYNAMIC_FEE:
+            buf.write_i32(3)
+
+
+
+
+
+
+
+class TransactionType(enum.Enum):
+    READ = 0
+    
+    WRITE = 1
+    
+
+
+class _UniffiConverterTypeTransactionType(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        variant = buf.read_i32()
+        if variant == 1:
+            return TransactionType.READ
+        if variant == 2:
+            return TransactionType.WRITE
+        raise InternalError("Raw enum value doesn't match any cases")
+
+    @staticmethod
+    def check_lower(value):
+        if value == TransactionType.READ:
+            return
+        if value == TransactionType.WRITE:
             return
 
     @staticmethod
@@ -2992,6 +4383,16 @@ class VdrError:  # type: ignore
         def __repr__(self):
             return "VdrError.ClientNodeUnreachable({})".format(str(self))
     _UniffiTempVdrError.ClientNodeUnreachable = ClientNodeUnreachable # type: ignore
+    class ClientHttpStatus(_UniffiTempVdrError):
+
+        def __init__(self, code):
+            super().__init__(", ".join([
+                "code={!r}".format(code),
+            ]))
+            self.code = code
+        def __repr__(self):
+            return "VdrError.ClientHttpStatus({})".format(str(self))
+    _UniffiTempVdrError.ClientHttpStatus = ClientHttpStatus # type: ignore
     class ClientInvalidTransaction(_UniffiTempVdrError):
 
         def __init__(self, msg):
@@ -3052,7 +4453,7 @@ class VdrError:  # type: ignore
         def __repr__(self):
             return "VdrError.ClientInvalidState({})".format(str(self))
     _UniffiTempVdrError.ClientInvalidState = ClientInvalidState # type: ignore
-    class ContractInvalidName(_UniffiTempVdrError):
+    class ClientTimeout(_UniffiTempVdrError):
 
         def __init__(self, msg):
             super().__init__(", ".join([
@@ -3060,9 +4461,9 @@ class VdrError:  # type: ignore
             ]))
             self.msg = msg
         def __repr__(self):
-            return "VdrError.ContractInvalidName({})".format(str(self))
-    _UniffiTempVdrError.ContractInvalidName = ContractInvalidName # type: ignore
-    class ContractInvalidSpec(_UniffiTempVdrError):
+            return "VdrError.ClientTimeout({})".format(str(self))
+    _UniffiTempVdrError.ClientTimeout = ClientTimeout # type: ignore
+    class ClientQueryLimitExceeded(_UniffiTempVdrError):
 
         def __init__(self, msg):
             super().__init__(", ".join([
@@ -3070,13 +4471,33 @@ class VdrError:  # type: ignore
             ]))
             self.msg = msg
         def __repr__(self):
-            return "VdrError.ContractInvalidSpec({})".format(str(self))
-    _UniffiTempVdrError.ContractInvalidSpec = ContractInvalidSpec # type: ignore
-    class ContractInvalidInputData(_UniffiTempVdrError):
+            return "VdrError.ClientQueryLimitExceeded({})".format(str(self))
+    _UniffiTempVdrError.ClientQueryLimitExceeded = ClientQueryLimitExceeded # type: ignore
+    class ContractInvalidName(_UniffiTempVdrError):
 
-        def __init__(self):
-            pass
-        def __repr__(self):
+        def __init__(self, msg):
+            super().__init__(", ".join([
+                "msg={!r}".format(msg),
+            ]))
+            self.msg = msg
+        def __repr__(self):
+            return "VdrError.ContractInvalidName({})".format(str(self))
+    _UniffiTempVdrError.ContractInvalidName = ContractInvalidName # type: ignore
+    class ContractInvalidSpec(_UniffiTempVdrError):
+
+        def __init__(self, msg):
+            super().__init__(", ".join([
+                "msg={!r}".format(msg),
+            ]))
+            self.msg = msg
+        def __repr__(self):
+            return "VdrError.ContractInvalidSpec({})".format(str(self))
+    _UniffiTempVdrError.ContractInvalidSpec = ContractInvalidSpec # type: ignore
+    class ContractInvalidInputData(_UniffiTempVdrError):
+
+        def __init__(self):
+            pass
+        def __repr__(self):
             return "VdrError.ContractInvalidInputData({})".format(str(self))
     _UniffiTempVdrError.ContractInvalidInputData = ContractInvalidInputData # type: ignore
     class ContractInvalidResponseData(_UniffiTempVdrError):
@@ -3123,6 +4544,26 @@ class VdrError:  # type: ignore
         def __repr__(self):
             return "VdrError.SignerUnexpectedError({})".format(str(self))
     _UniffiTempVdrError.SignerUnexpectedError = SignerUnexpectedError # type: ignore
+    class SignerInvalidKeystore(_UniffiTempVdrError):
+
+        def __init__(self, msg):
+            super().__init__(", ".join([
+                "msg={!r}".format(msg),
+            ]))
+            self.msg = msg
+        def __repr__(self):
+            return "VdrError.SignerInvalidKeystore({})".format(str(self))
+    _UniffiTempVdrError.SignerInvalidKeystore = SignerInvalidKeystore # type: ignore
+    class SignerInvalidMnemonic(_UniffiTempVdrError):
+
+        def __init__(self, msg):
+            super().__init__(", ".join([
+                "msg={!r}".format(msg),
+            ]))
+            self.msg = msg
+        def __repr__(self):
+            return "VdrError.SignerInvalidMnemonic({})".format(str(self))
+    _UniffiTempVdrError.SignerInvalidMnemonic = SignerInvalidMnemonic # type: ignore
     class CommonInvalidData(_UniffiTempVdrError):
 
         def __init__(self, msg):
@@ -3153,6 +4594,16 @@ class VdrError:  # type: ignore
         def __repr__(self):
             return "VdrError.GetTransactionError({})".format(str(self))
     _UniffiTempVdrError.GetTransactionError = GetTransactionError # type: ignore
+    class InvalidDidDocument(_UniffiTempVdrError):
+
+        def __init__(self, msg):
+            super().__init__(", ".join([
+                "msg={!r}".format(msg),
+            ]))
+            self.msg = msg
+        def __repr__(self):
+            return "VdrError.InvalidDidDocument({})".format(str(self))
+    _UniffiTempVdrError.InvalidDidDocument = InvalidDidDocument # type: ignore
     class InvalidSchema(_UniffiTempVdrError):
 
         def __init__(self, msg):
@@ -3186,75 +4637,99 @@ class _UniffiConverterTypeVdrError(_UniffiConverterRustBuffer):
             return VdrError.ClientNodeUnreachable(
             )
         if variant == 2:
+            return VdrError.ClientHttpStatus(
+                code=_UniffiConverterUInt16.read(buf),
+            )
+        if variant == 3:
             return VdrError.ClientInvalidTransaction(
                 msg=_UniffiConverterString.read(buf),
             )
-        if variant == 3:
+        if variant == 4:
             return VdrError.ClientInvalidEndorsementData(
                 msg=_UniffiConverterString.read(buf),
             )
-        if variant == 4:
+        if variant == 5:
             return VdrError.ClientInvalidResponse(
                 msg=_UniffiConverterString.read(buf),
             )
-        if variant == 5:
+        if variant == 6:
             return VdrError.ClientTransactionReverted(
                 msg=_UniffiConverterString.read(buf),
             )
-        if variant == 6:
+        if variant == 7:
             return VdrError.ClientUnexpectedError(
                 msg=_UniffiConverterString.read(buf),
             )
-        if variant == 7:
+        if variant == 8:
             return VdrError.ClientInvalidState(
                 msg=_UniffiConverterString.read(buf),
             )
-        if variant == 8:
+        if variant == 9:
+            return VdrError.ClientTimeout(
+                msg=_UniffiConverterString.read(buf),
+            )
+        if variant == 10:
+            return VdrError.ClientQueryLimitExceeded(
+                msg=_UniffiConverterString.read(buf),
+            )
+        if variant == 11:
             return VdrError.ContractInvalidName(
                 msg=_UniffiConverterString.read(buf),
             )
-        if variant == 9:
+        if variant == 12:
             return VdrError.ContractInvalidSpec(
                 msg=_UniffiConverterString.read(buf),
             )
-        if variant == 10:
+        if variant == 13:
             return VdrError.ContractInvalidInputData(
             )
-        if variant == 11:
+        if variant == 14:
             return VdrError.ContractInvalidResponseData(
                 msg=_UniffiConverterString.read(buf),
             )
-        if variant == 12:
+        if variant == 15:
             return VdrError.SignerInvalidPrivateKey(
             )
-        if variant == 13:
+        if variant == 16:
             return VdrError.SignerInvalidMessage(
             )
-        if variant == 14:
+        if variant == 17:
             return VdrError.SignerMissingKey(
                 msg=_UniffiConverterString.read(buf),
             )
-        if variant == 15:
+        if variant == 18:
             return VdrError.SignerUnexpectedError(
                 msg=_UniffiConverterString.read(buf),
             )
-        if variant == 16:
+        if variant == 19:
+            return VdrError.SignerInvalidKeystore(
+                msg=_UniffiConverterString.read(buf),
+            )
+        if variant == 20:
+            return VdrError.SignerInvalidMnemonic(
+                msg=_UniffiConverterString.read(buf),
+            )
+        if variant == 21:
             return VdrError.CommonInvalidData(
                 msg=_UniffiConverterString.read(buf),
             )
-        if variant == 17:
+        if variant == 22:
             return VdrError.QuorumNotReached(
                 msg=_UniffiConverterString.read(buf),
             )
-        if variant == 18:
+        if variant == 23:
             return VdrError.GetTransactionError(
                 msg=_UniffiConverterString.read(buf),
             )
-        if variant == 19:
+        if variant == 24:
+            return VdrError.InvalidDidDocument(
+                msg=_UniffiConverterString.read(buf),
+            )
+        if variant == 25:
             return VdrError.InvalidSchema(
                 msg=_UniffiConverterString.read(buf),
             )
-        if variant == 20:
+        if variant == 26:
             return VdrError.InvalidCredentialDefinition(
                 msg=_UniffiConverterString.read(buf),
             )
@@ -3264,6 +4739,9 @@ class _UniffiConverterTypeVdrError(_UniffiConverterRustBuffer):
     def check_lower(value):
         if isinstance(value, VdrError.ClientNodeUnreachable):
             return
+        if isinstance(value, VdrError.ClientHttpStatus):
+            _UniffiConverterUInt16.check_lower(value.code)
+            return
         if isinstance(value, VdrError.ClientInvalidTransaction):
             _UniffiConverterString.check_lower(value.msg)
             return
@@ -3282,6 +4760,12 @@ class _UniffiConverterTypeVdrError(_UniffiConverterRustBuffer):
         if isinstance(value, VdrError.ClientInvalidState):
             _UniffiConverterString.check_lower(value.msg)
             return
+        if isinstance(value, VdrError.ClientTimeout):
+            _UniffiConverterString.check_lower(value.msg)
+            return
+        if isinstance(value, VdrError.ClientQueryLimitExceeded):
+            _UniffiConverterString.check_lower(value.msg)
+            return
         if isinstance(value, VdrError.ContractInvalidName):
             _UniffiConverterString.check_lower(value.msg)
             return
@@ -3303,6 +4787,12 @@ class _UniffiConverterTypeVdrError(_UniffiConverterRustBuffer):
         if isinstance(value, VdrError.SignerUnexpectedError):
             _UniffiConverterString.check_lower(value.msg)
             return
+        if isinstance(value, VdrError.SignerInvalidKeystore):
+            _UniffiConverterString.check_lower(value.msg)
+            return
+        if isinstance(value, VdrError.SignerInvalidMnemonic):
+            _UniffiConverterString.check_lower(value.msg)
+            return
         if isinstance(value, VdrError.CommonInvalidData):
             _UniffiConverterString.check_lower(value.msg)
             return
@@ -3312,79 +4802,754 @@ class _UniffiConverterTypeVdrError(_UniffiConverterRustBuffer):
         if isinstance(value, VdrError.GetTransactionError):
             _UniffiConverterString.check_lower(value.msg)
             return
+        if isinstance(value, VdrError.InvalidDidDocument):
+            _UniffiConverterString.check_lower(value.msg)
+            return
         if isinstance(value, VdrError.InvalidSchema):
             _UniffiConverterString.check_lower(value.msg)
             return
-        if isinstance(value, VdrError.InvalidCredentialDefinition):
-            _UniffiConverterString.check_lower(value.msg)
+        if isinstance(value, VdrError.InvalidCredentialDefinition):
+            _UniffiConverterString.check_lower(value.msg)
+            return
+
+    @staticmethod
+    def write(value, buf):
+        if isinstance(value, VdrError.ClientNodeUnreachable):
+            buf.write_i32(1)
+        if isinstance(value, VdrError.ClientHttpStatus):
+            buf.write_i32(2)
+            _UniffiConverterUInt16.write(value.code, buf)
+        if isinstance(value, VdrError.ClientInvalidTransaction):
+            buf.write_i32(3)
+            _UniffiConverterString.write(value.msg, buf)
+        if isinstance(value, VdrError.ClientInvalidEndorsementData):
+            buf.write_i32(4)
+            _UniffiConverterString.write(value.msg, buf)
+        if isinstance(value, VdrError.ClientInvalidResponse):
+            buf.write_i32(5)
+            _UniffiConverterString.write(value.msg, buf)
+        if isinstance(value, VdrError.ClientTransactionReverted):
+            buf.write_i32(6)
+            _UniffiConverterString.write(value.msg, buf)
+        if isinstance(value, VdrError.ClientUnexpectedError):
+            buf.write_i32(7)
+            _UniffiConverterString.write(value.msg, buf)
+        if isinstance(value, VdrError.ClientInvalidState):
+            buf.write_i32(8)
+            _UniffiConverterString.write(value.msg, buf)
+        if isinstance(value, VdrError.ClientTimeout):
+            buf.write_i32(9)
+            _UniffiConverterString.write(value.msg, buf)
+        if isinstance(value, VdrError.ClientQueryLimitExceeded):
+            buf.write_i32(10)
+            _UniffiConverterString.write(value.msg, buf)
+        if isinstance(value, VdrError.ContractInvalidName):
+            buf.write_i32(11)
+            _UniffiConverterString.write(value.msg, buf)
+        if isinstance(value, VdrError.ContractInvalidSpec):
+            buf.write_i32(12)
+            _UniffiConverterString.write(value.msg, buf)
+        if isinstance(value, VdrError.ContractInvalidInputData):
+            buf.write_i32(13)
+        if isinstance(value, VdrError.ContractInvalidResponseData):
+            buf.write_i32(14)
+            _UniffiConverterString.write(value.msg, buf)
+        if isinstance(value, VdrError.SignerInvalidPrivateKey):
+            buf.write_i32(15)
+        if isinstance(value, VdrError.SignerInvalidMessage):
+            buf.write_i32(16)
+        if isinstance(value, VdrError.SignerMissingKey):
+            buf.write_i32(17)
+            _UniffiConverterString.write(value.msg, buf)
+        if isinstance(value, VdrError.SignerUnexpectedError):
+            buf.write_i32(18)
+            _UniffiConverterString.write(value.msg, buf)
+        if isinstance(value, VdrError.SignerInvalidKeystore):
+            buf.write_i32(19)
+            _UniffiConverterString.write(value.msg, buf)
+        if isinstance(value, VdrError.SignerInvalidMnemonic):
+            buf.write_i32(20)
+            _UniffiConverterString.write(value.msg, buf)
+        if isinstance(value, VdrError.CommonInvalidData):
+            buf.write_i32(21)
+            _UniffiConverterString.write(value.msg, buf)
+        if isinstance(value, VdrError.QuorumNotReached):
+            buf.write_i32(22)
+            _UniffiConverterString.write(value.msg, buf)
+        if isinstance(value, VdrError.GetTransactionError):
+            buf.write_i32(23)
+            _UniffiConverterString.write(value.msg, buf)
+        if isinstance(value, VdrError.InvalidDidDocument):
+            buf.write_i32(24)
+            _UniffiConverterString.write(value.msg, buf)
+        if isinstance(value, VdrError.InvalidSchema):
+            buf.write_i32(25)
+            _UniffiConverterString.write(value.msg, buf)
+        if isinstance(value, VdrError.InvalidCredentialDefinition):
+            buf.write_i32(26)
+            _UniffiConverterString.write(value.msg, buf)
+
+
+
+
+
+class VerificationMismatch:
+    def __init__(self):
+        raise RuntimeError("VerificationMismatch cannot be instantiated directly")
+
+    # Each enum variant is a nested class of the enum itself.
+    class CHAIN_ID:
+        expected: "int"
+        actual: "int"
+
+        @typing.no_type_check
+        def __init__(self,expected: "int", actual: "int"):
+            
+            self.expected = expected
+            self.actual = actual
+            
+
+        def __str__(self):
+            return "VerificationMismatch.CHAIN_ID(expected={}, actual={})".format(self.expected, self.actual)
+
+        def __eq__(self, other):
+            if not other.is_chain_id():
+                return False
+            if self.expected != other.expected:
+                return False
+            if self.actual != other.actual:
+                return False
+            return True
+    class CONTRACT_NOT_DEPLOYED:
+        contract: "str"
+        address: "str"
+
+        @typing.no_type_check
+        def __init__(self,contract: "str", address: "str"):
+            
+            self.contract = contract
+            self.address = address
+            
+
+        def __str__(self):
+            return "VerificationMismatch.CONTRACT_NOT_DEPLOYED(contract={}, address={})".format(self.contract, self.address)
+
+        def __eq__(self, other):
+            if not other.is_contract_not_deployed():
+                return False
+            if self.contract != other.contract:
+                return False
+            if self.address != other.address:
+                return False
+            return True
+    class CONTRACT_FUNCTIONS_MISSING:
+        contract: "str"
+        address: "str"
+        functions: "typing.List[str]"
+
+        @typing.no_type_check
+        def __init__(self,contract: "str", address: "str", functions: "typing.List[str]"):
+            
+            self.contract = contract
+            self.address = address
+            self.functions = functions
+            
+
+        def __str__(self):
+            return "VerificationMismatch.CONTRACT_FUNCTIONS_MISSING(contract={}, address={}, functions={})".format(self.contract, self.address, self.functions)
+
+        def __eq__(self, other):
+            if not other.is_contract_functions_missing():
+                return False
+            if self.contract != other.contract:
+                return False
+            if self.address != other.address:
+                return False
+            if self.functions != other.functions:
+                return False
+            return True
+    
+
+    # For each variant, we have an `is_NAME` method for easily checking
+    # whether an instance is that variant.
+    def is_chain_id(self) -> bool:
+        return isinstance(self, VerificationMismatch.CHAIN_ID)
+    def is_contract_not_deployed(self) -> bool:
+        return isinstance(self, VerificationMismatch.CONTRACT_NOT_DEPLOYED)
+    def is_contract_functions_missing(self) -> bool:
+        return isinstance(self, VerificationMismatch.CONTRACT_FUNCTIONS_MISSING)
+    
+
+# Now, a little trick - we make each nested variant class be a subclass of the main
+# enum class, so that method calls and instance checks etc will work intuitively.
+# We might be able to do this a little more neatly with a metaclass, but this'll do.
+VerificationMismatch.CHAIN_ID = type("VerificationMismatch.CHAIN_ID", (VerificationMismatch.CHAIN_ID, VerificationMismatch,), {})  # type: ignore
+VerificationMismatch.CONTRACT_NOT_DEPLOYED = type("VerificationMismatch.CONTRACT_NOT_DEPLOYED", (VerificationMismatch.CONTRACT_NOT_DEPLOYED, VerificationMismatch,), {})  # type: ignore
+VerificationMismatch.CONTRACT_FUNCTIONS_MISSING = type("VerificationMismatch.CONTRACT_FUNCTIONS_MISSING", (VerificationMismatch.CONTRACT_FUNCTIONS_MISSING, VerificationMismatch,), {})  # type: ignore
+
+
+
+
+class _UniffiConverterTypeVerificationMismatch(_UniffiConverterRustBuffer):
+    @staticmethod
+    def read(buf):
+        variant = buf.read_i32()
+        if variant == 1:
+            return VerificationMismatch.CHAIN_ID(
+                _UniffiConverterUInt64.read(buf),
+                _UniffiConverterUInt64.read(buf),
+            )
+        if variant == 2:
+            return VerificationMismatch.CONTRACT_NOT_DEPLOYED(
+                _UniffiConverterString.read(buf),
+                _UniffiConverterString.read(buf),
+            )
+        if variant == 3:
+            return VerificationMismatch.CONTRACT_FUNCTIONS_MISSING(
+                _UniffiConverterString.read(buf),
+                _UniffiConverterString.read(buf),
+                _UniffiConverterSequenceString.read(buf),
+            )
+        raise InternalError("Raw enum value doesn't match any cases")
+
+    @staticmethod
+    def check_lower(value):
+        if value.is_chain_id():
+            _UniffiConverterUInt64.check_lower(value.expected)
+            _UniffiConverterUInt64.check_lower(value.actual)
+            return
+        if value.is_contract_not_deployed():
+            _UniffiConverterString.check_lower(value.contract)
+            _UniffiConverterString.check_lower(value.address)
+            return
+        if value.is_contract_functions_missing():
+            _UniffiConverterString.check_lower(value.contract)
+            _UniffiConverterString.check_lower(value.address)
+            _UniffiConverterSequenceString.check_lower(value.functions)
+            return
+
+    @staticmethod
+    def write(value, buf):
+        if value.is_chain_id():
+            buf.write_i32(1)
+            _UniffiConverterUInt64.write(value.expected, buf)
+            _UniffiConverterUInt64.write(value.actual, buf)
+        if value.is_contract_not_deployed():
+            buf.write_i32(2)
+            _UniffiConverterString.write(value.contract, buf)
+            _UniffiConverterString.write(value.address, buf)
+        if value.is_contract_functions_missing():
+            buf.write_i32(3)
+            _UniffiConverterString.write(value.contract, buf)
+            _UniffiConverterString.write(value.address, buf)
+            _UniffiConverterSequenceString.write(value.functions, buf)
+
+
+
+
+
+class TokenProvider(typing.Protocol):
+    """
+    Provider of bearer tokens used to authenticate requests to the RPC node.
+    Called before every request, so implementations should cache the token until it expires
+    """
+
+    def token(self, ):
+        raise NotImplementedError
+import threading
+
+class ConcurrentHandleMap:
+    """
+    A map where inserting, getting and removing data is synchronized with a lock.
+    """
+
+    def __init__(self):
+        # type Handle = int
+        self._left_map = {}  # type: Dict[Handle, Any]
+
+        self._lock = threading.Lock()
+        self._current_handle = 0
+        self._stride = 1
+
+    def insert(self, obj):
+        with self._lock:
+            handle = self._current_handle
+            self._current_handle += self._stride
+            self._left_map[handle] = obj
+            return handle
+
+    def get(self, handle):
+        with self._lock:
+            obj = self._left_map.get(handle)
+        if not obj:
+            raise InternalError("No callback in handlemap; this is a uniffi bug")
+        return obj
+
+    def remove(self, handle):
+        with self._lock:
+            if handle in self._left_map:
+                obj = self._left_map.pop(handle)
+                return obj
+
+# Magic number for the Rust proxy to call using the same mechanism as every other method,
+# to free the callback once it's dropped by Rust.
+IDX_CALLBACK_FREE = 0
+# Return codes for callback calls
+_UNIFFI_CALLBACK_SUCCESS = 0
+_UNIFFI_CALLBACK_ERROR = 1
+_UNIFFI_CALLBACK_UNEXPECTED_ERROR = 2
+
+class UniffiCallbackInterfaceFfiConverter:
+    _handle_map = ConcurrentHandleMap()
+
+    @classmethod
+    def lift(cls, handle):
+        return cls._handle_map.get(handle)
+
+    @classmethod
+    def read(cls, buf):
+        handle = buf.read_u64()
+        cls.lift(handle)
+
+    @classmethod
+    def check_lower(cls, cb):
+        pass
+
+    @classmethod
+    def lower(cls, cb):
+        handle = cls._handle_map.insert(cb)
+        return handle
+
+    @classmethod
+    def write(cls, cb, buf):
+        buf.write_u64(cls.lower(cb))
+
+# Declaration and _UniffiConverters for TokenProvider Callback Interface
+
+def UniffiCallbackInterfaceTokenProvider(handle, method, args_data, args_len, buf_ptr):
+    
+    def invoke_token(python_callback, args_stream, buf_ptr):
+        def makeCall():
+            return python_callback.token()
+
+        def makeCallAndHandleReturn():
+            rval = makeCall()
+            with _UniffiRustBuffer.alloc_with_builder() as builder:
+                _UniffiConverterString.write(rval, builder)
+                buf_ptr[0] = builder.finalize()
+            return _UNIFFI_CALLBACK_SUCCESS
+        try:
+            return makeCallAndHandleReturn()
+        except VdrError as e:
+            # Catch errors declared in the UDL file
+            with _UniffiRustBuffer.alloc_with_builder() as builder:
+                _UniffiConverterTypeVdrError.write(e, builder)
+                buf_ptr[0] = builder.finalize()
+            return _UNIFFI_CALLBACK_ERROR
+
+    
+
+    cb = _UniffiConverterCallbackInterfaceTokenProvider._handle_map.get(handle)
+
+    if method == IDX_CALLBACK_FREE:
+        _UniffiConverterCallbackInterfaceTokenProvider._handle_map.remove(handle)
+
+        # Successful return
+        # See docs of ForeignCallback in `uniffi_core/src/ffi/foreigncallbacks.rs`
+        return _UNIFFI_CALLBACK_SUCCESS
+
+    if method == 1:
+        # Call the method and handle any errors
+        # See docs of ForeignCallback in `uniffi_core/src/ffi/foreigncallbacks.rs` for details
+        try:
+            return invoke_token(cb, _UniffiRustBufferStream(args_data, args_len), buf_ptr)
+        except BaseException as e:
+            # Catch unexpected errors
+            try:
+                # Try to serialize the exception into a String
+                buf_ptr[0] = _UniffiConverterString.lower(repr(e))
+            except:
+                # If that fails, just give up
+                pass
+            return _UNIFFI_CALLBACK_UNEXPECTED_ERROR
+    
+
+    # This should never happen, because an out of bounds method index won't
+    # ever be used. Once we can catch errors, we should return an InternalException.
+    # https://github.com/mozilla/uniffi-rs/issues/351
+
+    # An unexpected error happened.
+    # See docs of ForeignCallback in `uniffi_core/src/ffi/foreigncallbacks.rs`
+    return _UNIFFI_CALLBACK_UNEXPECTED_ERROR
+
+# We need to keep this function reference alive:
+# if they get GC'd while in use then UniFFI internals could attempt to call a function
+# that is in freed memory.
+# That would be...uh...bad. Yeah, that's the word. Bad.
+uniffiCallbackInterfaceTokenProvider = _UNIFFI_FOREIGN_CALLBACK_T(UniffiCallbackInterfaceTokenProvider)
+_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_init_callback_tokenprovider(uniffiCallbackInterfaceTokenProvider)
+
+# The _UniffiConverter which transforms the Callbacks in to Handles to pass to Rust.
+_UniffiConverterCallbackInterfaceTokenProvider = UniffiCallbackInterfaceFfiConverter()
+
+
+
+class _UniffiConverterOptionalUInt8(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        if value is not None:
+            _UniffiConverterUInt8.check_lower(value)
+
+    @classmethod
+    def write(cls, value, buf):
+        if value is None:
+            buf.write_u8(0)
+            return
+
+        buf.write_u8(1)
+        _UniffiConverterUInt8.write(value, buf)
+
+    @classmethod
+    def read(cls, buf):
+        flag = buf.read_u8()
+        if flag == 0:
+            return None
+        elif flag == 1:
+            return _UniffiConverterUInt8.read(buf)
+        else:
+            raise InternalError("Unexpected flag byte for optional type")
+
+
+
+class _UniffiConverterOptionalUInt64(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        if value is not None:
+            _UniffiConverterUInt64.check_lower(value)
+
+    @classmethod
+    def write(cls, value, buf):
+        if value is None:
+            buf.write_u8(0)
+            return
+
+        buf.write_u8(1)
+        _UniffiConverterUInt64.write(value, buf)
+
+    @classmethod
+    def read(cls, buf):
+        flag = buf.read_u8()
+        if flag == 0:
+            return None
+        elif flag == 1:
+            return _UniffiConverterUInt64.read(buf)
+        else:
+            raise InternalError("Unexpected flag byte for optional type")
+
+
+
+class _UniffiConverterOptionalDouble(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        if value is not None:
+            _UniffiConverterDouble.check_lower(value)
+
+    @classmethod
+    def write(cls, value, buf):
+        if value is None:
+            buf.write_u8(0)
+            return
+
+        buf.write_u8(1)
+        _UniffiConverterDouble.write(value, buf)
+
+    @classmethod
+    def read(cls, buf):
+        flag = buf.read_u8()
+        if flag == 0:
+            return None
+        elif flag == 1:
+            return _UniffiConverterDouble.read(buf)
+        else:
+            raise InternalError("Unexpected flag byte for optional type")
+
+
+
+class _UniffiConverterOptionalBool(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        if value is not None:
+            _UniffiConverterBool.check_lower(value)
+
+    @classmethod
+    def write(cls, value, buf):
+        if value is None:
+            buf.write_u8(0)
+            return
+
+        buf.write_u8(1)
+        _UniffiConverterBool.write(value, buf)
+
+    @classmethod
+    def read(cls, buf):
+        flag = buf.read_u8()
+        if flag == 0:
+            return None
+        elif flag == 1:
+            return _UniffiConverterBool.read(buf)
+        else:
+            raise InternalError("Unexpected flag byte for optional type")
+
+
+
+class _UniffiConverterOptionalString(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        if value is not None:
+            _UniffiConverterString.check_lower(value)
+
+    @classmethod
+    def write(cls, value, buf):
+        if value is None:
+            buf.write_u8(0)
+            return
+
+        buf.write_u8(1)
+        _UniffiConverterString.write(value, buf)
+
+    @classmethod
+    def read(cls, buf):
+        flag = buf.read_u8()
+        if flag == 0:
+            return None
+        elif flag == 1:
+            return _UniffiConverterString.read(buf)
+        else:
+            raise InternalError("Unexpected flag byte for optional type")
+
+
+
+class _UniffiConverterOptionalBytes(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        if value is not None:
+            _UniffiConverterBytes.check_lower(value)
+
+    @classmethod
+    def write(cls, value, buf):
+        if value is None:
+            buf.write_u8(0)
+            return
+
+        buf.write_u8(1)
+        _UniffiConverterBytes.write(value, buf)
+
+    @classmethod
+    def read(cls, buf):
+        flag = buf.read_u8()
+        if flag == 0:
+            return None
+        elif flag == 1:
+            return _UniffiConverterBytes.read(buf)
+        else:
+            raise InternalError("Unexpected flag byte for optional type")
+
+
+
+class _UniffiConverterOptionalTypeBasicAuth(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        if value is not None:
+            _UniffiConverterTypeBasicAuth.check_lower(value)
+
+    @classmethod
+    def write(cls, value, buf):
+        if value is None:
+            buf.write_u8(0)
+            return
+
+        buf.write_u8(1)
+        _UniffiConverterTypeBasicAuth.write(value, buf)
+
+    @classmethod
+    def read(cls, buf):
+        flag = buf.read_u8()
+        if flag == 0:
+            return None
+        elif flag == 1:
+            return _UniffiConverterTypeBasicAuth.read(buf)
+        else:
+            raise InternalError("Unexpected flag byte for optional type")
+
+
+
+class _UniffiConverterOptionalTypeConfirmationPolicy(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        if value is not None:
+            _UniffiConverterTypeConfirmationPolicy.check_lower(value)
+
+    @classmethod
+    def write(cls, value, buf):
+        if value is None:
+            buf.write_u8(0)
+            return
+
+        buf.write_u8(1)
+        _UniffiConverterTypeConfirmationPolicy.write(value, buf)
+
+    @classmethod
+    def read(cls, buf):
+        flag = buf.read_u8()
+        if flag == 0:
+            return None
+        elif flag == 1:
+            return _UniffiConverterTypeConfirmationPolicy.read(buf)
+        else:
+            raise InternalError("Unexpected flag byte for optional type")
+
+
+
+class _UniffiConverterOptionalTypeConnectionConfig(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        if value is not None:
+            _UniffiConverterTypeConnectionConfig.check_lower(value)
+
+    @classmethod
+    def write(cls, value, buf):
+        if value is None:
+            buf.write_u8(0)
+            return
+
+        buf.write_u8(1)
+        _UniffiConverterTypeConnectionConfig.write(value, buf)
+
+    @classmethod
+    def read(cls, buf):
+        flag = buf.read_u8()
+        if flag == 0:
+            return None
+        elif flag == 1:
+            return _UniffiConverterTypeConnectionConfig.read(buf)
+        else:
+            raise InternalError("Unexpected flag byte for optional type")
+
+
+
+class _UniffiConverterOptionalTypeContractSpec(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        if value is not None:
+            _UniffiConverterTypeContractSpec.check_lower(value)
+
+    @classmethod
+    def write(cls, value, buf):
+        if value is None:
+            buf.write_u8(0)
+            return
+
+        buf.write_u8(1)
+        _UniffiConverterTypeContractSpec.write(value, buf)
+
+    @classmethod
+    def read(cls, buf):
+        flag = buf.read_u8()
+        if flag == 0:
+            return None
+        elif flag == 1:
+            return _UniffiConverterTypeContractSpec.read(buf)
+        else:
+            raise InternalError("Unexpected flag byte for optional type")
+
+
+
+class _UniffiConverterOptionalTypeDidResolutionOptions(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        if value is not None:
+            _UniffiConverterTypeDidResolutionOptions.check_lower(value)
+
+    @classmethod
+    def write(cls, value, buf):
+        if value is None:
+            buf.write_u8(0)
+            return
+
+        buf.write_u8(1)
+        _UniffiConverterTypeDidResolutionOptions.write(value, buf)
+
+    @classmethod
+    def read(cls, buf):
+        flag = buf.read_u8()
+        if flag == 0:
+            return None
+        elif flag == 1:
+            return _UniffiConverterTypeDidResolutionOptions.read(buf)
+        else:
+            raise InternalError("Unexpected flag byte for optional type")
+
+
+
+class _UniffiConverterOptionalTypeQuorumConfig(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        if value is not None:
+            _UniffiConverterTypeQuorumConfig.check_lower(value)
+
+    @classmethod
+    def write(cls, value, buf):
+        if value is None:
+            buf.write_u8(0)
+            return
+
+        buf.write_u8(1)
+        _UniffiConverterTypeQuorumConfig.write(value, buf)
+
+    @classmethod
+    def read(cls, buf):
+        flag = buf.read_u8()
+        if flag == 0:
+            return None
+        elif flag == 1:
+            return _UniffiConverterTypeQuorumConfig.read(buf)
+        else:
+            raise InternalError("Unexpected flag byte for optional type")
+
+
+
+class _UniffiConverterOptionalTypeSignatureData(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        if value is not None:
+            _UniffiConverterTypeSignatureData.check_lower(value)
+
+    @classmethod
+    def write(cls, value, buf):
+        if value is None:
+            buf.write_u8(0)
             return
 
-    @staticmethod
-    def write(value, buf):
-        if isinstance(value, VdrError.ClientNodeUnreachable):
-            buf.write_i32(1)
-        if isinstance(value, VdrError.ClientInvalidTransaction):
-            buf.write_i32(2)
-            _UniffiConverterString.write(value.msg, buf)
-        if isinstance(value, VdrError.ClientInvalidEndorsementData):
-            buf.write_i32(3)
-            _UniffiConverterString.write(value.msg, buf)
-        if isinstance(value, VdrError.ClientInvalidResponse):
-            buf.write_i32(4)
-            _UniffiConverterString.write(value.msg, buf)
-        if isinstance(value, VdrError.ClientTransactionReverted):
-            buf.write_i32(5)
-            _UniffiConverterString.write(value.msg, buf)
-        if isinstance(value, VdrError.ClientUnexpectedError):
-            buf.write_i32(6)
-            _UniffiConverterString.write(value.msg, buf)
-        if isinstance(value, VdrError.ClientInvalidState):
-            buf.write_i32(7)
-            _UniffiConverterString.write(value.msg, buf)
-        if isinstance(value, VdrError.ContractInvalidName):
-            buf.write_i32(8)
-            _UniffiConverterString.write(value.msg, buf)
-        if isinstance(value, VdrError.ContractInvalidSpec):
-            buf.write_i32(9)
-            _UniffiConverterString.write(value.msg, buf)
-        if isinstance(value, VdrError.ContractInvalidInputData):
-            buf.write_i32(10)
-        if isinstance(value, VdrError.ContractInvalidResponseData):
-            buf.write_i32(11)
-            _UniffiConverterString.write(value.msg, buf)
-        if isinstance(value, VdrError.SignerInvalidPrivateKey):
-            buf.write_i32(12)
-        if isinstance(value, VdrError.SignerInvalidMessage):
-            buf.write_i32(13)
-        if isinstance(value, VdrError.SignerMissingKey):
-            buf.write_i32(14)
-            _UniffiConverterString.write(value.msg, buf)
-        if isinstance(value, VdrError.SignerUnexpectedError):
-            buf.write_i32(15)
-            _UniffiConverterString.write(value.msg, buf)
-        if isinstance(value, VdrError.CommonInvalidData):
-            buf.write_i32(16)
-            _UniffiConverterString.write(value.msg, buf)
-        if isinstance(value, VdrError.QuorumNotReached):
-            buf.write_i32(17)
-            _UniffiConverterString.write(value.msg, buf)
-        if isinstance(value, VdrError.GetTransactionError):
-            buf.write_i32(18)
-            _UniffiConverterString.write(value.msg, buf)
-        if isinstance(value, VdrError.InvalidSchema):
-            buf.write_i32(19)
-            _UniffiConverterString.write(value.msg, buf)
-        if isinstance(value, VdrError.InvalidCredentialDefinition):
-            buf.write_i32(20)
-            _UniffiConverterString.write(value.msg, buf)
+        buf.write_u8(1)
+        _UniffiConverterTypeSignatureData.write(value, buf)
+
+    @classmethod
+    def read(cls, buf):
+        flag = buf.read_u8()
+        if flag == 0:
+            return None
+        elif flag == 1:
+            return _UniffiConverterTypeSignatureData.read(buf)
+        else:
+            raise InternalError("Unexpected flag byte for optional type")
 
 
 
-class _UniffiConverterOptionalUInt8(_UniffiConverterRustBuffer):
+class _UniffiConverterOptionalTypeTlsConfig(_UniffiConverterRustBuffer):
     @classmethod
     def check_lower(cls, value):
         if value is not None:
-            _UniffiConverterUInt8.check_lower(value)
+            _UniffiConverterTypeTlsConfig.check_lower(value)
 
     @classmethod
     def write(cls, value, buf):
@@ -3393,7 +5558,7 @@ class _UniffiConverterOptionalUInt8(_UniffiConverterRustBuffer):
             return
 
         buf.write_u8(1)
-        _UniffiConverterUInt8.write(value, buf)
+        _UniffiConverterTypeTlsConfig.write(value, buf)
 
     @classmethod
     def read(cls, buf):
@@ -3401,17 +5566,17 @@ class _UniffiConverterOptionalUInt8(_UniffiConverterRustBuffer):
         if flag == 0:
             return None
         elif flag == 1:
-            return _UniffiConverterUInt8.read(buf)
+            return _UniffiConverterTypeTlsConfig.read(buf)
         else:
             raise InternalError("Unexpected flag byte for optional type")
 
 
 
-class _UniffiConverterOptionalUInt64(_UniffiConverterRustBuffer):
+class _UniffiConverterOptionalTypeBlockTag(_UniffiConverterRustBuffer):
     @classmethod
     def check_lower(cls, value):
         if value is not None:
-            _UniffiConverterUInt64.check_lower(value)
+            _UniffiConverterTypeBlockTag.check_lower(value)
 
     @classmethod
     def write(cls, value, buf):
@@ -3420,7 +5585,7 @@ class _UniffiConverterOptionalUInt64(_UniffiConverterRustBuffer):
             return
 
         buf.write_u8(1)
-        _UniffiConverterUInt64.write(value, buf)
+        _UniffiConverterTypeBlockTag.write(value, buf)
 
     @classmethod
     def read(cls, buf):
@@ -3428,17 +5593,17 @@ class _UniffiConverterOptionalUInt64(_UniffiConverterRustBuffer):
         if flag == 0:
             return None
         elif flag == 1:
-            return _UniffiConverterUInt64.read(buf)
+            return _UniffiConverterTypeBlockTag.read(buf)
         else:
             raise InternalError("Unexpected flag byte for optional type")
 
 
 
-class _UniffiConverterOptionalString(_UniffiConverterRustBuffer):
+class _UniffiConverterOptionalTypeFeeStrategy(_UniffiConverterRustBuffer):
     @classmethod
     def check_lower(cls, value):
         if value is not None:
-            _UniffiConverterString.check_lower(value)
+            _UniffiConverterTypeFeeStrategy.check_lower(value)
 
     @classmethod
     def write(cls, value, buf):
@@ -3447,7 +5612,7 @@ class _UniffiConverterOptionalString(_UniffiConverterRustBuffer):
             return
 
         buf.write_u8(1)
-        _UniffiConverterString.write(value, buf)
+        _UniffiConverterTypeFeeStrategy.write(value, buf)
 
     @classmethod
     def read(cls, buf):
@@ -3455,17 +5620,17 @@ class _UniffiConverterOptionalString(_UniffiConverterRustBuffer):
         if flag == 0:
             return None
         elif flag == 1:
-            return _UniffiConverterString.read(buf)
+            return _UniffiConverterTypeFeeStrategy.read(buf)
         else:
             raise InternalError("Unexpected flag byte for optional type")
 
 
 
-class _UniffiConverterOptionalBytes(_UniffiConverterRustBuffer):
+class _UniffiConverterOptionalTypeQuorumPolicy(_UniffiConverterRustBuffer):
     @classmethod
     def check_lower(cls, value):
         if value is not None:
-            _UniffiConverterBytes.check_lower(value)
+            _UniffiConverterTypeQuorumPolicy.check_lower(value)
 
     @classmethod
     def write(cls, value, buf):
@@ -3474,7 +5639,7 @@ class _UniffiConverterOptionalBytes(_UniffiConverterRustBuffer):
             return
 
         buf.write_u8(1)
-        _UniffiConverterBytes.write(value, buf)
+        _UniffiConverterTypeQuorumPolicy.write(value, buf)
 
     @classmethod
     def read(cls, buf):
@@ -3482,17 +5647,17 @@ class _UniffiConverterOptionalBytes(_UniffiConverterRustBuffer):
         if flag == 0:
             return None
         elif flag == 1:
-            return _UniffiConverterBytes.read(buf)
+            return _UniffiConverterTypeQuorumPolicy.read(buf)
         else:
             raise InternalError("Unexpected flag byte for optional type")
 
 
 
-class _UniffiConverterOptionalTypeContractSpec(_UniffiConverterRustBuffer):
+class _UniffiConverterOptionalSequenceString(_UniffiConverterRustBuffer):
     @classmethod
     def check_lower(cls, value):
         if value is not None:
-            _UniffiConverterTypeContractSpec.check_lower(value)
+            _UniffiConverterSequenceString.check_lower(value)
 
     @classmethod
     def write(cls, value, buf):
@@ -3501,7 +5666,7 @@ class _UniffiConverterOptionalTypeContractSpec(_UniffiConverterRustBuffer):
             return
 
         buf.write_u8(1)
-        _UniffiConverterTypeContractSpec.write(value, buf)
+        _UniffiConverterSequenceString.write(value, buf)
 
     @classmethod
     def read(cls, buf):
@@ -3509,17 +5674,17 @@ class _UniffiConverterOptionalTypeContractSpec(_UniffiConverterRustBuffer):
         if flag == 0:
             return None
         elif flag == 1:
-            return _UniffiConverterTypeContractSpec.read(buf)
+            return _UniffiConverterSequenceString.read(buf)
         else:
             raise InternalError("Unexpected flag byte for optional type")
 
 
 
-class _UniffiConverterOptionalTypeDidResolutionOptions(_UniffiConverterRustBuffer):
+class _UniffiConverterOptionalSequenceOptionalSequenceString(_UniffiConverterRustBuffer):
     @classmethod
     def check_lower(cls, value):
         if value is not None:
-            _UniffiConverterTypeDidResolutionOptions.check_lower(value)
+            _UniffiConverterSequenceOptionalSequenceString.check_lower(value)
 
     @classmethod
     def write(cls, value, buf):
@@ -3528,7 +5693,7 @@ class _UniffiConverterOptionalTypeDidResolutionOptions(_UniffiConverterRustBuffe
             return
 
         buf.write_u8(1)
-        _UniffiConverterTypeDidResolutionOptions.write(value, buf)
+        _UniffiConverterSequenceOptionalSequenceString.write(value, buf)
 
     @classmethod
     def read(cls, buf):
@@ -3536,17 +5701,17 @@ class _UniffiConverterOptionalTypeDidResolutionOptions(_UniffiConverterRustBuffe
         if flag == 0:
             return None
         elif flag == 1:
-            return _UniffiConverterTypeDidResolutionOptions.read(buf)
+            return _UniffiConverterSequenceOptionalSequenceString.read(buf)
         else:
             raise InternalError("Unexpected flag byte for optional type")
 
 
 
-class _UniffiConverterOptionalTypeQuorumConfig(_UniffiConverterRustBuffer):
+class _UniffiConverterOptionalMapStringUInt64(_UniffiConverterRustBuffer):
     @classmethod
     def check_lower(cls, value):
         if value is not None:
-            _UniffiConverterTypeQuorumConfig.check_lower(value)
+            _UniffiConverterMapStringUInt64.check_lower(value)
 
     @classmethod
     def write(cls, value, buf):
@@ -3555,7 +5720,7 @@ class _UniffiConverterOptionalTypeQuorumConfig(_UniffiConverterRustBuffer):
             return
 
         buf.write_u8(1)
-        _UniffiConverterTypeQuorumConfig.write(value, buf)
+        _UniffiConverterMapStringUInt64.write(value, buf)
 
     @classmethod
     def read(cls, buf):
@@ -3563,17 +5728,17 @@ class _UniffiConverterOptionalTypeQuorumConfig(_UniffiConverterRustBuffer):
         if flag == 0:
             return None
         elif flag == 1:
-            return _UniffiConverterTypeQuorumConfig.read(buf)
+            return _UniffiConverterMapStringUInt64.read(buf)
         else:
             raise InternalError("Unexpected flag byte for optional type")
 
 
 
-class _UniffiConverterOptionalTypeSignatureData(_UniffiConverterRustBuffer):
+class _UniffiConverterOptionalMapStringString(_UniffiConverterRustBuffer):
     @classmethod
     def check_lower(cls, value):
         if value is not None:
-            _UniffiConverterTypeSignatureData.check_lower(value)
+            _UniffiConverterMapStringString.check_lower(value)
 
     @classmethod
     def write(cls, value, buf):
@@ -3582,7 +5747,7 @@ class _UniffiConverterOptionalTypeSignatureData(_UniffiConverterRustBuffer):
             return
 
         buf.write_u8(1)
-        _UniffiConverterTypeSignatureData.write(value, buf)
+        _UniffiConverterMapStringString.write(value, buf)
 
     @classmethod
     def read(cls, buf):
@@ -3590,7 +5755,7 @@ class _UniffiConverterOptionalTypeSignatureData(_UniffiConverterRustBuffer):
         if flag == 0:
             return None
         elif flag == 1:
-            return _UniffiConverterTypeSignatureData.read(buf)
+            return _UniffiConverterMapStringString.read(buf)
         else:
             raise InternalError("Unexpected flag byte for optional type")
 
@@ -3646,6 +5811,31 @@ class _UniffiConverterSequenceBytes(_UniffiConverterRustBuffer):
 
 
 
+class _UniffiConverterSequenceTypeAccessListItem(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        for item in value:
+            _UniffiConverterTypeAccessListItem.check_lower(item)
+
+    @classmethod
+    def write(cls, value, buf):
+        items = len(value)
+        buf.write_i32(items)
+        for item in value:
+            _UniffiConverterTypeAccessListItem.write(item, buf)
+
+    @classmethod
+    def read(cls, buf):
+        count = buf.read_i32()
+        if count < 0:
+            raise InternalError("Unexpected negative sequence length")
+
+        return [
+            _UniffiConverterTypeAccessListItem.read(buf) for i in range(count)
+        ]
+
+
+
 class _UniffiConverterSequenceTypeContractConfig(_UniffiConverterRustBuffer):
     @classmethod
     def check_lower(cls, value):
@@ -3696,6 +5886,56 @@ class _UniffiConverterSequenceTypeEventLog(_UniffiConverterRustBuffer):
 
 
 
+class _UniffiConverterSequenceTypeVerificationMismatch(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        for item in value:
+            _UniffiConverterTypeVerificationMismatch.check_lower(item)
+
+    @classmethod
+    def write(cls, value, buf):
+        items = len(value)
+        buf.write_i32(items)
+        for item in value:
+            _UniffiConverterTypeVerificationMismatch.write(item, buf)
+
+    @classmethod
+    def read(cls, buf):
+        count = buf.read_i32()
+        if count < 0:
+            raise InternalError("Unexpected negative sequence length")
+
+        return [
+            _UniffiConverterTypeVerificationMismatch.read(buf) for i in range(count)
+        ]
+
+
+
+class _UniffiConverterSequenceOptionalSequenceString(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, value):
+        for item in value:
+            _UniffiConverterOptionalSequenceString.check_lower(item)
+
+    @classmethod
+    def write(cls, value, buf):
+        items = len(value)
+        buf.write_i32(items)
+        for item in value:
+            _UniffiConverterOptionalSequenceString.write(item, buf)
+
+    @classmethod
+    def read(cls, buf):
+        count = buf.read_i32()
+        if count < 0:
+            raise InternalError("Unexpected negative sequence length")
+
+        return [
+            _UniffiConverterOptionalSequenceString.read(buf) for i in range(count)
+        ]
+
+
+
 class _UniffiConverterSequenceTypeJsonValue(_UniffiConverterRustBuffer):
     @classmethod
     def check_lower(cls, value):
@@ -3720,6 +5960,72 @@ class _UniffiConverterSequenceTypeJsonValue(_UniffiConverterRustBuffer):
         ]
 
 
+
+class _UniffiConverterMapStringUInt64(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, items):
+        for (key, value) in items.items():
+            _UniffiConverterString.check_lower(key)
+            _UniffiConverterUInt64.check_lower(value)
+
+    @classmethod
+    def write(cls, items, buf):
+        buf.write_i32(len(items))
+        for (key, value) in items.items():
+            _UniffiConverterString.write(key, buf)
+            _UniffiConverterUInt64.write(value, buf)
+
+    @classmethod
+    def read(cls, buf):
+        count = buf.read_i32()
+        if count < 0:
+            raise InternalError("Unexpected negative map size")
+
+        # It would be nice to use a dict comprehension,
+        # but in Python 3.7 and before the evaluation order is not according to spec,
+        # so we we're reading the value before the key.
+        # This loop makes the order explicit: first reading the key, then the value.
+        d = {}
+        for i in range(count):
+            key = _UniffiConverterString.read(buf)
+            val = _UniffiConverterUInt64.read(buf)
+            d[key] = val
+        return d
+
+
+
+class _UniffiConverterMapStringString(_UniffiConverterRustBuffer):
+    @classmethod
+    def check_lower(cls, items):
+        for (key, value) in items.items():
+            _UniffiConverterString.check_lower(key)
+            _UniffiConverterString.check_lower(value)
+
+    @classmethod
+    def write(cls, items, buf):
+        buf.write_i32(len(items))
+        for (key, value) in items.items():
+            _UniffiConverterString.write(key, buf)
+            _UniffiConverterString.write(value, buf)
+
+    @classmethod
+    def read(cls, buf):
+        count = buf.read_i32()
+        if count < 0:
+            raise InternalError("Unexpected negative map size")
+
+        # It would be nice to use a dict comprehension,
+        # but in Python 3.7 and before the evaluation order is not according to spec,
+        # so we we're reading the value before the key.
+        # This loop makes the order explicit: first reading the key, then the value.
+        d = {}
+        for i in range(count):
+            key = _UniffiConverterString.read(buf)
+            val = _UniffiConverterString.read(buf)
+            d[key] = val
+        return d
+
+
 # Type alias
 JsonValue = str
 
@@ -4966,6 +7272,13 @@ def transaction_endorsing_data_get_signing_bytes(data: "TransactionEndorsingData
         _UniffiConverterTypeTransactionEndorsingData.lower(data)))
 
 
+def transaction_endorsing_data_recover_signer(data: "TransactionEndorsingData") -> "str":
+    _UniffiConverterTypeTransactionEndorsingData.check_lower(data)
+    
+    return _UniffiConverterString.lift(_rust_call_with_error(_UniffiConverterTypeVdrError,_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_func_transaction_endorsing_data_recover_signer,
+        _UniffiConverterTypeTransactionEndorsingData.lower(data)))
+
+
 def transaction_endorsing_data_to_string(data: "TransactionEndorsingData") -> "str":
     _UniffiConverterTypeTransactionEndorsingData.check_lower(data)
     
@@ -4973,6 +7286,13 @@ def transaction_endorsing_data_to_string(data: "TransactionEndorsingData") -> "s
         _UniffiConverterTypeTransactionEndorsingData.lower(data)))
 
 
+def transaction_endorsing_data_verify(data: "TransactionEndorsingData"):
+    _UniffiConverterTypeTransactionEndorsingData.check_lower(data)
+    
+    _rust_call_with_error(_UniffiConverterTypeVdrError,_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_func_transaction_endorsing_data_verify,
+        _UniffiConverterTypeTransactionEndorsingData.lower(data))
+
+
 def transaction_from_string(value: "str") -> "Transaction":
     _UniffiConverterString.check_lower(value)
     
@@ -4987,6 +7307,13 @@ def transaction_get_signing_bytes(data: "Transaction") -> "bytes":
         _UniffiConverterTypeTransaction.lower(data)))
 
 
+def transaction_recover_signer(data: "Transaction") -> "str":
+    _UniffiConverterTypeTransaction.check_lower(data)
+    
+    return _UniffiConverterString.lift(_rust_call_with_error(_UniffiConverterTypeVdrError,_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_func_transaction_recover_signer,
+        _UniffiConverterTypeTransaction.lower(data)))
+
+
 def transaction_to_string(data: "Transaction") -> "str":
     _UniffiConverterTypeTransaction.check_lower(data)
     
@@ -4994,12 +7321,29 @@ def transaction_to_string(data: "Transaction") -> "str":
         _UniffiConverterTypeTransaction.lower(data)))
 
 
+def transaction_verify(data: "Transaction"):
+    _UniffiConverterTypeTransaction.check_lower(data)
+    
+    _rust_call_with_error(_UniffiConverterTypeVdrError,_UniffiLib.uniffi_indy_besu_vdr_uniffi_fn_func_transaction_verify,
+        _UniffiConverterTypeTransaction.lower(data))
+
+
 __all__ = [
     "InternalError",
+    "BlockTag",
     "DidEvents",
+    "FeeStrategy",
+    "QuorumPolicy",
+    "SimulationResult",
     "Status",
+    "TransactionEnvelope",
     "TransactionType",
     "VdrError",
+    "VerificationMismatch",
+    "AccessListItem",
+    "BasicAuth",
+    "ConfirmationPolicy",
+    "ConnectionConfig",
     "ContractConfig",
     "ContractSpec",
     "CredentialDefinition",
@@ -5008,13 +7352,18 @@ __all__ = [
     "DidOwnerChanged",
     "DidResolutionOptions",
     "EventLog",
+    "EventPagination",
     "EventQuery",
+    "LedgerClientOptions",
     "PingStatus",
     "QuorumConfig",
     "Schema",
     "SignatureData",
+    "TlsConfig",
     "Transaction",
     "TransactionEndorsingData",
+    "TransactionFees",
+    "VerificationReport",
     "build_add_validator_transaction",
     "build_assign_role_transaction",
     "build_create_credential_definition_endorsing_data",
@@ -5084,10 +7433,15 @@ __all__ = [
     "transaction_endorsing_data_create",
     "transaction_endorsing_data_from_string",
     "transaction_endorsing_data_get_signing_bytes",
+    "transaction_endorsing_data_recover_signer",
     "transaction_endorsing_data_to_string",
+    "transaction_endorsing_data_verify",
     "transaction_from_string",
     "transaction_get_signing_bytes",
+    "transaction_recover_signer",
     "transaction_to_string",
+    "transaction_verify",
     "LedgerClient",
+    "TokenProvider",
 ]
 